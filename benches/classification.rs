@@ -0,0 +1,83 @@
+// classification.rs - benchmarks of string classification
+
+use to_be::{
+    batch::BatchClassifier,
+    string_is_falsey,
+    string_is_truey,
+    string_is_truthy,
+    string_is_truthy_with,
+    Terms,
+};
+
+use criterion::{
+    criterion_group,
+    criterion_main,
+    Criterion,
+};
+use std::hint::black_box;
+
+
+const INPUTS : &[&str] = &[
+    "true", "False", "YES", "nO", " on ", "OFF", "0", "1", "enabled", "", "tRuE", "maybe",
+];
+
+fn bench_string_is_truthy(c : &mut Criterion) {
+    c.bench_function("string_is_truthy", |b| {
+        b.iter(|| {
+            for &s in INPUTS {
+                black_box(string_is_truthy(black_box(s)));
+            }
+        })
+    });
+}
+
+fn bench_string_is_falsey_and_truey(c : &mut Criterion) {
+    c.bench_function("string_is_falsey + string_is_truey", |b| {
+        b.iter(|| {
+            for &s in INPUTS {
+                black_box(string_is_falsey(black_box(s)));
+                black_box(string_is_truey(black_box(s)));
+            }
+        })
+    });
+}
+
+fn bench_string_is_truthy_with(c : &mut Criterion) {
+    let terms = Terms::Strings {
+        falsey_precise_strings :   &["Nyet", "Nope"],
+        falsey_lowercase_strings : &["nyet", "nope"],
+        truey_precise_strings :    &["Da", "Yup"],
+        truey_lowercase_strings :  &["da", "yup"],
+    };
+
+    c.bench_function("string_is_truthy_with", |b| {
+        b.iter(|| {
+            for &s in INPUTS {
+                black_box(string_is_truthy_with(black_box(s), terms.clone()));
+            }
+        })
+    });
+}
+
+fn bench_batch_classifier(c : &mut Criterion) {
+    let classifier = BatchClassifier::new(Terms::Default);
+    let mut results = Vec::with_capacity(INPUTS.len());
+
+    c.bench_function("BatchClassifier::classify_into", |b| {
+        b.iter(|| {
+            results.clear();
+            classifier.classify_into(black_box(INPUTS).iter().copied(), &mut results);
+            black_box(&results);
+        })
+    });
+}
+
+
+criterion_group!(
+    benches,
+    bench_string_is_truthy,
+    bench_string_is_falsey_and_truey,
+    bench_string_is_truthy_with,
+    bench_batch_classifier,
+);
+criterion_main!(benches);
@@ -0,0 +1,132 @@
+// build.rs - to-be build script
+
+use std::{
+    env,
+    fmt::Write as _,
+    fs,
+    path::Path,
+};
+
+
+const CLDR_DATA_PATH : &str = "data/cldr-yesno.tsv";
+#[cfg(feature = "ffi")]
+const FFI_SOURCE_PATH : &str = "src/ffi.rs";
+
+
+/// Generates, from the CLDR data file, the static locale tables that are
+/// included by the locale registry when the `"cldr"` feature is enabled.
+fn generate_cldr_tables(out_dir : &Path) {
+    let data = fs::read_to_string(CLDR_DATA_PATH).expect("could not read CLDR data file");
+
+    let mut code = String::new();
+
+    code.push_str("// generated by build.rs from data/cldr-yesno.tsv - do not edit\n\n");
+    code.push_str("const CLDR_LOCALES : &[LocaleEntry] = &[\n");
+
+    for (line_index, line) in data.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let fields : Vec<&str> = line.split('\t').collect();
+
+        if 3 != fields.len() {
+            panic!("{CLDR_DATA_PATH}:{}: expected 3 tab-separated fields", line_index + 1);
+        }
+
+        let forms = |field : &str| -> String {
+            field
+                .split(':')
+                .map(|form| format!("{:?}", form.trim().to_lowercase()))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        writeln!(
+            code,
+            "    LocaleEntry {{ tag : {:?}, falsey_lowercase_strings : &[{}], truey_lowercase_strings : &[{}] }},",
+            fields[0],
+            forms(fields[2]),
+            forms(fields[1]),
+        )
+        .unwrap();
+    }
+
+    code.push_str("];\n");
+
+    fs::write(out_dir.join("cldr_tables.rs"), code).expect("could not write CLDR tables");
+}
+
+/// Generates, from the `ffi` module, the C header that declares the
+/// C-compatible API, which is checked (by the module's tests) against that
+/// in `include/to_be.h`.
+#[cfg(feature = "ffi")]
+fn generate_c_header(out_dir : &Path) {
+    let config = cbindgen::Config {
+        header : Some("/* to_be.h - to-be C API (generated by cbindgen from src/ffi.rs - do not edit) */".into()),
+        include_guard : Some("TO_BE_H".into()),
+        language : cbindgen::Language::C,
+        cpp_compat : true,
+        usize_is_size_t : true,
+        documentation_style : cbindgen::DocumentationStyle::C,
+        ..Default::default()
+    };
+
+    cbindgen::Builder::new()
+        .with_config(config)
+        .with_src(FFI_SOURCE_PATH)
+        .generate()
+        .expect("could not generate C header")
+        .write_to_file(out_dir.join("to_be.h"));
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={CLDR_DATA_PATH}");
+
+    println!("cargo:rustc-check-cfg=cfg(to_be_stock_default)");
+
+    let out_dir = env::var_os("OUT_DIR").expect("OUT_DIR not set");
+
+    // the stock terms are the default vocabulary unless one of the
+    // `"default-locale-*"` or `"default-preset-*"` features is enabled, and
+    // at most one of those may be enabled, which is checked here (rather
+    // than in the crate) so that the conflict is the only error reported
+
+    let mut default_features : Vec<String> = env::vars_os()
+        .filter_map(|(name, _)| name.into_string().ok())
+        .filter_map(|name| {
+            ["CARGO_FEATURE_DEFAULT_LOCALE_", "CARGO_FEATURE_DEFAULT_PRESET_"]
+                .iter()
+                .any(|prefix| name.starts_with(prefix))
+                .then(|| name["CARGO_FEATURE_".len()..].to_lowercase().replace('_', "-"))
+        })
+        .collect();
+
+    default_features.sort();
+
+    match default_features.len() {
+        0 => println!("cargo:rustc-cfg=to_be_stock_default"),
+        1 => (),
+        _ => {
+            panic!(
+                "at most one of the \"default-locale-*\" and \"default-preset-*\" features may be enabled, but {} are: {}",
+                default_features.len(),
+                default_features.iter().map(|feature| format!("\"{feature}\"")).collect::<Vec<_>>().join(", "),
+            );
+        },
+    }
+
+    if env::var_os("CARGO_FEATURE_CLDR").is_some() {
+        generate_cldr_tables(Path::new(&out_dir));
+    }
+
+    #[cfg(feature = "ffi")]
+    {
+        println!("cargo:rerun-if-changed={FFI_SOURCE_PATH}");
+
+        generate_c_header(Path::new(&out_dir));
+    }
+}
@@ -0,0 +1,351 @@
+// lib.rs - to-be-derive API
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    parse::ParseStream,
+    parse_macro_input,
+    Attribute,
+    Data,
+    DataEnum,
+    DataStruct,
+    DeriveInput,
+    Error,
+    Fields,
+    Ident,
+    Index,
+    LitBool,
+    LitStr,
+};
+
+
+/// The classification given to an enum variant by its `#[truthy(...)]`
+/// attribute.
+enum VariantTruthiness {
+    Falsey,
+    Truey,
+    Unknown,
+}
+
+fn parse_variant_truthiness_(input : ParseStream) -> syn::Result<VariantTruthiness> {
+    if input.peek(LitBool) {
+        let lit : LitBool = input.parse()?;
+
+        Ok(if lit.value {
+            VariantTruthiness::Truey
+        } else {
+            VariantTruthiness::Falsey
+        })
+    } else {
+        let ident : Ident = input.parse()?;
+
+        if ident == "unknown" {
+            Ok(VariantTruthiness::Unknown)
+        } else {
+            Err(Error::new(ident.span(), "expected `true`, `false`, or `unknown`"))
+        }
+    }
+}
+
+fn truthy_attribute_(attrs : &[Attribute]) -> Option<&Attribute> {
+    attrs.iter().find(|attr| attr.path().is_ident("truthy"))
+}
+
+fn expand_enum_(
+    input : &DeriveInput,
+    data : &DataEnum,
+) -> syn::Result<TokenStream2> {
+    let mut arms = Vec::with_capacity(data.variants.len());
+
+    for variant in &data.variants {
+        let Some(attr) = truthy_attribute_(&variant.attrs) else {
+            return Err(Error::new_spanned(
+                variant,
+                "each variant must be marked with `#[truthy(true)]`, `#[truthy(false)]`, or `#[truthy(unknown)]`",
+            ));
+        };
+
+        let value = match attr.parse_args_with(parse_variant_truthiness_)? {
+            VariantTruthiness::Falsey => quote! { ::core::option::Option::Some(false) },
+            VariantTruthiness::Truey => quote! { ::core::option::Option::Some(true) },
+            VariantTruthiness::Unknown => quote! { ::core::option::Option::None },
+        };
+
+        let ident = &variant.ident;
+        let pattern = match variant.fields {
+            Fields::Named(_) => quote! { Self::#ident { .. } },
+            Fields::Unnamed(_) => quote! { Self::#ident(..) },
+            Fields::Unit => quote! { Self::#ident },
+        };
+
+        arms.push(quote! { #pattern => #value, });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::to_be::Truthy for #name #ty_generics #where_clause {
+            fn is_truthy(&self) -> ::core::option::Option<bool> {
+                match *self {
+                    #(#arms)*
+                }
+            }
+        }
+    })
+}
+
+fn expand_struct_(
+    input : &DeriveInput,
+    data : &DataStruct,
+) -> syn::Result<TokenStream2> {
+    let mut delegate = None;
+
+    for (index, field) in data.fields.iter().enumerate() {
+        let Some(attr) = truthy_attribute_(&field.attrs) else {
+            continue;
+        };
+
+        attr.meta.require_path_only()?;
+
+        if delegate.is_some() {
+            return Err(Error::new_spanned(attr, "only one field may be marked with `#[truthy]`"));
+        }
+
+        delegate = Some(match &field.ident {
+            Some(ident) => quote! { #ident },
+            None => {
+                let index = Index::from(index);
+
+                quote! { #index }
+            },
+        });
+    }
+
+    let Some(delegate) = delegate else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "the field to which `Truthy` delegates must be marked with `#[truthy]`",
+        ));
+    };
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::to_be::Truthy for #name #ty_generics #where_clause {
+            fn is_truthy(&self) -> ::core::option::Option<bool> {
+                ::to_be::Truthy::is_truthy(&self.#delegate)
+            }
+        }
+    })
+}
+
+fn expand_(input : &DeriveInput) -> syn::Result<TokenStream2> {
+    match &input.data {
+        Data::Enum(data) => expand_enum_(input, data),
+        Data::Struct(data) => expand_struct_(input, data),
+        Data::Union(_) => Err(Error::new_spanned(&input.ident, "`Truthy` cannot be derived for unions")),
+    }
+}
+
+/// The name and default of a field given by its `#[env(...)]` attributes.
+#[derive(Default)]
+struct EnvFieldOptions {
+    name :    Option<String>,
+    default : Option<bool>,
+}
+
+fn env_attributes_(attrs : &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("env"))
+}
+
+fn env_prefix_(input : &DeriveInput) -> syn::Result<String> {
+    let mut prefix = String::new();
+
+    for attr in env_attributes_(&input.attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("prefix") {
+                prefix = meta.value()?.parse::<LitStr>()?.value();
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `prefix = \"...\"`"))
+            }
+        })?;
+    }
+
+    Ok(prefix)
+}
+
+fn env_field_options_(attrs : &[Attribute]) -> syn::Result<EnvFieldOptions> {
+    let mut options = EnvFieldOptions::default();
+
+    for attr in env_attributes_(attrs) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                options.name = Some(meta.value()?.parse::<LitStr>()?.value());
+
+                Ok(())
+            } else if meta.path.is_ident("default") {
+                options.default = Some(meta.value()?.parse::<LitBool>()?.value);
+
+                Ok(())
+            } else {
+                Err(meta.error("expected `name = \"...\"` or `default = true|false`"))
+            }
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn expand_from_env_truthy_(input : &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(DataStruct {
+        fields : Fields::Named(fields),
+        ..
+    }) = &input.data
+    else {
+        return Err(Error::new_spanned(
+            &input.ident,
+            "`FromEnvTruthy` can be derived only for structs with named fields",
+        ));
+    };
+
+    let prefix = env_prefix_(input)?;
+
+    let mut lets = Vec::with_capacity(fields.named.len());
+    let mut inits = Vec::with_capacity(fields.named.len());
+
+    // the locals are named such that they cannot collide with the fields
+    // (nor, thereby, with one another)
+
+    for (index, field) in fields.named.iter().enumerate() {
+        let Some(ident) = &field.ident else {
+            continue;
+        };
+        let local = format_ident!("__to_be_field_{}", index);
+
+        let options = env_field_options_(&field.attrs)?;
+        let name = match options.name {
+            Some(name) => name,
+            None => format!("{prefix}{}", ident.to_string().trim_start_matches("r#").to_uppercase()),
+        };
+        let default = match options.default {
+            Some(b) => quote! { ::core::option::Option::Some(#b) },
+            None => quote! { ::core::option::Option::None },
+        };
+        let ty = &field.ty;
+
+        lets.push(quote! {
+            let #local = ::to_be::env::__field_from_env::<#ty>(#name, #default, &mut __to_be_errors);
+        });
+        inits.push(quote! {
+            #ident : #local.unwrap(),
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::to_be::env::FromEnvTruthy for #name #ty_generics #where_clause {
+            fn from_env() -> ::core::result::Result<Self, ::to_be::env::FromEnvError> {
+                let mut __to_be_errors = ::std::vec::Vec::new();
+
+                #(#lets)*
+
+                if !__to_be_errors.is_empty() {
+                    return ::core::result::Result::Err(::to_be::env::FromEnvError {
+                        errors : __to_be_errors,
+                    });
+                }
+
+                ::core::result::Result::Ok(Self {
+                    #(#inits)*
+                })
+            }
+        }
+    })
+}
+
+
+/// Derives `to_be::Truthy` for an enum or a struct.
+///
+/// Each variant of an enum must be marked with one of:
+///
+/// - `#[truthy(true)]` - the variant is "truey";
+/// - `#[truthy(false)]` - the variant is "falsey";
+/// - `#[truthy(unknown)]` - the variant is not "truthy";
+///
+/// Exactly one field of a struct must be marked with `#[truthy]`, and the
+/// implementation delegates to that field (whose type must itself implement
+/// `to_be::Truthy`).
+///
+/// # Example:
+///
+/// ```ignore
+/// #[derive(to_be::Truthy)]
+/// enum Status {
+///     #[truthy(true)]
+///     Enabled,
+///     #[truthy(false)]
+///     Disabled,
+///     #[truthy(unknown)]
+///     Unknown,
+/// }
+///
+/// #[derive(to_be::Truthy)]
+/// struct Setting {
+///     name : String,
+///     #[truthy]
+///     raw_value : String,
+/// }
+/// ```
+#[proc_macro_derive(Truthy, attributes(truthy))]
+pub fn derive_truthy(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_(&input).unwrap_or_else(Error::into_compile_error).into()
+}
+
+/// Derives `to_be::env::FromEnvTruthy` for a struct with named fields, each
+/// of which is obtained from an environment variable, and whose type must
+/// implement `to_be::env::FromEnvFlag` (as do `bool`, `Option<bool>`, and
+/// `to_be::Truthiness`).
+///
+/// The name of the variable of each field is the name of the field in
+/// uppercase, preceded by the prefix given by the attribute
+/// `#[env(prefix = "...")]` on the struct, if any. Each field may be marked
+/// with:
+///
+/// - `#[env(name = "...")]` - the name of the variable (to which the
+///   prefix is not applied);
+/// - `#[env(default = true|false)]` - the flag of the variable when it is
+///   not set;
+///
+/// All fields are obtained, and the errors of all that are in error are
+/// reported together.
+///
+/// # Example:
+///
+/// ```ignore
+/// #[derive(to_be::env::FromEnvTruthy)]
+/// #[env(prefix = "MY_SERVICE_")]
+/// struct Switches {
+///     #[env(default = false)]
+///     verbose :  bool,
+///     #[env(name = "NO_COLOR")]
+///     no_color : Option<bool>,
+/// }
+/// ```
+#[proc_macro_derive(FromEnvTruthy, attributes(env))]
+pub fn derive_from_env_truthy(input : TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    expand_from_env_truthy_(&input).unwrap_or_else(Error::into_compile_error).into()
+}
@@ -0,0 +1,136 @@
+/* to_be.h - to-be C API (generated by cbindgen from src/ffi.rs - do not edit) */
+
+#ifndef TO_BE_H
+#define TO_BE_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ Custom terms, prepared for classification, that are created by
+ `to_be_terms_create()` (or `to_be_terms_create_for_locale()`) and
+ destroyed by `to_be_terms_destroy()`.
+ */
+typedef struct ToBeTerms ToBeTerms;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ Indicates whether the given string, when trimmed, is "truthy" according
+ to the default vocabulary and, if so, whether it is "truey" or "falsey".
+
+ # Returns:
+ - `-1` - `s` is null;
+ - `0` - string is not classified as "truthy";
+ - `1` - string is classified as "truthy", and, if `out` is not null,
+   `*out` is set to `1` if it is "truey" and to `0` if it is "falsey";
+
+ # Safety
+
+ `s` must be null or point to a nul-terminated string, and `out` must be
+ null or point to a writable `int`.
+ */
+int to_be_string_is_truthy(const char *s, int *out);
+
+/*
+ Indicates that the given string, when trimmed, is deemed as "falsey"
+ according to the default vocabulary.
+
+ # Returns:
+ `1` if so, otherwise (including if `s` is null) `0`.
+
+ # Safety
+
+ `s` must be null or point to a nul-terminated string.
+ */
+int to_be_string_is_falsey(const char *s);
+
+/*
+ Indicates that the given string, when trimmed, is deemed as "truey"
+ according to the default vocabulary.
+
+ # Returns:
+ `1` if so, otherwise (including if `s` is null) `0`.
+
+ # Safety
+
+ `s` must be null or point to a nul-terminated string.
+ */
+int to_be_string_is_truey(const char *s);
+
+/*
+ Creates custom terms from the given "falsey" and "truey" term strings,
+ which are matched case-insensitively.
+
+ # Returns:
+ - `NULL` - an array is null (with a non-zero count), or a term string is
+   null or not valid UTF-8;
+ - otherwise, the terms, which must be destroyed by
+   `to_be_terms_destroy()`;
+
+ # Safety
+
+ Each of `falsey_terms` and `truey_terms` must be null (in which case the
+ corresponding count must be 0) or point to the given number of pointers
+ to nul-terminated strings.
+ */
+struct ToBeTerms *to_be_terms_create(const char *const *falsey_terms,
+                                     size_t num_falsey_terms,
+                                     const char *const *truey_terms,
+                                     size_t num_truey_terms);
+
+/*
+ Creates the terms registered for the given locale, whose tag is matched
+ as by `to_be::locale::terms_for_locale()`.
+
+ # Returns:
+ - `NULL` - `tag` is null or not valid UTF-8, or no terms are registered
+   for the locale;
+ - otherwise, the terms, which must be destroyed by
+   `to_be_terms_destroy()`;
+
+ # Safety
+
+ `tag` must be null or point to a nul-terminated string.
+ */
+struct ToBeTerms *to_be_terms_create_for_locale(const char *tag);
+
+/*
+ Destroys the given terms. Does nothing if `terms` is null.
+
+ # Safety
+
+ `terms` must be null or have been returned by `to_be_terms_create()` (or
+ `to_be_terms_create_for_locale()`) and not already destroyed.
+ */
+void to_be_terms_destroy(struct ToBeTerms *terms);
+
+/*
+ Indicates whether the given string, when trimmed, is "truthy" according
+ to the given terms and, if so, whether it is "truey" or "falsey".
+
+ # Returns:
+ - `-1` - `terms` or `s` is null;
+ - `0` - string is not classified as "truthy";
+ - `1` - string is classified as "truthy", and, if `out` is not null,
+   `*out` is set to `1` if it is "truey" and to `0` if it is "falsey";
+
+ # Safety
+
+ `terms` must be null or a live pointer returned by
+ `to_be_terms_create()` (or `to_be_terms_create_for_locale()`), `s` must
+ be null or point to a nul-terminated string, and `out` must be null or
+ point to a writable `int`.
+ */
+int to_be_terms_string_is_truthy(const struct ToBeTerms *terms, const char *s, int *out);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TO_BE_H */
@@ -0,0 +1,440 @@
+// aggregate.rs - combination and counting of the classifications of many values
+
+
+use super::{
+    Truthiness,
+    Truthy,
+};
+
+
+/// How values that are not "truthy" (i.e. unknown) are treated when the
+/// classifications of many values are combined, as by [all_truey()] and
+/// [any_truey()].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum UnknownHandling {
+    /// Unknown values are combined according to Kleene's three-valued
+    /// logic, so that, say, the conjunction of "truey" and unknown values
+    /// is unknown.
+    #[default]
+    Kleene,
+    /// Unknown values are disregarded, so that the combination is that of
+    /// the remaining values.
+    Skip,
+}
+
+/// Combines the classifications of the given values, as by
+/// [Truthy::is_truthy()], with the given operation, which is
+/// short-circuited by the given (absorbing) value.
+fn combine_<I, T>(
+    values : I,
+    unknown_handling : UnknownHandling,
+    identity : Truthiness,
+    absorbing : Truthiness,
+    op : fn(Truthiness, Truthiness) -> Truthiness,
+) -> Truthiness
+where
+    I : IntoIterator<Item = T>,
+    T : Truthy,
+{
+    let mut r = identity;
+
+    for value in values {
+        let truthiness = Truthiness::from(value.is_truthy());
+
+        if Truthiness::Unknown == truthiness && UnknownHandling::Skip == unknown_handling {
+            continue;
+        }
+
+        r = op(r, truthiness);
+
+        if absorbing == r {
+            break;
+        }
+    }
+
+    r
+}
+
+/// Indicates whether all of the given values are "truey", combining their
+/// classifications, as by [Truthy::is_truthy()], by conjunction (as by
+/// [Truthiness::and()]).
+///
+/// Evaluation stops at the first value that is "falsey".
+///
+/// # Returns:
+/// - [Truthiness::False] - any value is "falsey";
+/// - [Truthiness::Unknown] - no value is "falsey", and any value is not
+///   "truthy" and `unknown_handling` is [UnknownHandling::Kleene];
+/// - [Truthiness::True] - otherwise, including where there are no values
+///   (or, with [UnknownHandling::Skip], no "truthy" values);
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(feature = "implement-Truthy-for-bool")]
+/// # {
+/// use to_be::{
+///     all_truey,
+///     Truthiness,
+///     UnknownHandling,
+/// };
+///
+/// let feature_enabled = Some(true);
+/// let tenant_opted_in = None; // e.g. not recognised
+/// let region_allowed = Some(true);
+///
+/// let gates = [feature_enabled, tenant_opted_in, region_allowed];
+///
+/// assert_eq!(Truthiness::Unknown, all_truey(gates, UnknownHandling::Kleene));
+/// assert_eq!(Truthiness::True, all_truey(gates, UnknownHandling::Skip));
+/// # }
+/// ```
+pub fn all_truey<I, T>(
+    values : I,
+    unknown_handling : UnknownHandling,
+) -> Truthiness
+where
+    I : IntoIterator<Item = T>,
+    T : Truthy,
+{
+    combine_(values, unknown_handling, Truthiness::True, Truthiness::False, Truthiness::and)
+}
+
+/// Indicates whether any of the given values is "truey", combining their
+/// classifications, as by [Truthy::is_truthy()], by disjunction (as by
+/// [Truthiness::or()]).
+///
+/// Evaluation stops at the first value that is "truey".
+///
+/// # Returns:
+/// - [Truthiness::True] - any value is "truey";
+/// - [Truthiness::Unknown] - no value is "truey", and any value is not
+///   "truthy" and `unknown_handling` is [UnknownHandling::Kleene];
+/// - [Truthiness::False] - otherwise, including where there are no values
+///   (or, with [UnknownHandling::Skip], no "truthy" values);
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(feature = "implement-Truthy-for-bool")]
+/// # {
+/// use to_be::{
+///     any_truey,
+///     Truthiness,
+///     UnknownHandling,
+/// };
+///
+/// let overrides = [Some(false), None, Some(false)];
+///
+/// assert_eq!(Truthiness::Unknown, any_truey(overrides, UnknownHandling::Kleene));
+/// assert_eq!(Truthiness::False, any_truey(overrides, UnknownHandling::Skip));
+/// assert_eq!(Truthiness::True, any_truey([false, true], UnknownHandling::Kleene));
+/// # }
+/// ```
+pub fn any_truey<I, T>(
+    values : I,
+    unknown_handling : UnknownHandling,
+) -> Truthiness
+where
+    I : IntoIterator<Item = T>,
+    T : Truthy,
+{
+    combine_(values, unknown_handling, Truthiness::False, Truthiness::True, Truthiness::or)
+}
+
+/// Combination of the classifications of the items of an iterator, as by
+/// [all_truey()] and [any_truey()].
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(feature = "implement-Truthy-for-bool")]
+/// # {
+/// use to_be::{
+///     Truthiness,
+///     TruthyIterator,
+///     UnknownHandling,
+/// };
+///
+/// let checks = vec![true, true, false];
+///
+/// assert_eq!(Truthiness::False, checks.iter().all_truey(UnknownHandling::Kleene));
+/// assert_eq!(Truthiness::True, checks.iter().any_truey(UnknownHandling::Kleene));
+/// # }
+/// ```
+pub trait TruthyIterator : Iterator + Sized
+where
+    Self::Item : Truthy,
+{
+    /// Indicates whether all of the items are "truey", as by
+    /// [all_truey()].
+    fn all_truey(
+        self,
+        unknown_handling : UnknownHandling,
+    ) -> Truthiness {
+        all_truey(self, unknown_handling)
+    }
+
+    /// Indicates whether any of the items is "truey", as by
+    /// [any_truey()].
+    fn any_truey(
+        self,
+        unknown_handling : UnknownHandling,
+    ) -> Truthiness {
+        any_truey(self, unknown_handling)
+    }
+}
+
+impl<I> TruthyIterator for I
+where
+    I : Iterator,
+    I::Item : Truthy,
+{
+}
+
+/// The numbers of classifications of each kind among many values,
+/// accumulated in one pass by collecting (or extending with) the
+/// classifications, as either `Option<bool>` or [Truthiness].
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     string_is_truthy_with,
+///     Terms,
+///     TruthyStats,
+/// };
+///
+/// let terms = Terms::Strings {
+///     falsey_precise_strings :   &[],
+///     falsey_lowercase_strings : &["nein"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["ja"],
+/// };
+///
+/// let values = ["ja", "nein", "JA", "vielleicht"];
+///
+/// let mut stats : TruthyStats = values.iter().map(|s| string_is_truthy_with(s, terms.clone())).collect();
+///
+/// assert_eq!(2, stats.truey);
+/// assert_eq!(1, stats.falsey);
+/// assert_eq!(1, stats.unknown);
+///
+/// stats.extend([Some(false), None]);
+///
+/// assert_eq!(6, stats.total());
+/// ```
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct TruthyStats {
+    /// The number of "truey" classifications.
+    pub truey :   usize,
+    /// The number of "falsey" classifications.
+    pub falsey :  usize,
+    /// The number of unknown (i.e. not "truthy") classifications.
+    pub unknown : usize,
+}
+
+impl TruthyStats {
+    /// Counts the given classification.
+    pub fn record(
+        &mut self,
+        classification : Option<bool>,
+    ) {
+        match classification {
+            Some(true) => self.truey += 1,
+            Some(false) => self.falsey += 1,
+            None => self.unknown += 1,
+        };
+    }
+
+    /// The total number of classifications.
+    pub fn total(&self) -> usize {
+        self.truey + self.falsey + self.unknown
+    }
+}
+
+impl Extend<Option<bool>> for TruthyStats {
+    fn extend<I>(
+        &mut self,
+        iter : I,
+    ) where
+        I : IntoIterator<Item = Option<bool>>,
+    {
+        iter.into_iter().for_each(|classification| self.record(classification));
+    }
+}
+
+impl Extend<Truthiness> for TruthyStats {
+    fn extend<I>(
+        &mut self,
+        iter : I,
+    ) where
+        I : IntoIterator<Item = Truthiness>,
+    {
+        self.extend(iter.into_iter().map(Option::<bool>::from));
+    }
+}
+
+impl FromIterator<Option<bool>> for TruthyStats {
+    fn from_iter<I>(iter : I) -> Self
+    where
+        I : IntoIterator<Item = Option<bool>>,
+    {
+        let mut stats = Self::default();
+
+        stats.extend(iter);
+
+        stats
+    }
+}
+
+impl FromIterator<Truthiness> for TruthyStats {
+    fn from_iter<I>(iter : I) -> Self
+    where
+        I : IntoIterator<Item = Truthiness>,
+    {
+        let mut stats = Self::default();
+
+        stats.extend(iter);
+
+        stats
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    const ALL : [Option<bool>; 3] = [Some(false), None, Some(true)];
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    #[test]
+    fn TEST_all_truey_and_any_truey_EMPTY() {
+        for unknown_handling in [UnknownHandling::Kleene, UnknownHandling::Skip] {
+            assert_eq!(Truthiness::True, all_truey(Vec::<bool>::new(), unknown_handling));
+            assert_eq!(Truthiness::False, any_truey(Vec::<bool>::new(), unknown_handling));
+        }
+
+        assert_eq!(Truthiness::True, all_truey([None::<bool>, None], UnknownHandling::Skip));
+        assert_eq!(Truthiness::False, any_truey([None::<bool>, None], UnknownHandling::Skip));
+    }
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    #[test]
+    fn TEST_all_truey_and_any_truey_KLEENE() {
+        for lhs in ALL {
+            for rhs in ALL {
+                let (l, r) = (Truthiness::from(lhs), Truthiness::from(rhs));
+
+                assert_eq!(l.and(r), all_truey([lhs, rhs], UnknownHandling::Kleene), "{lhs:?} && {rhs:?}");
+                assert_eq!(l.or(r), any_truey([lhs, rhs], UnknownHandling::Kleene), "{lhs:?} || {rhs:?}");
+            }
+        }
+    }
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    #[test]
+    fn TEST_all_truey_and_any_truey_SKIP() {
+        assert_eq!(Truthiness::True, all_truey([Some(true), None], UnknownHandling::Skip));
+        assert_eq!(Truthiness::False, all_truey([None, Some(false), Some(true)], UnknownHandling::Skip));
+        assert_eq!(Truthiness::True, any_truey([None, Some(true)], UnknownHandling::Skip));
+        assert_eq!(Truthiness::False, any_truey([Some(false), None], UnknownHandling::Skip));
+    }
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    #[test]
+    fn TEST_all_truey_and_any_truey_SHORT_CIRCUIT() {
+        let mut evaluated = 0;
+
+        let r = all_truey(
+            [Some(true), Some(false), None].into_iter().inspect(|_| evaluated += 1),
+            UnknownHandling::Kleene,
+        );
+
+        assert_eq!(Truthiness::False, r);
+        assert_eq!(2, evaluated);
+
+        evaluated = 0;
+
+        let r = any_truey(
+            [None, Some(true), Some(false)].into_iter().inspect(|_| evaluated += 1),
+            UnknownHandling::Skip,
+        );
+
+        assert_eq!(Truthiness::True, r);
+        assert_eq!(2, evaluated);
+    }
+
+    #[cfg(feature = "implement-Truthy-for-bool")]
+    #[test]
+    fn TEST_TruthyIterator() {
+        let values = vec![Some(true), None, Some(true)];
+
+        assert_eq!(Truthiness::Unknown, values.iter().all_truey(UnknownHandling::default()));
+        assert_eq!(Truthiness::True, values.iter().all_truey(UnknownHandling::Skip));
+        assert_eq!(Truthiness::True, values.into_iter().any_truey(UnknownHandling::Kleene));
+        assert_eq!(Truthiness::False, [false, false].into_iter().any_truey(UnknownHandling::Kleene));
+    }
+
+    #[test]
+    fn TEST_TruthyStats() {
+        let stats : TruthyStats = [Some(true), None, Some(false), Some(true)].into_iter().collect();
+
+        assert_eq!(
+            TruthyStats {
+                truey :   2,
+                falsey :  1,
+                unknown : 1,
+            },
+            stats
+        );
+        assert_eq!(4, stats.total());
+
+        let stats : TruthyStats = [Truthiness::Unknown, Truthiness::False].into_iter().collect();
+
+        assert_eq!(
+            TruthyStats {
+                truey :   0,
+                falsey :  1,
+                unknown : 1,
+            },
+            stats
+        );
+
+        let stats : TruthyStats = std::iter::empty::<Option<bool>>().collect();
+
+        assert_eq!(TruthyStats::default(), stats);
+        assert_eq!(0, stats.total());
+    }
+
+    #[test]
+    fn TEST_TruthyStats_extend() {
+        let mut stats = TruthyStats::default();
+
+        stats.extend([Some(true), Some(true)]);
+        stats.extend([Truthiness::False, Truthiness::Unknown, Truthiness::True]);
+        stats.record(None);
+
+        assert_eq!(3, stats.truey);
+        assert_eq!(1, stats.falsey);
+        assert_eq!(2, stats.unknown);
+        assert_eq!(6, stats.total());
+    }
+}
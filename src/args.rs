@@ -0,0 +1,275 @@
+// args.rs - scanning of argument lists for boolean flags
+
+//! Scanning of command-line argument lists for boolean flags, with
+//! consistent (truthy) semantics - including GNU-style negation, in which
+//! `--no-name` is the negation of `--name` - for small programs that do
+//! not warrant a full argument parser.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::args::args_flag;
+//!
+//! let verbose = args_flag(std::env::args(), "verbose").unwrap_or(false);
+//! ```
+
+use super::string_is_truthy;
+
+
+/// The spelling in which a flag is given.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum FlagSpelling {
+    /// The flag is given by its name, e.g. `--foo`.
+    Positive,
+    /// The flag is given by its negated name, e.g. `--no-foo`.
+    Negated,
+}
+
+/// Obtains the negation of the given flag name - which may be given with or
+/// without the leading `"--"`, which is preserved - by adding the `"no-"`
+/// prefix or, if it is already present, removing it.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::args::negated_name;
+///
+/// assert_eq!("no-color", negated_name("color"));
+/// assert_eq!("--no-color", negated_name("--color"));
+/// assert_eq!("--color", negated_name("--no-color"));
+/// ```
+pub fn negated_name(name : &str) -> String {
+    let (dashes, name) = match name.strip_prefix("--") {
+        Some(name) => ("--", name),
+        None => ("", name),
+    };
+
+    match name.strip_prefix("no-") {
+        Some(positive) => format!("{dashes}{positive}"),
+        None => format!("{dashes}no-{name}"),
+    }
+}
+
+/// Matches the given argument, without its leading `"--"`, against the
+/// given name.
+///
+/// # Returns:
+/// - `None` - argument does not match;
+/// - `Some(None)` - argument is the bare name;
+/// - `Some(Some(value))` - argument is of the form `name=value`;
+fn match_flag_<'s>(
+    body : &'s str,
+    name : &str,
+) -> Option<Option<&'s str>> {
+    let rest = body.strip_prefix(name)?;
+
+    if rest.is_empty() {
+        Some(None)
+    } else {
+        rest.strip_prefix('=').map(Some)
+    }
+}
+
+/// Obtains the value of the flag of the given name - which may be given
+/// with or without the leading `"--"` - from the given arguments.
+///
+/// The flag may be given as:
+/// - `--name`, which is "truey";
+/// - `--name=value`, where `value` is classified as by
+///   [string_is_truthy()](crate::string_is_truthy());
+/// - `--name value`, where `value` is classified likewise, if it is
+///   recognised, and is otherwise deemed to be the next argument (so that
+///   the flag is bare, and so "truey");
+///
+/// or in any of these forms of its negated name (see [negated_name()]),
+/// e.g. `--no-name`, in which case the value is negated.
+///
+/// If the flag is given more than once, in either spelling, the last
+/// occurrence prevails, and arguments following `--` are not scanned.
+///
+/// # Returns:
+/// - `None` - flag is not given, or its (last) value, given as
+///   `--name=value`, is not recognised;
+/// - `Some(false)` - flag is "falsey";
+/// - `Some(true)` - flag is "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::args::args_flag;
+///
+/// let args = ["prog", "--verbose", "--color=off", "--debug", "yes", "--no-cache", "input.txt", "--", "--dry-run"];
+///
+/// assert_eq!(Some(true), args_flag(args, "verbose"));
+/// assert_eq!(Some(false), args_flag(args, "cache"));
+/// assert_eq!(None, args_flag(args, "dry-run"));
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!(Some(false), args_flag(args, "--color"));
+/// assert_eq!(Some(true), args_flag(args, "debug"));
+/// # }
+/// ```
+pub fn args_flag<I>(
+    args : I,
+    name : &str,
+) -> Option<bool>
+where
+    I : IntoIterator,
+    I::Item : AsRef<str>,
+{
+    args_flag_with_spelling(args, name).map(|(_, b)| b)
+}
+
+/// Obtains the value of the flag of the given name, as by [args_flag()],
+/// along with the spelling of its (last) occurrence.
+///
+/// # Returns:
+/// - `None` - flag is not given, or its (last) value, given as
+///   `--name=value`, is not recognised;
+/// - `Some((spelling, false))` - flag is "falsey";
+/// - `Some((spelling, true))` - flag is "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::args::{
+///     args_flag_with_spelling,
+///     FlagSpelling,
+/// };
+///
+/// assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--no-cache"], "cache"));
+/// assert_eq!(Some((FlagSpelling::Positive, true)), args_flag_with_spelling(["--no-cache", "--cache"], "cache"));
+/// ```
+pub fn args_flag_with_spelling<I>(
+    args : I,
+    name : &str,
+) -> Option<(FlagSpelling, bool)>
+where
+    I : IntoIterator,
+    I::Item : AsRef<str>,
+{
+    let name = name.strip_prefix("--").unwrap_or(name);
+    let negated = negated_name(name);
+    let mut args = args.into_iter().peekable();
+    let mut flag = None;
+
+    while let Some(arg) = args.next() {
+        let arg = arg.as_ref();
+
+        if "--" == arg {
+            break;
+        }
+
+        let Some(body) = arg.strip_prefix("--") else {
+            continue;
+        };
+
+        let (spelling, value) = if let Some(value) = match_flag_(body, name) {
+            (FlagSpelling::Positive, value)
+        } else if let Some(value) = match_flag_(body, &negated) {
+            (FlagSpelling::Negated, value)
+        } else {
+            continue;
+        };
+
+        let b = match value {
+            Some(value) => string_is_truthy(value),
+            None => match args.peek().and_then(|next| string_is_truthy(next.as_ref())) {
+                Some(b) => {
+                    args.next();
+
+                    Some(b)
+                },
+                None => Some(true),
+            },
+        };
+
+        flag = b.map(|b| (spelling, b != (FlagSpelling::Negated == spelling)));
+    }
+
+    flag
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_args_flag_BARE() {
+        assert_eq!(Some(true), args_flag(["prog", "--x"], "x"));
+        assert_eq!(Some(true), args_flag(["--x", "file"], "x"));
+        assert_eq!(Some(true), args_flag(vec!["--x".to_owned()], "--x"));
+        assert_eq!(None, args_flag(["prog", "-x", "x", "--xy", "--x-y"], "x"));
+        assert_eq!(None, args_flag(["prog", "--", "--x"], "x"));
+        assert_eq!(None, args_flag(Vec::<String>::new(), "x"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_args_flag_VALUES() {
+        assert_eq!(Some(false), args_flag(["--x=0"], "x"));
+        assert_eq!(Some(false), args_flag(["--x", "0", "file"], "x"));
+        assert_eq!(Some(true), args_flag(["--x", "1"], "x"));
+        assert_eq!(None, args_flag(["--x="], "x"));
+        assert_eq!(None, args_flag(["--x=2"], "x"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_args_flag_LAST_PREVAILS() {
+        assert_eq!(Some(false), args_flag(["--x", "--y", "--x=0"], "x"));
+        assert_eq!(Some(true), args_flag(["--x=0", "--x"], "x"));
+        assert_eq!(Some(true), args_flag(["--x=2", "--x=1"], "x"));
+        assert_eq!(None, args_flag(["--x=1", "--x=2"], "x"));
+    }
+
+    #[test]
+    fn TEST_negated_name() {
+        assert_eq!("no-x", negated_name("x"));
+        assert_eq!("x", negated_name("no-x"));
+        assert_eq!("--no-x", negated_name("--x"));
+        assert_eq!("--x", negated_name("--no-x"));
+        assert_eq!("no-", negated_name(""));
+        assert_eq!("no-nox", negated_name("nox"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_args_flag_with_spelling() {
+        assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--no-x"], "x"));
+        assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--no-x", "file"], "--x"));
+        assert_eq!(Some((FlagSpelling::Negated, true)), args_flag_with_spelling(["--no-x=0"], "x"));
+        assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--no-x", "1"], "x"));
+        assert_eq!(Some((FlagSpelling::Positive, true)), args_flag_with_spelling(["--no-x", "--x"], "x"));
+        assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--x", "--no-x"], "x"));
+        assert_eq!(None, args_flag_with_spelling(["--no-x=2"], "x"));
+        assert_eq!(None, args_flag_with_spelling(["--no-xy", "-no-x", "--", "--no-x"], "x"));
+
+        // the name may itself be the negated spelling
+
+        assert_eq!(Some((FlagSpelling::Positive, true)), args_flag_with_spelling(["--no-x"], "no-x"));
+        assert_eq!(Some((FlagSpelling::Negated, false)), args_flag_with_spelling(["--x"], "no-x"));
+
+        assert_eq!(Some(false), args_flag(["--x", "--no-x"], "x"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_args_flag_TERMS() {
+        let args : Vec<String> =
+            ["prog", "--a", "No", "--b=TRUE", "--c", "maybe"].iter().map(|&s| s.to_owned()).collect();
+
+        assert_eq!(Some(false), args_flag(args.iter(), "a"));
+        assert_eq!(Some(true), args_flag(args.iter(), "b"));
+        assert_eq!(Some(true), args_flag(args.clone(), "c"));
+    }
+}
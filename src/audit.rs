@@ -0,0 +1,310 @@
+// audit.rs - an audit log of classifications
+
+//! An evaluator that records every classification it makes - with its
+//! context (e.g. the configuration key), input, result, matched term, and
+//! time - in a bounded, in-memory log, so that, say, the decisions made
+//! when loading configuration at startup may be dumped and reviewed.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::{
+//!     audit::AuditingEvaluator,
+//!     Terms,
+//! };
+//!
+//! let evaluator = AuditingEvaluator::new(Terms::Default, 100);
+//!
+//! let verbose = evaluator.classify("verbose", " Yes ");
+//! let daemon = evaluator.classify("daemon", "maybe");
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! assert_eq!(Some(true), verbose);
+//! assert_eq!(None, daemon);
+//!
+//! let records = evaluator.records();
+//!
+//! assert_eq!(2, records.len());
+//! assert_eq!("verbose", records[0].context);
+//! assert_eq!(Some("Yes".to_owned()), records[0].matched_term);
+//!
+//! // e.g. "1767225600.000 verbose \" Yes \" -> truey (matched \"Yes\")"
+//! println!("{}", evaluator.report());
+//! # }
+//! ```
+
+use super::{
+    diagnostics::matched_term_with,
+    string_is_truthy_with,
+    Terms,
+};
+
+use std::{
+    collections::VecDeque,
+    fmt::{
+        self,
+        Write as _,
+    },
+    sync::Mutex,
+    time::{
+        SystemTime,
+        UNIX_EPOCH,
+    },
+};
+
+
+/// A record of a single classification.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct AuditRecord {
+    /// The context of the classification, e.g. the configuration key.
+    pub context :      String,
+    /// The (untrimmed) input.
+    pub input :        String,
+    /// The classification.
+    pub result :       Option<bool>,
+    /// The term matched by the input, if any.
+    pub matched_term : Option<String>,
+    /// The time of the classification.
+    pub timestamp :    SystemTime,
+}
+
+impl fmt::Display for AuditRecord {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let since_epoch = self.timestamp.duration_since(UNIX_EPOCH).unwrap_or_default();
+
+        write!(
+            f,
+            "{}.{:03} {} {:?} -> ",
+            since_epoch.as_secs(),
+            since_epoch.subsec_millis(),
+            self.context,
+            self.input
+        )?;
+
+        match (self.result, &self.matched_term) {
+            (None, _) => write!(f, "not truthy"),
+            (Some(truey), Some(term)) => write!(f, "{} (matched {term:?})", if truey { "truey" } else { "falsey" }),
+            (Some(truey), None) => write!(f, "{}", if truey { "truey" } else { "falsey" }),
+        }
+    }
+}
+
+/// The log, holding at most `capacity` records, along with the number of
+/// (oldest) records discarded to honour the capacity.
+#[derive(Debug)]
+struct AuditLog {
+    records :   VecDeque<AuditRecord>,
+    discarded : usize,
+}
+
+/// An evaluator that classifies against the given terms, as by
+/// [string_is_truthy_with()], recording each classification in a bounded
+/// log, from which the oldest records are discarded once it is full.
+#[derive(Debug)]
+pub struct AuditingEvaluator<'a> {
+    terms :    Terms<'a>,
+    capacity : usize,
+    log :      Mutex<AuditLog>,
+}
+
+impl<'a> AuditingEvaluator<'a> {
+    /// Creates an instance that classifies against the given terms and
+    /// holds at most the given number of records.
+    pub fn new(
+        terms : Terms<'a>,
+        capacity : usize,
+    ) -> Self {
+        Self {
+            terms,
+            capacity,
+            log : Mutex::new(AuditLog {
+                records :   VecDeque::with_capacity(capacity.min(1024)),
+                discarded : 0,
+            }),
+        }
+    }
+
+    /// The maximum number of records held.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    fn lock_(&self) -> std::sync::MutexGuard<'_, AuditLog> {
+        self.log.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Classifies the given input, in the given context, recording the
+    /// classification.
+    ///
+    /// # Returns:
+    /// - `None` - input is not classified as "truthy";
+    /// - `Some(false)` - input (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - input (is classified as "truthy" and) is deemed
+    ///   "truey";
+    pub fn classify(
+        &self,
+        context : &str,
+        input : &str,
+    ) -> Option<bool> {
+        let result = string_is_truthy_with(input, self.terms.clone());
+        let matched_term = result.and(matched_term_with(input, &self.terms)).map(|(_, term)| term.to_owned());
+
+        let record = AuditRecord {
+            context : context.to_owned(),
+            input : input.to_owned(),
+            result,
+            matched_term,
+            timestamp : SystemTime::now(),
+        };
+
+        let mut log = self.lock_();
+
+        if 0 == self.capacity {
+            log.discarded += 1;
+        } else {
+            if log.records.len() == self.capacity {
+                log.records.pop_front();
+                log.discarded += 1;
+            }
+
+            log.records.push_back(record);
+        }
+
+        result
+    }
+
+    /// Obtains a copy of the records, oldest first.
+    pub fn records(&self) -> Vec<AuditRecord> {
+        self.lock_().records.iter().cloned().collect()
+    }
+
+    /// The number of records discarded, since creation or the last
+    /// [AuditingEvaluator::clear()], to honour the capacity.
+    pub fn discarded(&self) -> usize {
+        self.lock_().discarded
+    }
+
+    /// Discards all records, and resets the number discarded.
+    pub fn clear(&self) {
+        let mut log = self.lock_();
+
+        log.records.clear();
+        log.discarded = 0;
+    }
+
+    /// Formats the records as a report, one line per record, oldest first,
+    /// preceded by a line stating the number discarded, if any.
+    pub fn report(&self) -> String {
+        let log = self.lock_();
+        let mut report = String::new();
+
+        if 0 != log.discarded {
+            let _ = writeln!(report, "({} earlier records discarded)", log.discarded);
+        }
+
+        for record in &log.records {
+            let _ = writeln!(report, "{record}");
+        }
+
+        report
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::time::Duration;
+
+
+    fn terms_() -> Terms<'static> {
+        Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["nein"],
+            truey_precise_strings :    &["JA"],
+            truey_lowercase_strings :  &["ja"],
+        }
+    }
+
+    #[test]
+    fn TEST_AuditingEvaluator_classify() {
+        let evaluator = AuditingEvaluator::new(terms_(), 10);
+
+        assert_eq!(Some(true), evaluator.classify("a", "JA"));
+        assert_eq!(Some(true), evaluator.classify("b", " Ja "));
+        assert_eq!(Some(false), evaluator.classify("c", "NEIN"));
+        assert_eq!(None, evaluator.classify("d", "vielleicht"));
+
+        let records = evaluator.records();
+        let summary : Vec<_> =
+            records.iter().map(|r| (r.context.as_str(), r.result, r.matched_term.as_deref())).collect();
+
+        assert_eq!(
+            vec![
+                ("a", Some(true), Some("JA")),
+                ("b", Some(true), Some("ja")),
+                ("c", Some(false), Some("nein")),
+                ("d", None, None),
+            ],
+            summary
+        );
+        assert_eq!(" Ja ", records[1].input);
+        assert!(records.windows(2).all(|w| w[0].timestamp <= w[1].timestamp));
+        assert_eq!(0, evaluator.discarded());
+    }
+
+    #[test]
+    fn TEST_AuditingEvaluator_CAPACITY() {
+        let evaluator = AuditingEvaluator::new(terms_(), 2);
+
+        for input in ["ja", "nein", "ja", "x"] {
+            evaluator.classify("key", input);
+        }
+
+        assert_eq!(2, evaluator.capacity());
+        assert_eq!(vec!["ja", "x"], evaluator.records().into_iter().map(|r| r.input).collect::<Vec<_>>());
+        assert_eq!(2, evaluator.discarded());
+        assert!(evaluator.report().starts_with("(2 earlier records discarded)\n"));
+
+        evaluator.clear();
+
+        assert!(evaluator.records().is_empty());
+        assert_eq!(0, evaluator.discarded());
+        assert_eq!("", evaluator.report());
+
+        let evaluator = AuditingEvaluator::new(terms_(), 0);
+
+        assert_eq!(Some(true), evaluator.classify("key", "ja"));
+        assert!(evaluator.records().is_empty());
+        assert_eq!(1, evaluator.discarded());
+    }
+
+    #[test]
+    fn TEST_AuditRecord_Display() {
+        let mut record = AuditRecord {
+            context :      "verbose".to_owned(),
+            input :        " Ja ".to_owned(),
+            result :       Some(true),
+            matched_term : Some("ja".to_owned()),
+            timestamp :    UNIX_EPOCH + Duration::from_millis(1_700_000_000_042),
+        };
+
+        assert_eq!("1700000000.042 verbose \" Ja \" -> truey (matched \"ja\")", record.to_string());
+
+        record.result = None;
+        record.matched_term = None;
+
+        assert_eq!("1700000000.042 verbose \" Ja \" -> not truthy", record.to_string());
+    }
+}
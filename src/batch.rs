@@ -0,0 +1,354 @@
+// batch.rs - classification of many strings at a time
+
+//! Classification of many strings at a time, such as the values of a
+//! column of tabular data.
+//!
+//! A [BatchClassifier] prepares, once, the packed forms of the terms that
+//! are ASCII and no longer than 8 bytes, bucketed by length. Each string
+//! that is (when trimmed) ASCII and no longer than 8 bytes is then packed
+//! into a `u64`, compared against the precise terms of its length, and then
+//! case-folded eight bytes at a time (i.e. SIMD-within-a-register, or SWAR)
+//! and compared against the lowercase terms of its length, without any
+//! allocation or per-character work.
+//!
+//! Strings that are longer than 8 bytes, or that contain non-ASCII
+//! characters, fall back to the same (per-string) classification as
+//! [string_is_truthy_with()](crate::string_is_truthy_with()). Since terms
+//! that cannot be packed can match only such strings, the results are
+//! always identical to those of classifying each string individually.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::batch::strings_are_truthy;
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! let column = ["true", "0", "Yes", "perhaps"];
+//!
+//! assert_eq!(vec![Some(true), Some(false), Some(true), None], strings_are_truthy(column));
+//! # }
+//! ```
+
+
+use super::{
+    defaults,
+    string_is_truthy_with_,
+    Terms,
+};
+
+
+/// The maximum length of the (ASCII) strings that are classified by packed
+/// comparison, i.e. the number of bytes in a `u64`.
+const PACKED_MAX_LEN : usize = 8;
+
+const ONES : u64 = 0x0101_0101_0101_0101;
+const HIGH_BITS : u64 = 0x8080_8080_8080_8080;
+
+/// Packs the given bytes, which must number no more than [PACKED_MAX_LEN],
+/// into a `u64`, padded with zero bytes.
+#[inline]
+fn pack_(bytes : &[u8]) -> u64 {
+    let mut buf = [0u8; PACKED_MAX_LEN];
+
+    buf[..bytes.len()].copy_from_slice(bytes);
+
+    u64::from_le_bytes(buf)
+}
+
+/// Lowercases each of the ASCII uppercase letters packed into the given
+/// `u64`, eight at a time (i.e. SIMD-within-a-register).
+#[inline]
+fn lowercase_packed_(x : u64) -> u64 {
+    let heptets = x & !HIGH_BITS;
+    let ge_a = heptets + (0x80 - u64::from(b'A')) * ONES;
+    let gt_z = heptets + (0x80 - u64::from(b'Z') - 1) * ONES;
+    let is_upper = ge_a & !gt_z & !x & HIGH_BITS;
+
+    x | (is_upper >> 2)
+}
+
+/// The packed forms of the (ASCII) terms of a given length, each with
+/// whether it is "truey", in precedence order.
+type Bucket = Vec<(u64, bool)>;
+
+/// Obtains, for each length, the packed - and, optionally, folded - forms
+/// of the given terms, "falsey" before "truey".
+fn buckets_(
+    falsey_strings : &[&str],
+    truey_strings : &[&str],
+    fold : bool,
+) -> [Bucket; PACKED_MAX_LEN + 1] {
+    let mut buckets : [Bucket; PACKED_MAX_LEN + 1] = Default::default();
+
+    for (truey, strings) in [(false, falsey_strings), (true, truey_strings)] {
+        // terms that are non-ASCII or too long can match only strings that
+        // are themselves non-ASCII or too long, which are classified by
+        // `string_is_truthy_with()`
+
+        for term in strings.iter().filter(|term| term.is_ascii() && term.len() <= PACKED_MAX_LEN) {
+            let packed = pack_(term.as_bytes());
+            let packed = if fold { lowercase_packed_(packed) } else { packed };
+
+            buckets[term.len()].push((packed, truey));
+        }
+    }
+
+    buckets
+}
+
+/// Classifies many strings against a given set of terms.
+///
+/// Strings that are (when trimmed) ASCII and no longer than 8 bytes - i.e.
+/// almost all of those encountered in practice - are packed into a `u64`,
+/// case-folded eight bytes at a time, and compared against the packed forms
+/// of just those terms of the same length; all others are classified as by
+/// [string_is_truthy_with()](crate::string_is_truthy_with()).
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     batch::BatchClassifier,
+///     Terms,
+/// };
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// let classifier = BatchClassifier::new(Terms::Default);
+/// let mut results = Vec::new();
+///
+/// classifier.classify_into(["yes", " OFF", "maybe"], &mut results);
+///
+/// assert_eq!([Some(true), Some(false), None], results[..]);
+/// # }
+/// ```
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct BatchClassifier<'a> {
+    terms :           Terms<'a>,
+    precise_buckets : [Bucket; PACKED_MAX_LEN + 1],
+    folded_buckets :  [Bucket; PACKED_MAX_LEN + 1],
+}
+
+impl<'a> BatchClassifier<'a> {
+    /// Creates a classifier for the given terms.
+    pub fn new(terms : Terms<'a>) -> Self {
+        let (falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings) =
+            match terms {
+                Terms::Default => (
+                    &defaults::FALSEY_PRECISE_STRINGS[..],
+                    &defaults::FALSEY_LOWERCASE_STRINGS[..],
+                    &defaults::TRUEY_PRECISE_STRINGS[..],
+                    &defaults::TRUEY_LOWERCASE_STRINGS[..],
+                ),
+                Terms::Strings {
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                }
+                | Terms::SortedStrings {
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                } => (
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                ),
+            };
+
+        let precise_buckets = buckets_(falsey_precise_strings, truey_precise_strings, false);
+        let folded_buckets = buckets_(falsey_lowercase_strings, truey_lowercase_strings, true);
+
+        Self {
+            terms,
+            precise_buckets,
+            folded_buckets,
+        }
+    }
+
+    /// Indicates whether the given string is "truthy" and, if so, whether
+    /// it is "truey" or "falsey".
+    ///
+    /// # Returns:
+    /// - `None` - string is not classified as "truthy";
+    /// - `Some(false)` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - string (is classified as "truthy" and) is deemed
+    ///   "truey";
+    #[inline]
+    pub fn classify(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        let r = self.classify_(s);
+
+        record_classification_!(crate::metrics::Evaluator::Batch, r);
+        report_unrecognised_!("BatchClassifier", s, r);
+
+        r
+    }
+
+    #[inline]
+    fn classify_(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        let t = s.trim().as_bytes();
+
+        if t.len() > PACKED_MAX_LEN || !t.is_ascii() {
+            return string_is_truthy_with_(
+                s,
+                self.terms.clone(),
+                defaults::FALSEY_PRECISE_STRINGS,
+                defaults::FALSEY_LOWERCASE_STRINGS,
+                defaults::TRUEY_PRECISE_STRINGS,
+                defaults::TRUEY_LOWERCASE_STRINGS,
+            );
+        }
+
+        let packed = pack_(t);
+
+        if let Some(&(_, truey)) = self.precise_buckets[t.len()].iter().find(|&&(p, _)| p == packed) {
+            return Some(truey);
+        }
+
+        let packed = lowercase_packed_(packed);
+
+        self.folded_buckets[t.len()]
+            .iter()
+            .find(|&&(p, _)| p == packed)
+            .map(|&(_, truey)| truey)
+    }
+
+    /// Classifies each of the given strings, appending the results, in
+    /// order, to `results`.
+    pub fn classify_into<'s, I>(
+        &self,
+        strings : I,
+        results : &mut Vec<Option<bool>>,
+    ) where
+        I : IntoIterator<Item = &'s str>,
+    {
+        let strings = strings.into_iter();
+
+        results.reserve(strings.size_hint().0);
+        results.extend(strings.map(|s| self.classify(s)));
+    }
+}
+
+/// Classifies each of the given strings against the default vocabulary.
+///
+/// # Returns:
+/// The classifications, in order, as by
+/// [string_is_truthy()](crate::string_is_truthy()).
+pub fn strings_are_truthy<'s, I>(strings : I) -> Vec<Option<bool>>
+where
+    I : IntoIterator<Item = &'s str>,
+{
+    let mut results = Vec::new();
+
+    BatchClassifier::new(Terms::Default).classify_into(strings, &mut results);
+
+    results
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::{
+        preset::Preset,
+        string_is_truthy_with,
+    };
+
+
+    const INPUTS : &[&str] = &[
+        "",
+        " ",
+        "0",
+        "1",
+        "no",
+        "No",
+        "NO",
+        "nO",
+        " yes ",
+        "\u{b}yes\u{b}",
+        "True",
+        "tRuE",
+        "OFF",
+        "on",
+        "y",
+        "Y",
+        "n",
+        "maybe",
+        "yess",
+        "Nyet",
+        "NYET",
+        "da",
+        "Da",
+        "DA",
+        "YUP",
+        "verylongterm",
+        "To Be",
+        "not to be",
+        "NOT TO BE ",
+        "VERYLONGTERM",
+        "ＹＥＳ",
+        "Ça",
+        "@",
+        "[",
+        "`",
+        "{",
+    ];
+
+
+    #[test]
+    fn TEST_lowercase_packed_() {
+        assert_eq!(pack_(b"abcxyz@["), lowercase_packed_(pack_(b"ABCXYZ@[")));
+        assert_eq!(pack_(b"`{09 az"), lowercase_packed_(pack_(b"`{09 az")));
+        assert_eq!(pack_(b"yes"), lowercase_packed_(pack_(b"YeS")));
+        assert_eq!(0, lowercase_packed_(0));
+    }
+
+    #[test]
+    fn TEST_BatchClassifier_MATCHES_string_is_truthy_with() {
+        for terms in [
+            Terms::Default,
+            Preset::Fortran.terms(),
+            Preset::Hdl.terms(),
+            Preset::Spreadsheet.terms(),
+            Preset::Sql.terms(),
+            Preset::Statistical.terms(),
+            Preset::ToBeOrNotToBe.terms(),
+            Preset::XsBoolean.terms(),
+            Preset::Yaml11.terms(),
+            Terms::Strings {
+                falsey_precise_strings :   &["NYET", "Nyet"],
+                falsey_lowercase_strings : &["nyet", "Nope", "verylongterm"],
+                truey_precise_strings :    &["Da", "NYET"],
+                truey_lowercase_strings :  &["da", "YUP", "ça"],
+            },
+        ] {
+            let classifier = BatchClassifier::new(terms.clone());
+
+            for s in INPUTS {
+                assert_eq!(string_is_truthy_with(s, terms.clone()), classifier.classify(s), "'{s}' ({terms:?})");
+            }
+        }
+    }
+
+    #[test]
+    fn TEST_strings_are_truthy() {
+        let expected : Vec<_> = INPUTS.iter().map(|s| crate::string_is_truthy(s)).collect();
+
+        assert_eq!(expected, strings_are_truthy(INPUTS.iter().copied()));
+    }
+}
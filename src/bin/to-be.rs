@@ -0,0 +1,486 @@
+// to-be.rs - command-line classification of strings
+
+use to_be::{
+    diagnostics,
+    locale,
+    preset::Preset,
+    CompiledTerms,
+    Strictness,
+    Terms,
+};
+
+use std::{
+    env,
+    io::{
+        self,
+        BufRead as _,
+    },
+    fmt,
+    process::ExitCode,
+};
+
+
+const EXIT_USAGE : u8 = 64;
+const EXIT_IOERR : u8 = 74;
+
+const USAGE : &str = "\
+USAGE:
+    to-be [OPTIONS] [--] [VALUE ...]
+    to-be filter [OPTIONS] [FILTER-OPTIONS]
+
+Classifies each VALUE - or, if none is given, each line of standard input -
+printing `true`, `false`, or `unknown` for each.
+
+The exit status is 0 if all values are \"truey\", 1 if any value is
+\"falsey\" (and none is unknown), and 2 if any value is unknown (or there
+are no values).
+
+In filter mode, each line of standard input - or the given field of each
+line - is classified, and only those lines that are \"truey\" (or, as
+selected, \"falsey\" or unknown) are passed through to standard output. The
+exit status is 0 if any line is passed through, and 1 otherwise.
+
+OPTIONS:
+    -t, --truey TERM     adds TERM to the custom \"truey\" terms
+    -f, --falsey TERM    adds TERM to the custom \"falsey\" terms
+    -p, --preset NAME    uses the named preset (fortran, hdl,
+                         spreadsheet, sql, statistical,
+                         to-be-or-not-to-be, xs-boolean, yaml11)
+    -l, --locale TAG     uses the terms of the given locale (e.g. en, fr-CA)
+    -s, --strictness NAME
+                         classifies with the named strictness: strict
+                         (exact terms only), standard (the default), or
+                         lenient (also quoted and punctuated values,
+                         numbers, and an extended vocabulary)
+    -e, --fail-fast      reports the first unknown value as an error and
+                         stops processing
+    -q, --quiet          prints nothing, indicating only via exit status
+    -h, --help           prints this help and exits
+    -V, --version        prints the version and exits
+
+FILTER-OPTIONS:
+    -o, --only WHICH     passes through only the lines that are `truey`
+                         (the default), `falsey`, or `unknown`
+    -k, --field N        classifies only the Nth (1-based) field of each
+                         line, rather than the whole line
+    -d, --delimiter SEP  separates fields by SEP (default: tab)
+    -a, --annotate       prefixes each line passed through with its
+                         classification and a tab
+
+Custom terms (which are matched case-insensitively) replace the default
+vocabulary, and may not be combined with --preset or --locale.
+";
+
+
+/// The source of the terms against which values are classified.
+enum Vocabulary {
+    Default,
+    Custom {
+        falsey_strings : Vec<String>,
+        truey_strings :  Vec<String>,
+    },
+    Locale(String),
+    Preset(Preset),
+}
+
+/// The options of filter mode.
+struct Filter {
+    /// The classification of the lines that are passed through.
+    only :      Option<bool>,
+    /// The (0-based) index of the field that is classified, if any.
+    field :     Option<usize>,
+    delimiter : String,
+    annotate :  bool,
+}
+
+/// The parsed command-line.
+struct Options {
+    vocabulary : Vocabulary,
+    strictness : Strictness,
+    fail_fast :  bool,
+    quiet :      bool,
+    values :     Vec<String>,
+    filter :     Option<Filter>,
+}
+
+/// The outcome of parsing the command-line.
+enum Command {
+    Classify(Options),
+    Help,
+    Version,
+}
+
+
+fn parse_only_(which : &str) -> Option<Option<bool>> {
+    match which {
+        "truey" | "true" => Some(Some(true)),
+        "falsey" | "false" => Some(Some(false)),
+        "unknown" => Some(None),
+        _ => None,
+    }
+}
+
+fn parse_args_<I>(args : I) -> Result<Command, String>
+where
+    I : IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+
+    let filter_mode = args.next_if(|arg| "filter" == arg).is_some();
+
+    let mut falsey_strings = Vec::new();
+    let mut truey_strings = Vec::new();
+    let mut locale_tag = None;
+    let mut preset = None;
+    let mut strictness = Strictness::default();
+    let mut fail_fast = false;
+    let mut quiet = false;
+    let mut values = Vec::new();
+    let mut only = Some(true);
+    let mut field = None;
+    let mut delimiter = None;
+    let mut annotate = false;
+
+    while let Some(arg) = args.next() {
+        let mut value_of = |name : &str| args.next().ok_or_else(|| format!("option '{name}' requires a value"));
+
+        match arg.as_str() {
+            "-a" | "--annotate" if filter_mode => {
+                annotate = true;
+            },
+            "-d" | "--delimiter" if filter_mode => {
+                let sep = value_of(&arg)?;
+
+                if sep.is_empty() {
+                    return Err("the delimiter may not be empty".into());
+                }
+
+                delimiter = Some(sep);
+            },
+            "-k" | "--field" if filter_mode => {
+                let n = value_of(&arg)?;
+
+                match n.parse::<usize>() {
+                    Ok(n) if 0 != n => field = Some(n - 1),
+                    _ => return Err(format!("invalid field number '{n}'")),
+                };
+            },
+            "-o" | "--only" if filter_mode => {
+                let which = value_of(&arg)?;
+
+                only = parse_only_(&which).ok_or_else(|| format!("invalid selection '{which}'"))?;
+            },
+            "--" => {
+                values.extend(args.by_ref());
+            },
+            "-e" | "--fail-fast" => {
+                fail_fast = true;
+            },
+            "-f" | "--falsey" => {
+                falsey_strings.push(value_of(&arg)?);
+            },
+            "-h" | "--help" => {
+                return Ok(Command::Help);
+            },
+            "-l" | "--locale" => {
+                locale_tag = Some(value_of(&arg)?);
+            },
+            "-p" | "--preset" => {
+                let name = value_of(&arg)?;
+
+                preset = Some(Preset::from_name(&name).ok_or_else(|| format!("unrecognised preset '{name}'"))?);
+            },
+            "-q" | "--quiet" => {
+                quiet = true;
+            },
+            "-s" | "--strictness" => {
+                let name = value_of(&arg)?;
+
+                strictness = Strictness::from_name(&name).ok_or_else(|| format!("unrecognised strictness '{name}'"))?;
+            },
+            "-t" | "--truey" => {
+                truey_strings.push(value_of(&arg)?);
+            },
+            "-V" | "--version" => {
+                return Ok(Command::Version);
+            },
+            _ if arg.starts_with('-') && 1 != arg.len() => {
+                return Err(format!("unrecognised option '{arg}'"));
+            },
+            _ => {
+                values.push(arg);
+            },
+        };
+    }
+
+    let filter = if filter_mode {
+        if !values.is_empty() {
+            return Err("filter mode does not accept values".into());
+        }
+
+        Some(Filter {
+            only,
+            field,
+            delimiter : delimiter.unwrap_or_else(|| "\t".into()),
+            annotate,
+        })
+    } else {
+        None
+    };
+
+    let custom = !falsey_strings.is_empty() || !truey_strings.is_empty();
+
+    let vocabulary = match (custom, locale_tag, preset) {
+        (false, None, None) => Vocabulary::Default,
+        (true, None, None) => Vocabulary::Custom {
+            falsey_strings,
+            truey_strings,
+        },
+        (false, Some(tag), None) => Vocabulary::Locale(tag),
+        (false, None, Some(preset)) => Vocabulary::Preset(preset),
+        _ => {
+            return Err("at most one of custom terms, --locale, and --preset may be specified".into());
+        },
+    };
+
+    Ok(Command::Classify(Options {
+        vocabulary,
+        strictness,
+        fail_fast,
+        quiet,
+        values,
+        filter,
+    }))
+}
+
+/// Invokes the given function with the terms of the given vocabulary,
+/// obtaining its result.
+fn with_terms_<R, F>(
+    vocabulary : &Vocabulary,
+    f : F,
+) -> Result<R, String>
+where
+    F : FnOnce(Terms) -> R,
+{
+    match vocabulary {
+        Vocabulary::Default => Ok(f(Terms::Default)),
+        Vocabulary::Custom {
+            falsey_strings,
+            truey_strings,
+        } => {
+            let falsey_strings : Vec<&str> = falsey_strings.iter().map(String::as_str).collect();
+            let truey_strings : Vec<&str> = truey_strings.iter().map(String::as_str).collect();
+
+            Ok(f(Terms::Strings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : &falsey_strings,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  &truey_strings,
+            }))
+        },
+        Vocabulary::Locale(tag) => locale::terms_for_locale(tag)
+            .map(f)
+            .ok_or_else(|| format!("no terms are registered for locale '{tag}'")),
+        Vocabulary::Preset(preset) => Ok(f(preset.terms())),
+    }
+}
+
+fn compile_terms_(
+    vocabulary : &Vocabulary,
+    strictness : Strictness,
+) -> Result<CompiledTerms, String> {
+    with_terms_(vocabulary, |terms| CompiledTerms::new(terms).with_strictness(strictness))
+}
+
+fn describe_(classification : Option<bool>) -> &'static str {
+    match classification {
+        None => "unknown",
+        Some(false) => "false",
+        Some(true) => "true",
+    }
+}
+
+/// Reports the given unrecognised value, along with any near-miss terms of
+/// the given vocabulary.
+fn report_unknown_(
+    value : &str,
+    vocabulary : &Vocabulary,
+) {
+    let suggestions : Vec<String> = with_terms_(vocabulary, |terms| {
+        diagnostics::near_misses_with(value, &terms)
+            .into_iter()
+            .map(str::to_owned)
+            .collect()
+    })
+    .unwrap_or_default();
+
+    if suggestions.is_empty() {
+        eprintln!("to-be: unrecognised value {value:?}");
+    } else {
+        let suggestions : Vec<String> = suggestions.iter().map(|s| format!("{s:?}")).collect();
+
+        eprintln!("to-be: unrecognised value {value:?} (did you mean: {}?)", suggestions.join(", "));
+    }
+}
+
+/// Writes the given line to the given output, indicating whether the
+/// output is still open, i.e. treating a broken pipe - as when the output
+/// is piped into, say, `head` - as the end of the output rather than as an
+/// error.
+fn write_line_(
+    output : &mut impl io::Write,
+    args : fmt::Arguments<'_>,
+) -> Result<bool, io::Error> {
+    match writeln!(output, "{args}") {
+        Ok(()) => Ok(true),
+        Err(e) if io::ErrorKind::BrokenPipe == e.kind() => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Classifies the given values, returning the exit status.
+fn classify_<I>(
+    options : &Options,
+    terms : &CompiledTerms,
+    values : I,
+) -> Result<u8, io::Error>
+where
+    I : IntoIterator<Item = Result<String, io::Error>>,
+{
+    let mut stdout = io::stdout().lock();
+    let mut any = false;
+    let mut any_falsey = false;
+    let mut any_unknown = false;
+
+    for value in values {
+        let value = value?;
+        let classification = terms.string_is_truthy(&value);
+
+        any = true;
+
+        if !options.quiet && !write_line_(&mut stdout, format_args!("{}", describe_(classification)))? {
+            break;
+        }
+
+        match classification {
+            None => {
+                if options.fail_fast {
+                    report_unknown_(&value, &options.vocabulary);
+
+                    return Ok(2);
+                }
+
+                any_unknown = true;
+            },
+            Some(false) => {
+                any_falsey = true;
+            },
+            Some(true) => {},
+        };
+    }
+
+    Ok(if !any || any_unknown {
+        2
+    } else if any_falsey {
+        1
+    } else {
+        0
+    })
+}
+
+/// Passes through the selected lines, returning the exit status.
+fn filter_<I>(
+    options : &Options,
+    filter : &Filter,
+    terms : &CompiledTerms,
+    lines : I,
+) -> Result<u8, io::Error>
+where
+    I : IntoIterator<Item = Result<String, io::Error>>,
+{
+    let mut stdout = io::stdout().lock();
+    let mut any = false;
+
+    for line in lines {
+        let line = line?;
+        let value = match filter.field {
+            Some(index) => line.split(filter.delimiter.as_str()).nth(index).unwrap_or(""),
+            None => line.as_str(),
+        };
+        let classification = terms.string_is_truthy(value);
+
+        if options.fail_fast && classification.is_none() {
+            report_unknown_(value, &options.vocabulary);
+
+            return Ok(2);
+        }
+
+        if filter.only != classification {
+            continue;
+        }
+
+        any = true;
+
+        if !options.quiet {
+            let open = if filter.annotate {
+                write_line_(&mut stdout, format_args!("{}\t{line}", describe_(classification)))?
+            } else {
+                write_line_(&mut stdout, format_args!("{line}"))?
+            };
+
+            if !open {
+                break;
+            }
+        }
+    }
+
+    Ok(if any { 0 } else { 1 })
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args_(env::args().skip(1)) {
+        Ok(Command::Classify(options)) => options,
+        Ok(Command::Help) => {
+            print!("{USAGE}");
+
+            return ExitCode::SUCCESS;
+        },
+        Ok(Command::Version) => {
+            println!("to-be {}", env!("CARGO_PKG_VERSION"));
+
+            return ExitCode::SUCCESS;
+        },
+        Err(message) => {
+            eprintln!("to-be: {message}");
+            eprintln!("to-be: use --help for usage");
+
+            return ExitCode::from(EXIT_USAGE);
+        },
+    };
+
+    let terms = match compile_terms_(&options.vocabulary, options.strictness) {
+        Ok(terms) => terms,
+        Err(message) => {
+            eprintln!("to-be: {message}");
+
+            return ExitCode::from(EXIT_USAGE);
+        },
+    };
+
+    let r = if let Some(filter) = &options.filter {
+        filter_(&options, filter, &terms, io::stdin().lock().lines())
+    } else if options.values.is_empty() {
+        classify_(&options, &terms, io::stdin().lock().lines())
+    } else {
+        classify_(&options, &terms, options.values.iter().cloned().map(Ok))
+    };
+
+    match r {
+        Ok(status) => ExitCode::from(status),
+        Err(e) => {
+            eprintln!("to-be: {e}");
+
+            ExitCode::from(EXIT_IOERR)
+        },
+    }
+}
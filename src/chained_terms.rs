@@ -0,0 +1,175 @@
+// chained_terms.rs - term sets consulted in order
+
+use super::{
+    string_is_truthy_with,
+    Terms,
+};
+
+
+/// A sequence of term sets, each consulted in order until one classifies
+/// the input, so that, say, a locale's terms may be layered over the
+/// default vocabulary without merging (and re-sorting) their strings.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     ChainedTerms,
+///     Terms,
+/// };
+///
+/// let de = Terms::Strings {
+///     falsey_precise_strings :   &[],
+///     falsey_lowercase_strings : &["nein", "aus"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["ja", "an"],
+/// };
+///
+/// let terms = Terms::chain([de, Terms::Default]);
+///
+/// assert_eq!(Some(true), terms.string_is_truthy("Ja"));
+/// assert_eq!(Some(false), terms.string_is_truthy("AUS"));
+/// # #[cfg(to_be_stock_default)]
+/// assert_eq!(Some(true), terms.string_is_truthy("yes"));
+/// assert_eq!(None, terms.string_is_truthy("vielleicht"));
+/// ```
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+pub struct ChainedTerms<'a> {
+    term_sets : Vec<Terms<'a>>,
+}
+
+impl<'a> ChainedTerms<'a> {
+    /// Creates an instance from the given term sets, which are consulted
+    /// in the given order.
+    pub fn new<I>(term_sets : I) -> Self
+    where
+        I : IntoIterator<Item = Terms<'a>>,
+    {
+        Self {
+            term_sets : term_sets.into_iter().collect(),
+        }
+    }
+
+    /// Appends the given term set, to be consulted after all others.
+    pub fn then(
+        mut self,
+        terms : Terms<'a>,
+    ) -> Self {
+        self.term_sets.push(terms);
+
+        self
+    }
+
+    /// The term sets, in the order in which they are consulted.
+    pub fn term_sets(&self) -> &[Terms<'a>] {
+        &self.term_sets
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey", according to the first of
+    /// the term sets that classifies it.
+    ///
+    /// # Returns:
+    /// - `None` - string is not classified as "truthy" by any of the term
+    ///   sets;
+    /// - `Some(false)` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - string (is classified as "truthy" and) is deemed
+    ///   "truey";
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        self.term_sets.iter().find_map(|terms| string_is_truthy_with(s, terms.clone()))
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "falsey".
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(false) == self.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey".
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(true) == self.string_is_truthy(s)
+    }
+}
+
+impl<'a> FromIterator<Terms<'a>> for ChainedTerms<'a> {
+    fn from_iter<I : IntoIterator<Item = Terms<'a>>>(iter : I) -> Self {
+        Self::new(iter)
+    }
+}
+
+impl<'a> Terms<'a> {
+    /// Creates a [ChainedTerms] from the given term sets, which are
+    /// consulted in the given order.
+    pub fn chain<I>(term_sets : I) -> ChainedTerms<'a>
+    where
+        I : IntoIterator<Item = Terms<'a>>,
+    {
+        ChainedTerms::new(term_sets)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_ChainedTerms_ORDER() {
+        let first = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["x"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["a"],
+        };
+        let second = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["a", "b"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["x"],
+        };
+
+        let terms = Terms::chain([first.clone(), second.clone()]);
+
+        assert_eq!(2, terms.term_sets().len());
+        assert_eq!(Some(true), terms.string_is_truthy("a"));
+        assert_eq!(Some(false), terms.string_is_truthy(" X "));
+        assert_eq!(Some(false), terms.string_is_truthy("b"));
+        assert_eq!(None, terms.string_is_truthy("c"));
+
+        let terms : ChainedTerms = [second, first].into_iter().collect();
+
+        assert!(terms.string_is_falsey("a"));
+        assert!(terms.string_is_truey("x"));
+    }
+
+    #[test]
+    fn TEST_ChainedTerms_EMPTY() {
+        let terms = ChainedTerms::default();
+
+        assert_eq!(None, terms.string_is_truthy("true"));
+
+        let terms = terms.then(Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &[],
+            truey_precise_strings :    &["T"],
+            truey_lowercase_strings :  &[],
+        });
+
+        assert_eq!(Some(true), terms.string_is_truthy("T"));
+        assert_eq!(None, terms.string_is_truthy("t"));
+    }
+}
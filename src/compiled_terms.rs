@@ -0,0 +1,838 @@
+// compiled_terms.rs - prepared (owned) terms
+
+
+use super::{
+    defaults,
+    fold_,
+    strictness::{
+        string_is_truthy_leniently_otherwise_,
+        strip_quotes_and_punctuation_,
+    },
+    FoldedString,
+    Strictness,
+    Terms,
+};
+
+use std::{
+    error,
+    fmt,
+};
+
+
+/// A prepared form of [Terms], in which the precise term strings are sorted
+/// and the folded forms of the lowercase term strings are derived once, at
+/// construction, rather than per classification.
+///
+/// As well as allowing logarithmic lookups, this means that custom
+/// lowercase term strings need not actually be lowercase: e.g. a term
+/// `"Oui"` in `truey_lowercase_strings` matches `"oui"`, `"OUI"`, and so
+/// on.
+///
+/// Inputs longer than the maximum length - which is, by default,
+/// [CompiledTerms::DEFAULT_MAX_LEN] bytes - are not classified, and so are
+/// not "truthy", without being scanned; this protects programs that
+/// classify untrusted inputs from pathologically long values.
+///
+/// Inputs are classified with [Strictness::Standard] unless another
+/// strictness is given (see [CompiledTerms::with_strictness()]).
+///
+/// # Note:
+/// Instances are compared by their terms and settings, but not by any
+/// callback given to `with_on_unrecognised()`, since separately created
+/// closures cannot be compared.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     CompiledTerms,
+///     Terms,
+/// };
+///
+/// let terms = CompiledTerms::new(Terms::Strings {
+///     falsey_precise_strings :   &[],
+///     falsey_lowercase_strings : &["Non"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["Oui"],
+/// });
+///
+/// assert_eq!(Some(true), terms.string_is_truthy("OUI"));
+/// assert_eq!(Some(false), terms.string_is_truthy(" non "));
+/// assert_eq!(None, terms.string_is_truthy("peut-être"));
+/// ```
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct CompiledTerms {
+    falsey_precise_strings :   Vec<String>,
+    falsey_folded_strings :    Vec<String>,
+    falsey_lowercase_strings : Vec<String>,
+    truey_precise_strings :    Vec<String>,
+    truey_folded_strings :     Vec<String>,
+    truey_lowercase_strings :  Vec<String>,
+    is_default :               bool,
+    max_len :                  Option<usize>,
+    strictness :               Strictness,
+    deprecations :             Vec<(String, TermDeprecation)>,
+    #[cfg(feature = "hooks")]
+    on_unrecognised :          Option<crate::hooks::OnUnrecognised>,
+}
+
+/// A term that is deprecated, i.e. that is still recognised but whose use
+/// is to be reported (see
+/// [CompiledTerms::string_is_truthy_reporting_deprecation()]).
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct TermDeprecation {
+    /// The deprecated term.
+    pub term :        String,
+    /// The term, if any, that is to be used instead.
+    pub replacement : Option<String>,
+}
+
+impl fmt::Display for TermDeprecation {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "the term '{}' is deprecated", self.term)?;
+
+        if let Some(replacement) = &self.replacement {
+            write!(f, "; use '{replacement}' instead")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// The error returned by [CompiledTerms::try_string_is_truthy()] for an
+/// input that is longer than the maximum length.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct InputTooLong {
+    /// The length, in bytes, of the input.
+    pub len :     usize,
+    /// The maximum length, in bytes, of inputs.
+    pub max_len : usize,
+}
+
+impl fmt::Display for InputTooLong {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "input of {} bytes exceeds the maximum length of {} bytes", self.len, self.max_len)
+    }
+}
+
+impl error::Error for InputTooLong {}
+
+/// The term string matched by an input.
+enum Matched<'a> {
+    /// The input matched the given precise term string.
+    Precise(&'a str),
+    /// The folded input matched the given folded term string.
+    Folded(&'a str),
+    /// The input matched none of the term strings, but is "truthy" by
+    /// [Strictness::Lenient].
+    Lenient,
+}
+
+/// Obtains a sorted, deduplicated, owned copy of the given strings, each
+/// transformed by `f`.
+fn sorted_<F>(
+    strings : &[&str],
+    f : F,
+) -> Vec<String>
+where
+    F : Fn(&str) -> String,
+{
+    let mut v : Vec<String> = strings.iter().map(|&s| f(s)).collect();
+
+    v.sort_unstable();
+    v.dedup();
+    v
+}
+
+impl CompiledTerms {
+    /// The default maximum length, in bytes, of inputs, which is far
+    /// greater than that of any realistic term.
+    pub const DEFAULT_MAX_LEN : usize = 256;
+
+    /// Prepares the given terms, with the default maximum input length.
+    ///
+    /// `Terms::Default` is prepared from the default vocabulary.
+    pub fn new(terms : Terms) -> Self {
+        let is_default = matches!(terms, Terms::Default);
+        let (falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings) =
+            match terms {
+                Terms::Default => (
+                    &defaults::FALSEY_PRECISE_STRINGS[..],
+                    &defaults::FALSEY_LOWERCASE_STRINGS[..],
+                    &defaults::TRUEY_PRECISE_STRINGS[..],
+                    &defaults::TRUEY_LOWERCASE_STRINGS[..],
+                ),
+                Terms::Strings {
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                }
+                | Terms::SortedStrings {
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                } => (
+                    falsey_precise_strings,
+                    falsey_lowercase_strings,
+                    truey_precise_strings,
+                    truey_lowercase_strings,
+                ),
+            };
+
+        Self {
+            falsey_precise_strings : sorted_(falsey_precise_strings, str::to_owned),
+            falsey_folded_strings : sorted_(falsey_lowercase_strings, fold_),
+            falsey_lowercase_strings : sorted_(falsey_lowercase_strings, str::to_owned),
+            truey_precise_strings : sorted_(truey_precise_strings, str::to_owned),
+            truey_folded_strings : sorted_(truey_lowercase_strings, fold_),
+            truey_lowercase_strings : sorted_(truey_lowercase_strings, str::to_owned),
+            is_default,
+            max_len : Some(Self::DEFAULT_MAX_LEN),
+            strictness : Strictness::Standard,
+            deprecations : Vec::new(),
+            #[cfg(feature = "hooks")]
+            on_unrecognised : None,
+        }
+    }
+
+    /// Sets the maximum length, in bytes, of inputs - before trimming -
+    /// beyond which they are not classified, or, if `None`, removes the
+    /// limit.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     CompiledTerms,
+    ///     Terms,
+    /// };
+    ///
+    /// # #[cfg(to_be_stock_default)]
+    /// # {
+    /// let terms = CompiledTerms::new(Terms::Default).with_max_len(Some(8));
+    ///
+    /// assert_eq!(Some(true), terms.string_is_truthy(" true "));
+    /// assert_eq!(None, terms.string_is_truthy("      true"));
+    /// assert!(terms.try_string_is_truthy("      true").is_err());
+    /// # }
+    /// ```
+    pub fn with_max_len(
+        self,
+        max_len : Option<usize>,
+    ) -> Self {
+        Self {
+            max_len,
+            ..self
+        }
+    }
+
+    /// The maximum length, in bytes, of inputs, if any.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Sets the strictness with which inputs are classified, as by
+    /// [string_is_truthy_with_terms_and_strictness()](crate::string_is_truthy_with_terms_and_strictness()).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     CompiledTerms,
+    ///     Strictness,
+    ///     Terms,
+    /// };
+    ///
+    /// let terms = CompiledTerms::new(Terms::Strings {
+    ///     falsey_precise_strings :   &[],
+    ///     falsey_lowercase_strings : &["non"],
+    ///     truey_precise_strings :    &[],
+    ///     truey_lowercase_strings :  &["oui"],
+    /// });
+    ///
+    /// let strict = terms.clone().with_strictness(Strictness::Strict);
+    /// let lenient = terms.clone().with_strictness(Strictness::Lenient);
+    ///
+    /// assert_eq!(Some(true), terms.string_is_truthy(" Oui "));
+    /// assert_eq!(None, strict.string_is_truthy(" Oui "));
+    /// assert_eq!(Some(true), strict.string_is_truthy("oui"));
+    /// assert_eq!(Some(false), lenient.string_is_truthy("'Non'."));
+    /// assert_eq!(Some(true), lenient.string_is_truthy("42"));
+    /// ```
+    pub fn with_strictness(
+        self,
+        strictness : Strictness,
+    ) -> Self {
+        Self {
+            strictness,
+            ..self
+        }
+    }
+
+    /// The strictness with which inputs are classified.
+    pub fn strictness(&self) -> Strictness {
+        self.strictness
+    }
+
+    /// Sets the callback that is invoked whenever these terms do not
+    /// recognise an input, instead of the process-wide callback (see
+    /// [hooks](crate::hooks)).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     CompiledTerms,
+    ///     Terms,
+    /// };
+    ///
+    /// use std::sync::{
+    ///     Arc,
+    ///     Mutex,
+    /// };
+    ///
+    /// let unrecognised = Arc::new(Mutex::new(Vec::new()));
+    ///
+    /// let terms = CompiledTerms::new(Terms::Default).with_on_unrecognised({
+    ///     let unrecognised = Arc::clone(&unrecognised);
+    ///
+    ///     move |u| unrecognised.lock().unwrap().push(u.input.to_owned())
+    /// });
+    ///
+    /// assert_eq!(None, terms.string_is_truthy(" sometimes "));
+    /// assert_eq!(vec!["sometimes"], *unrecognised.lock().unwrap());
+    /// ```
+    #[cfg(feature = "hooks")]
+    pub fn with_on_unrecognised<F>(
+        self,
+        callback : F,
+    ) -> Self
+    where
+        F : Fn(&crate::hooks::Unrecognised<'_>) + Send + Sync + 'static,
+    {
+        Self {
+            on_unrecognised : Some(crate::hooks::OnUnrecognised::new(callback)),
+            ..self
+        }
+    }
+
+    /// Marks the given term as deprecated, with the given replacement (if
+    /// any), such that it is still recognised but its use is reported by
+    /// [CompiledTerms::string_is_truthy_reporting_deprecation()].
+    ///
+    /// The deprecation applies to inputs that match the term precisely
+    /// and, if its folded form is among the folded lowercase term strings,
+    /// to those that match it case-insensitively.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     CompiledTerms,
+    ///     Terms,
+    /// };
+    ///
+    /// let terms = CompiledTerms::new(Terms::Strings {
+    ///     falsey_precise_strings :   &["N"],
+    ///     falsey_lowercase_strings : &["no"],
+    ///     truey_precise_strings :    &["Y"],
+    ///     truey_lowercase_strings :  &["yes"],
+    /// })
+    /// .with_deprecated_term("Y", Some("yes"))
+    /// .with_deprecated_term("N", Some("no"));
+    ///
+    /// let mut deprecated = Vec::new();
+    ///
+    /// for value in ["Y", "no", "N"] {
+    ///     terms.string_is_truthy_reporting_deprecation(value, |d| deprecated.push(d.to_string()));
+    /// }
+    ///
+    /// assert_eq!(
+    ///     vec!["the term 'Y' is deprecated; use 'yes' instead", "the term 'N' is deprecated; use 'no' instead"],
+    ///     deprecated
+    /// );
+    /// ```
+    pub fn with_deprecated_term(
+        mut self,
+        term : &str,
+        replacement : Option<&str>,
+    ) -> Self {
+        let deprecation = TermDeprecation {
+            term :        term.to_owned(),
+            replacement : replacement.map(str::to_owned),
+        };
+
+        self.deprecations.retain(|(_, d)| d.term != deprecation.term);
+        self.deprecations.push((fold_(term), deprecation));
+
+        self
+    }
+
+    /// The deprecated terms, in the order in which they were marked.
+    pub fn deprecated_terms(&self) -> impl Iterator<Item = &TermDeprecation> {
+        self.deprecations.iter().map(|(_, deprecation)| deprecation)
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey", as by
+    /// [string_is_truthy()](CompiledTerms::string_is_truthy), distinguishing
+    /// inputs that are too long to be classified.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - string is longer than the maximum length;
+    /// - `Ok(classification)` - the classification of the string;
+    pub fn try_string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Result<Option<bool>, InputTooLong> {
+        match self.max_len {
+            Some(max_len) if s.len() > max_len => Err(InputTooLong {
+                len : s.len(),
+                max_len,
+            }),
+            _ => Ok(self.string_is_truthy(s)),
+        }
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey".
+    ///
+    /// The string is trimmed, and otherwise treated, according to the
+    /// strictness (see [CompiledTerms::with_strictness()]).
+    ///
+    /// The precise term strings take precedence over the lowercase term
+    /// strings, and, within each, the "falsey" term strings take precedence
+    /// over the "truey" term strings.
+    ///
+    /// # Returns:
+    /// - `None` - string is not classified as "truthy", including because
+    ///   it is longer than the maximum length;
+    /// - `Some(false)` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - string (is classified as "truthy" and) is deemed
+    ///   "truey";
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        let r = match self.max_len {
+            Some(max_len) if s.len() > max_len => {
+                debug_event_!(len = s.len(), max_len, "input exceeds maximum length");
+
+                None
+            },
+            _ => self.classify_(s).map(|(truey, _)| truey),
+        };
+
+        record_classification_!(crate::metrics::Evaluator::Compiled, r);
+        report_unrecognised_!("CompiledTerms", s, r, self.on_unrecognised.as_ref());
+
+        r
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey", as by
+    /// [string_is_truthy()](CompiledTerms::string_is_truthy), invoking the
+    /// given callback if it matches a deprecated term (see
+    /// [CompiledTerms::with_deprecated_term()]).
+    ///
+    /// # Returns:
+    /// As [string_is_truthy()](CompiledTerms::string_is_truthy);
+    pub fn string_is_truthy_reporting_deprecation<F>(
+        &self,
+        s : &str,
+        on_deprecated : F,
+    ) -> Option<bool>
+    where
+        F : FnOnce(&TermDeprecation),
+    {
+        let r = match self.max_len {
+            Some(max_len) if s.len() > max_len => None,
+            _ => self.classify_(s),
+        };
+
+        record_classification_!(crate::metrics::Evaluator::Compiled, r.as_ref().map(|&(truey, _)| truey));
+        report_unrecognised_!("CompiledTerms", s, r, self.on_unrecognised.as_ref());
+
+        let (truey, matched) = r?;
+
+        let deprecation = self.deprecations.iter().find_map(|(folded, deprecation)| {
+            let is_match = match matched {
+                Matched::Precise(term) => deprecation.term == term,
+                Matched::Folded(term) => *folded == term,
+                Matched::Lenient => false,
+            };
+
+            is_match.then_some(deprecation)
+        });
+
+        if let Some(deprecation) = deprecation {
+            debug_event_!(term = %deprecation.term, "deprecated term matched");
+
+            on_deprecated(deprecation);
+        }
+
+        Some(truey)
+    }
+
+    /// Classifies the given (untrimmed) string according to the
+    /// strictness.
+    fn classify_<'s>(
+        &'s self,
+        s : &str,
+    ) -> Option<(bool, Matched<'s>)> {
+        match self.strictness {
+            Strictness::Strict => self.string_is_truthy_strictly_(s),
+            Strictness::Standard => self.string_is_truthy_(s.trim()),
+            Strictness::Lenient => {
+                let s = strip_quotes_and_punctuation_(s);
+
+                self.string_is_truthy_(s).or_else(|| {
+                    string_is_truthy_leniently_otherwise_(s, self.is_default).map(|truey| (truey, Matched::Lenient))
+                })
+            },
+        }
+    }
+
+    /// Indicates whether the given (untrimmed) string is exactly one of the
+    /// term strings, whether precise or lowercase.
+    fn string_is_truthy_strictly_<'s>(
+        &'s self,
+        s : &str,
+    ) -> Option<(bool, Matched<'s>)> {
+        let contains = |strings : &[String]| strings.binary_search_by(|t| t.as_str().cmp(s)).is_ok();
+        let search = |strings : &'s [String], s : &str| {
+            strings.binary_search_by(|t| t.as_str().cmp(s)).ok().map(|index| strings[index].as_str())
+        };
+
+        if let Some(term) = search(&self.falsey_precise_strings, s) {
+            return Some((false, Matched::Precise(term)));
+        }
+        if contains(&self.falsey_lowercase_strings) {
+            return Some((false, Matched::Folded(search(&self.falsey_folded_strings, &fold_(s))?)));
+        }
+        if let Some(term) = search(&self.truey_precise_strings, s) {
+            return Some((true, Matched::Precise(term)));
+        }
+        if contains(&self.truey_lowercase_strings) {
+            return Some((true, Matched::Folded(search(&self.truey_folded_strings, &fold_(s))?)));
+        }
+
+        None
+    }
+
+    fn string_is_truthy_<'s>(
+        &'s self,
+        s : &str,
+    ) -> Option<(bool, Matched<'s>)> {
+        let search = |strings : &'s [String], s : &str| {
+            strings.binary_search_by(|t| t.as_str().cmp(s)).ok().map(|index| strings[index].as_str())
+        };
+
+        if let Some(term) = search(&self.falsey_precise_strings, s) {
+            return Some((false, Matched::Precise(term)));
+        }
+        if let Some(term) = search(&self.truey_precise_strings, s) {
+            return Some((true, Matched::Precise(term)));
+        }
+
+        trace_event_!(input = %super::TracedInput(s), "no precise term matched; falling back to lowercase matching");
+
+        let l = FoldedString::new(s);
+
+        if let Some(term) = search(&self.falsey_folded_strings, l.as_str()) {
+            return Some((false, Matched::Folded(term)));
+        }
+        if let Some(term) = search(&self.truey_folded_strings, l.as_str()) {
+            return Some((true, Matched::Folded(term)));
+        }
+
+        debug_event_!(input = %super::TracedInput(s), "input is not recognised");
+
+        None
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "falsey".
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(false) == self.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey".
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(true) == self.string_is_truthy(s)
+    }
+
+    /// Indicates whether there are no term strings.
+    #[cfg(feature = "watch")]
+    pub(crate) fn is_empty_(&self) -> bool {
+        self.falsey_precise_strings.is_empty()
+            && self.falsey_folded_strings.is_empty()
+            && self.truey_precise_strings.is_empty()
+            && self.truey_folded_strings.is_empty()
+    }
+}
+
+impl From<Terms<'_>> for CompiledTerms {
+    fn from(terms : Terms<'_>) -> Self {
+        Self::new(terms)
+    }
+}
+
+impl PartialEq for CompiledTerms {
+    fn eq(
+        &self,
+        other : &Self,
+    ) -> bool {
+        // the on-unrecognised callback, if any, is deliberately not compared
+
+        self.falsey_precise_strings == other.falsey_precise_strings
+            && self.falsey_folded_strings == other.falsey_folded_strings
+            && self.falsey_lowercase_strings == other.falsey_lowercase_strings
+            && self.truey_precise_strings == other.truey_precise_strings
+            && self.truey_folded_strings == other.truey_folded_strings
+            && self.truey_lowercase_strings == other.truey_lowercase_strings
+            && self.is_default == other.is_default
+            && self.max_len == other.max_len
+            && self.strictness == other.strictness
+            && self.deprecations == other.deprecations
+    }
+}
+
+impl Eq for CompiledTerms {
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::{
+        string_is_truthy_with,
+        string_is_truthy_with_terms_and_strictness,
+    };
+
+
+    #[test]
+    fn TEST_CompiledTerms_MATCHES_string_is_truthy_with_FOR_Default() {
+        let compiled = CompiledTerms::new(Terms::Default);
+
+        for s in [
+            "", "0", "1", "no", "No", "NO", "nO", " yes ", "True", "tRuE", "OFF", "on", "maybe", "yess", "n",
+        ] {
+            assert_eq!(string_is_truthy_with(s, Terms::Default), compiled.string_is_truthy(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_MIXED_CASE_LOWERCASE_STRINGS() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["NYET"],
+            falsey_lowercase_strings : &["Nope", "NAH"],
+            truey_precise_strings :    &["Da"],
+            truey_lowercase_strings :  &["Yup", "yEAH"],
+        };
+        let compiled = CompiledTerms::from(terms);
+
+        for s in ["NYET", "nope", "NoPe", "nah", " Nah "] {
+            assert_eq!(Some(false), compiled.string_is_truthy(s), "'{s}'");
+            assert!(compiled.string_is_falsey(s), "'{s}'");
+        }
+
+        for s in ["Da", "yup", "YUP", "yeah", "Yeah"] {
+            assert_eq!(Some(true), compiled.string_is_truthy(s), "'{s}'");
+            assert!(compiled.string_is_truey(s), "'{s}'");
+        }
+
+        for s in ["", "nyet", "da", "DA", "maybe"] {
+            assert_eq!(None, compiled.string_is_truthy(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_PRECEDENCE() {
+        let compiled = CompiledTerms::new(Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["x"],
+            truey_precise_strings :    &["X"],
+            truey_lowercase_strings :  &["x"],
+        });
+
+        assert_eq!(Some(true), compiled.string_is_truthy("X"));
+        assert_eq!(Some(false), compiled.string_is_truthy("x"));
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_DEPRECATED_TERMS() {
+        let compiled = CompiledTerms::new(Terms::Strings {
+            falsey_precise_strings :   &["N"],
+            falsey_lowercase_strings : &["no", "nope"],
+            truey_precise_strings :    &["Y"],
+            truey_lowercase_strings :  &["yes", "y"],
+        })
+        .with_deprecated_term("Y", Some("yes"))
+        .with_deprecated_term("nope", None)
+        .with_deprecated_term("N", None)
+        .with_deprecated_term("N", Some("no"));
+
+        assert_eq!(
+            vec!["Y", "nope", "N"],
+            compiled.deprecated_terms().map(|d| d.term.as_str()).collect::<Vec<_>>()
+        );
+
+        let classify = |s| {
+            let mut deprecated = None;
+            let r = compiled.string_is_truthy_reporting_deprecation(s, |d| deprecated = Some(d.clone()));
+
+            (r, deprecated.map(|d| d.to_string()))
+        };
+
+        assert_eq!((Some(true), Some("the term 'Y' is deprecated; use 'yes' instead".to_owned())), classify(" Y "));
+        assert_eq!((Some(true), Some("the term 'Y' is deprecated; use 'yes' instead".to_owned())), classify("y"));
+        assert_eq!((Some(false), Some("the term 'nope' is deprecated".to_owned())), classify("NOPE"));
+        assert_eq!((Some(false), Some("the term 'N' is deprecated; use 'no' instead".to_owned())), classify("N"));
+        assert_eq!((Some(false), None), classify("No"));
+        assert_eq!((None, None), classify("n"));
+        assert_eq!((Some(true), None), classify("Yes"));
+        assert_eq!((None, None), classify("maybe"));
+
+        assert_eq!(Some(true), compiled.string_is_truthy("Y"));
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_MAX_LEN() {
+        let long_term = "y".repeat(300);
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["no"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["yes", &long_term],
+        };
+
+        let compiled = CompiledTerms::new(terms.clone());
+
+        assert_eq!(Some(CompiledTerms::DEFAULT_MAX_LEN), compiled.max_len());
+        assert_eq!(Some(true), compiled.string_is_truthy("yes"));
+        assert_eq!(None, compiled.string_is_truthy(&long_term));
+        assert_eq!(
+            Err(InputTooLong {
+                len :     300,
+                max_len : 256,
+            }),
+            compiled.try_string_is_truthy(&long_term)
+        );
+        assert_eq!(Ok(Some(false)), compiled.try_string_is_truthy("NO"));
+        assert_eq!(Ok(None), compiled.try_string_is_truthy("maybe"));
+
+        // the limit applies before trimming
+
+        let padded = format!("{}no", " ".repeat(255));
+
+        assert_eq!(None, compiled.string_is_truthy(&padded));
+        assert_eq!(Some(false), compiled.string_is_truthy(&padded[1..]));
+
+        let unlimited = CompiledTerms::new(terms).with_max_len(None);
+
+        assert_eq!(None, unlimited.max_len());
+        assert_eq!(Some(true), unlimited.string_is_truthy(&long_term));
+        assert_eq!(Ok(Some(false)), unlimited.try_string_is_truthy(&padded));
+
+        assert_eq!(
+            "input of 300 bytes exceeds the maximum length of 256 bytes",
+            InputTooLong {
+                len :     300,
+                max_len : 256,
+            }
+            .to_string()
+        );
+    }
+
+    #[cfg(feature = "unicode")]
+    #[test]
+    fn TEST_CompiledTerms_NON_ASCII() {
+        let compiled = CompiledTerms::new(Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["Nein"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["ÇA VA"],
+        });
+
+        assert_eq!(Some(true), compiled.string_is_truthy("ça va"));
+        assert_eq!(Some(false), compiled.string_is_truthy("ＮＥＩＮ"));
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_with_strictness_MATCHES_string_is_truthy_with_terms_and_strictness() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["NYET"],
+            falsey_lowercase_strings : &["Nope", "nah"],
+            truey_precise_strings :    &["Da"],
+            truey_lowercase_strings :  &["yup"],
+        };
+
+        for strictness in [Strictness::Strict, Strictness::Standard, Strictness::Lenient] {
+            for terms in [Terms::Default, terms.clone()] {
+                let compiled = CompiledTerms::new(terms.clone()).with_strictness(strictness);
+
+                assert_eq!(strictness, compiled.strictness());
+
+                for s in [
+                    "", "no", "No", " yes ", "\"Yes\"", "yes!", "1", "0", "-2.5", "y", "NYET", " NYET", "nyet", "Nope",
+                    "nope", "NOPE", "'nah'.", "Da", "da", "yup", " Yup?", "maybe",
+                ] {
+                    assert_eq!(
+                        string_is_truthy_with_terms_and_strictness(s, terms.clone(), strictness),
+                        compiled.string_is_truthy(s),
+                        "'{s}' ({strictness:?})"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn TEST_CompiledTerms_with_strictness_DEPRECATIONS() {
+        let compiled = CompiledTerms::new(Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["nope"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["yup"],
+        })
+        .with_deprecated_term("yup", Some("yes"))
+        .with_strictness(Strictness::Lenient);
+
+        let mut deprecated = Vec::new();
+
+        for value in ["'Yup'!", "1", "nope."] {
+            let r = compiled.string_is_truthy_reporting_deprecation(value, |d| deprecated.push(d.term.clone()));
+
+            assert!(r.is_some(), "'{value}'");
+        }
+
+        assert_eq!(vec!["yup"], deprecated);
+    }
+}
@@ -0,0 +1,878 @@
+// diagnostics.rs - diagnostic facilities, for use in reporting failures
+
+
+use super::{
+    defaults,
+    Terms,
+};
+
+use std::{
+    error,
+    fmt,
+    ops::Range,
+};
+
+
+/// Calculates the edit distance - the optimal string alignment distance,
+/// in which an adjacent transposition counts as a single edit - between the
+/// two given strings.
+fn edit_distance_(
+    lhs : &str,
+    rhs : &str,
+) -> usize {
+    let lhs : Vec<char> = lhs.chars().collect();
+    let rhs : Vec<char> = rhs.chars().collect();
+    let mut d = vec![vec![0usize; rhs.len() + 1]; lhs.len() + 1];
+
+    for (i, row) in d.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in d[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+
+    for i in 1..=lhs.len() {
+        for j in 1..=rhs.len() {
+            let cost = usize::from(lhs[i - 1] != rhs[j - 1]);
+
+            d[i][j] = (d[i - 1][j] + 1).min(d[i][j - 1] + 1).min(d[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && lhs[i - 1] == rhs[j - 2] && lhs[i - 2] == rhs[j - 1] {
+                d[i][j] = d[i][j].min(d[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    d[lhs.len()][rhs.len()]
+}
+
+/// Describes the given classification in words.
+fn describe_(classification : Option<bool>) -> &'static str {
+    match classification {
+        None => "not truthy",
+        Some(false) => "falsey",
+        Some(true) => "truey",
+    }
+}
+
+/// Obtains the term of the default vocabulary matched by the given string,
+/// if any, along with whether that term is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy";
+/// - `Some((false, term))` - string is deemed "falsey" by virtue of `term`;
+/// - `Some((true, term))` - string is deemed "truey" by virtue of `term`;
+pub fn matched_term(s : &str) -> Option<(bool, &'static str)> {
+    matched_term_with(s, &Terms::Default)
+}
+
+/// Obtains the term of the given terms matched by the given string, if
+/// any, along with whether that term is "truey" or "falsey", with the
+/// precedence of [string_is_truthy_with()](crate::string_is_truthy_with()).
+///
+/// # Returns:
+/// As [matched_term()];
+pub fn matched_term_with<'a>(
+    s : &str,
+    terms : &Terms<'a>,
+) -> Option<(bool, &'a str)> {
+    let s = s.trim();
+    let [falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings] =
+        term_strings_(terms);
+
+    for (truey, precise_strings) in [(false, falsey_precise_strings), (true, truey_precise_strings)] {
+        if let Some(&term) = precise_strings.iter().find(|&&term| term == s) {
+            return Some((truey, term));
+        }
+    }
+
+    let l = super::Folded::new(s);
+
+    for (truey, lowercase_strings) in [(false, falsey_lowercase_strings), (true, truey_lowercase_strings)] {
+        if let Some(term) = l.find_in(lowercase_strings) {
+            return Some((truey, term));
+        }
+    }
+
+    None
+}
+
+/// Obtains the terms of the default vocabulary that are near misses for the
+/// given (unrecognised) string - i.e. those within a small edit distance of
+/// it, ignoring case - in order of closeness.
+pub fn near_misses(s : &str) -> Vec<&'static str> {
+    near_misses_with(s, &Terms::Default)
+}
+
+/// Obtains the terms of the given terms that are near misses for the given
+/// (unrecognised) string, as by [near_misses()].
+pub fn near_misses_with<'a>(
+    s : &str,
+    terms : &Terms<'a>,
+) -> Vec<&'a str> {
+    let l = super::fold_(s.trim());
+
+    if l.is_empty() {
+        return Vec::new();
+    }
+
+    let [falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings] =
+        term_strings_(terms);
+
+    let mut candidates : Vec<(usize, &'a str)> = falsey_lowercase_strings
+        .iter()
+        .chain(truey_lowercase_strings.iter())
+        .chain(falsey_precise_strings.iter())
+        .chain(truey_precise_strings.iter())
+        .filter_map(|&term| {
+            let limit = if term.chars().count() <= 3 { 1 } else { 2 };
+            let distance = edit_distance_(&l, &super::fold_(term));
+
+            (0 != distance && distance <= limit).then_some((distance, term))
+        })
+        .collect();
+
+    candidates.sort_by_key(|&(distance, _)| distance);
+
+    let mut terms : Vec<&'a str> = Vec::with_capacity(candidates.len());
+
+    for (_, term) in candidates {
+        if !terms.iter().any(|t| super::fold_(t) == super::fold_(term)) {
+            terms.push(term);
+        }
+    }
+
+    terms
+}
+
+/// The location of a value within some source text (e.g. a configuration
+/// file), in bytes.
+///
+/// This converts to and from `Range<usize>` (as used by, e.g.,
+/// **codespan-reporting**) and `(offset, len)` (from which, e.g., the
+/// `SourceSpan` of **miette** may be created).
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct Span {
+    /// The offset of the value from the start of the source text.
+    pub offset : usize,
+    /// The length of the value.
+    pub len :    usize,
+}
+
+impl Span {
+    /// Creates a span of the given offset and length.
+    pub const fn new(
+        offset : usize,
+        len : usize,
+    ) -> Self {
+        Self {
+            offset,
+            len,
+        }
+    }
+
+    /// The range of the span.
+    pub const fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+
+    /// Obtains the (1-based) line and column numbers of the start of the
+    /// span within the given source text, in which columns are counted in
+    /// characters.
+    pub fn line_column(
+        &self,
+        source : &str,
+    ) -> (usize, usize) {
+        let offset = self.offset.min(source.len());
+        let before = source.get(..offset).unwrap_or(source);
+        let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+
+        (1 + before.matches('\n').count(), 1 + before[line_start..].chars().count())
+    }
+}
+
+impl From<Range<usize>> for Span {
+    fn from(range : Range<usize>) -> Self {
+        Self::new(range.start, range.end.saturating_sub(range.start))
+    }
+}
+
+impl From<Span> for Range<usize> {
+    fn from(span : Span) -> Self {
+        span.range()
+    }
+}
+
+impl From<Span> for (usize, usize) {
+    fn from(span : Span) -> Self {
+        (span.offset, span.len)
+    }
+}
+
+/// The error returned by [classify_spanned()] for a value that is not
+/// recognised.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct UnrecognisedValue {
+    /// The (trimmed) value.
+    pub value :       String,
+    /// The location of the (trimmed) value.
+    pub span :        Span,
+    /// The terms of the default vocabulary that are near misses for the
+    /// value (see [near_misses()]), in order of closeness.
+    pub suggestions : Vec<&'static str>,
+}
+
+impl UnrecognisedValue {
+    /// A suggestion of the correction of the value, if there are any near
+    /// misses, e.g. `"did you mean 'true'?"`.
+    pub fn help(&self) -> Option<String> {
+        let (last, others) = self.suggestions.split_last()?;
+
+        let mut help = String::from("did you mean ");
+
+        for (index, suggestion) in others.iter().enumerate() {
+            if 0 != index {
+                help.push_str(", ");
+            }
+            help.push_str(&format!("'{suggestion}'"));
+        }
+        if !others.is_empty() {
+            help.push_str(" or ");
+        }
+        help.push_str(&format!("'{last}'?"));
+
+        Some(help)
+    }
+
+    /// Formats the error, prefixed by its line and column within the given
+    /// source text, e.g. `"line 42, column 7: 'treu' is not a recognised
+    /// boolean (did you mean 'true'?)"`.
+    pub fn display_in(
+        &self,
+        source : &str,
+    ) -> String {
+        let (line, column) = self.span.line_column(source);
+
+        format!("line {line}, column {column}: {self}")
+    }
+}
+
+impl fmt::Display for UnrecognisedValue {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "'{}' is not a recognised boolean", self.value)?;
+
+        if let Some(help) = self.help() {
+            write!(f, " ({help})")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for UnrecognisedValue {}
+
+/// Classifies the given value, found at the given location in some source
+/// text, as by [string_is_truthy()](crate::string_is_truthy()), reporting
+/// an unrecognised value with its location and any near misses.
+///
+/// # Returns:
+/// - `Err(e)` - value is not recognised, where the span of `e` is that of
+///   the trimmed value;
+/// - `Ok(false)` - value is deemed "falsey";
+/// - `Ok(true)` - value is deemed "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::diagnostics::{
+///     classify_spanned,
+///     Span,
+/// };
+///
+/// let source = "[server]\nverbose = treu\n";
+/// let offset = source.find("treu").unwrap();
+///
+/// let e = classify_spanned("treu", Span::new(offset, 4)).unwrap_err();
+///
+/// # #[cfg(to_be_stock_default)]
+/// assert_eq!(
+///     "line 2, column 11: 'treu' is not a recognised boolean (did you mean 'true'?)",
+///     e.display_in(source)
+/// );
+/// ```
+pub fn classify_spanned(
+    input : &str,
+    span : Span,
+) -> Result<bool, UnrecognisedValue> {
+    if let Some(truey) = super::string_is_truthy(input) {
+        return Ok(truey);
+    }
+
+    let value = input.trim();
+    let leading = input.len() - input.trim_start().len();
+
+    Err(UnrecognisedValue {
+        value :       value.to_owned(),
+        span :        Span::new(span.offset + leading, value.len()),
+        suggestions : near_misses(value),
+    })
+}
+
+/// A pair of terms, of opposite polarity, that match the same input.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct TermConflict<'a> {
+    /// The "falsey" term.
+    pub falsey_term : &'a str,
+    /// The "truey" term.
+    pub truey_term :  &'a str,
+}
+
+impl fmt::Display for TermConflict<'_> {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(
+            f,
+            "the truey term '{}' and the falsey term '{}' match the same input",
+            self.truey_term, self.falsey_term
+        )
+    }
+}
+
+impl error::Error for TermConflict<'_> {}
+
+/// Obtains the falsey precise, falsey lowercase, truey precise, and truey
+/// lowercase strings of the given terms.
+fn term_strings_<'a>(terms : &Terms<'a>) -> [&'a [&'a str]; 4] {
+    match terms {
+        Terms::Default => [
+            defaults::FALSEY_PRECISE_STRINGS,
+            defaults::FALSEY_LOWERCASE_STRINGS,
+            defaults::TRUEY_PRECISE_STRINGS,
+            defaults::TRUEY_LOWERCASE_STRINGS,
+        ],
+        Terms::Strings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings,
+            truey_lowercase_strings,
+        }
+        | Terms::SortedStrings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings,
+            truey_lowercase_strings,
+        } => [
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings,
+            truey_lowercase_strings,
+        ],
+    }
+}
+
+/// Obtains the first of the given precise strings that equals the given
+/// (trimmed) string or, failing that, the first of the given lowercase
+/// strings that it matches.
+fn matching_term_<'a>(
+    s : &str,
+    precise_strings : &[&'a str],
+    lowercase_strings : &[&'a str],
+) -> Option<&'a str> {
+    precise_strings
+        .iter()
+        .copied()
+        .find(|&term| term == s)
+        .or_else(|| super::Folded::new(s).find_in(lowercase_strings))
+}
+
+/// Obtains the conflict, if any, between the terms of opposite polarity
+/// that the given string, when trimmed, matches - whether precisely or
+/// case-insensitively - among the given terms.
+///
+/// Such a string is deemed "falsey" by
+/// [string_is_truthy_with()](crate::string_is_truthy_with()) if it matches
+/// the falsey terms in the same manner as (or in a more precise manner
+/// than) the truey terms, and "truey" otherwise, without any indication of
+/// the conflict.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     diagnostics::term_conflict_with,
+///     Terms,
+/// };
+///
+/// let terms = Terms::Strings {
+///     falsey_precise_strings :   &["N"],
+///     falsey_lowercase_strings : &["no"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["yes", "n"],
+/// };
+///
+/// let conflict = term_conflict_with(" N ", &terms).unwrap();
+///
+/// assert_eq!("N", conflict.falsey_term);
+/// assert_eq!("n", conflict.truey_term);
+/// assert_eq!(None, term_conflict_with("yes", &terms));
+/// ```
+pub fn term_conflict_with<'a>(
+    s : &str,
+    terms : &Terms<'a>,
+) -> Option<TermConflict<'a>> {
+    let s = s.trim();
+    let [falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings] =
+        term_strings_(terms);
+
+    Some(TermConflict {
+        falsey_term : matching_term_(s, falsey_precise_strings, falsey_lowercase_strings)?,
+        truey_term :  matching_term_(s, truey_precise_strings, truey_lowercase_strings)?,
+    })
+}
+
+/// Classifies the given string as by
+/// [string_is_truthy_with()](crate::string_is_truthy_with()), save that a
+/// string that matches terms of both polarities is reported as a conflict
+/// rather than being deemed "falsey" (or "truey").
+///
+/// # Returns:
+/// - `Err(conflict)` - string matches terms of both polarities;
+/// - `Ok(None)` - string is not classified as "truthy";
+/// - `Ok(Some(false))` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Ok(Some(true))` - string (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn classify_detecting_conflicts<'a>(
+    s : &str,
+    terms : Terms<'a>,
+) -> Result<Option<bool>, TermConflict<'a>> {
+    match term_conflict_with(s, &terms) {
+        Some(conflict) => Err(conflict),
+        None => Ok(super::string_is_truthy_with(s, terms)),
+    }
+}
+
+/// Obtains all conflicts among the given terms, i.e. the pairs of terms of
+/// opposite polarity that match the same input, as a validation pass over
+/// a (user-supplied) vocabulary.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     diagnostics::conflicting_terms,
+///     Terms,
+/// };
+///
+/// let terms = Terms::Strings {
+///     falsey_precise_strings :   &["OFF"],
+///     falsey_lowercase_strings : &["off", "nein"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["on", "off"],
+/// };
+///
+/// let conflicts = conflicting_terms(&terms);
+///
+/// assert_eq!(2, conflicts.len());
+/// assert_eq!(("OFF", "off"), (conflicts[0].falsey_term, conflicts[0].truey_term));
+/// assert_eq!(("off", "off"), (conflicts[1].falsey_term, conflicts[1].truey_term));
+/// assert!(conflicting_terms(&Terms::Default).is_empty());
+/// ```
+pub fn conflicting_terms<'a>(terms : &Terms<'a>) -> Vec<TermConflict<'a>> {
+    let mut conflicts : Vec<TermConflict<'a>> = Vec::new();
+
+    // every input that matches terms of both polarities either equals one
+    // of the precise strings or is a case variant of one of the lowercase
+    // strings, so it suffices to try each term as input
+
+    for term in term_strings_(terms).into_iter().flatten() {
+        if let Some(conflict) = term_conflict_with(term, terms) {
+            if !conflicts.contains(&conflict) {
+                conflicts.push(conflict);
+            }
+        }
+    }
+
+    conflicts
+}
+
+/// Formats the failure message of the assertion macros. Not part of the
+/// public API.
+#[doc(hidden)]
+pub fn __assertion_failure_message(
+    expected : Option<bool>,
+    actual : Option<bool>,
+    input : &str,
+    message : Option<fmt::Arguments>,
+) -> String {
+    use fmt::Write as _;
+
+    let mut m = String::new();
+
+    match message {
+        Some(message) => {
+            let _ = writeln!(m, "assertion failed: {message}");
+        },
+        None => {
+            let _ = writeln!(m, "assertion failed: input is not {}", describe_(expected));
+        },
+    };
+
+    let _ = writeln!(m, "     input: {input:?}");
+    let _ = writeln!(m, "  expected: {}", describe_(expected));
+    let _ = writeln!(m, "    actual: {}", describe_(actual));
+
+    match matched_term(input) {
+        Some((_, term)) => {
+            let _ = write!(m, "   matched: {term:?}");
+        },
+        None => {
+            let _ = write!(m, "   matched: <none>");
+
+            let suggestions = near_misses(input);
+
+            if !suggestions.is_empty() {
+                let suggestions : Vec<String> = suggestions.iter().map(|s| format!("{s:?}")).collect();
+
+                let _ = write!(m, "\ndid you mean: {}", suggestions.join(", "));
+            }
+        },
+    };
+
+    m
+}
+
+
+/// Asserts that the given string is classified as `expected` (an
+/// `Option<bool>`) by [string_is_truthy](crate::string_is_truthy()).
+///
+/// On failure, the panic message includes the input, its classification,
+/// the matched term (if any), and any near-miss terms.
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// to_be::assert_truthy_eq!(Some(true), "Yes");
+/// to_be::assert_truthy_eq!(None, "maybe", "'{}' is not a valid flag", "maybe");
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_truthy_eq {
+    ($expected:expr, $input:expr $(,)?) => {
+        match (&$expected, &$input) {
+            (expected, input) => {
+                let expected : ::core::option::Option<bool> = *expected;
+                let input : &str = ::core::convert::AsRef::<str>::as_ref(input);
+                let actual = $crate::string_is_truthy(input);
+
+                if expected != actual {
+                    ::core::panic!(
+                        "{}",
+                        $crate::diagnostics::__assertion_failure_message(expected, actual, input, ::core::option::Option::None)
+                    );
+                }
+            },
+        }
+    };
+    ($expected:expr, $input:expr, $($arg:tt)+) => {
+        match (&$expected, &$input) {
+            (expected, input) => {
+                let expected : ::core::option::Option<bool> = *expected;
+                let input : &str = ::core::convert::AsRef::<str>::as_ref(input);
+                let actual = $crate::string_is_truthy(input);
+
+                if expected != actual {
+                    ::core::panic!(
+                        "{}",
+                        $crate::diagnostics::__assertion_failure_message(
+                            expected,
+                            actual,
+                            input,
+                            ::core::option::Option::Some(::core::format_args!($($arg)+))
+                        )
+                    );
+                }
+            },
+        }
+    };
+}
+
+/// Asserts that the given string is deemed "falsey" by
+/// [string_is_truthy](crate::string_is_truthy()).
+///
+/// See [assert_truthy_eq] for details.
+#[macro_export]
+macro_rules! assert_falsey {
+    ($input:expr $(,)?) => {
+        $crate::assert_truthy_eq!(::core::option::Option::Some(false), $input)
+    };
+    ($input:expr, $($arg:tt)+) => {
+        $crate::assert_truthy_eq!(::core::option::Option::Some(false), $input, $($arg)+)
+    };
+}
+
+/// Asserts that the given string is deemed "truey" by
+/// [string_is_truthy](crate::string_is_truthy()).
+///
+/// See [assert_truthy_eq] for details.
+#[macro_export]
+macro_rules! assert_truey {
+    ($input:expr $(,)?) => {
+        $crate::assert_truthy_eq!(::core::option::Option::Some(true), $input)
+    };
+    ($input:expr, $($arg:tt)+) => {
+        $crate::assert_truthy_eq!(::core::option::Option::Some(true), $input, $($arg)+)
+    };
+}
+
+
+#[cfg(all(test, to_be_stock_default))]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_edit_distance_() {
+        assert_eq!(0, edit_distance_("", ""));
+        assert_eq!(0, edit_distance_("true", "true"));
+        assert_eq!(1, edit_distance_("tru", "true"));
+        assert_eq!(1, edit_distance_("treu", "true"));
+        assert_eq!(1, edit_distance_("yse", "yes"));
+        assert_eq!(2, edit_distance_("flase", "fals"));
+        assert_eq!(3, edit_distance_("", "yes"));
+        assert_eq!(2, edit_distance_("off", "o"));
+    }
+
+    #[test]
+    fn TEST_matched_term() {
+        assert_eq!(Some((true, "Yes")), matched_term(" Yes "));
+        assert_eq!(Some((true, "yes")), matched_term("yEs"));
+        assert_eq!(Some((false, "0")), matched_term("0"));
+        assert_eq!(Some((false, "off")), matched_term("OfF"));
+        assert_eq!(None, matched_term("maybe"));
+    }
+
+    #[test]
+    fn TEST_matched_term_with() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["NEIN"],
+            falsey_lowercase_strings : &["nein", "x"],
+            truey_precise_strings :    &["X"],
+            truey_lowercase_strings :  &["ja"],
+        };
+
+        assert_eq!(Some((false, "NEIN")), matched_term_with("NEIN", &terms));
+        assert_eq!(Some((false, "nein")), matched_term_with(" Nein ", &terms));
+        assert_eq!(Some((true, "X")), matched_term_with("X", &terms));
+        assert_eq!(Some((false, "x")), matched_term_with("x", &terms));
+        assert_eq!(Some((true, "ja")), matched_term_with("JA", &terms));
+        assert_eq!(None, matched_term_with("yes", &terms));
+    }
+
+    #[test]
+    fn TEST_near_misses() {
+        assert_eq!(vec!["true"], near_misses("treu"));
+        assert_eq!(vec!["false"], near_misses("FLASE"));
+        assert_eq!(vec!["yes"], near_misses("yse"));
+        assert!(near_misses("").is_empty());
+        assert!(near_misses("yes").is_empty());
+        assert!(near_misses("orange").is_empty());
+    }
+
+    #[test]
+    fn TEST_near_misses_with() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["nein"],
+            truey_precise_strings :    &["JA"],
+            truey_lowercase_strings :  &["jawohl"],
+        };
+
+        assert_eq!(vec!["nein"], near_misses_with("NEN", &terms));
+        assert_eq!(vec!["JA"], near_misses_with("JAA", &terms));
+        assert_eq!(vec!["jawohl"], near_misses_with("jawol", &terms));
+        assert!(near_misses_with("yse", &terms).is_empty());
+    }
+
+    #[test]
+    fn TEST_Span() {
+        let span = Span::new(3, 4);
+
+        assert_eq!(3..7, span.range());
+        assert_eq!(span, Span::from(3..7));
+        assert_eq!((3, 4), <(usize, usize)>::from(span));
+
+        let source = "a = 1\nb = tr\u{fc}e\nc = x";
+
+        assert_eq!((1, 1), Span::new(0, 1).line_column(source));
+        assert_eq!((1, 5), Span::new(4, 1).line_column(source));
+        assert_eq!((2, 1), Span::new(6, 1).line_column(source));
+        assert_eq!((3, 5), Span::new(source.len() - 1, 1).line_column(source));
+        assert_eq!((3, 6), Span::new(1000, 0).line_column(source));
+    }
+
+    #[test]
+    fn TEST_classify_spanned() {
+        assert_eq!(Ok(true), classify_spanned(" yes ", Span::new(10, 5)));
+        assert_eq!(Ok(false), classify_spanned("Off", Span::new(0, 3)));
+
+        let e = classify_spanned("  treu ", Span::new(10, 7)).unwrap_err();
+
+        assert_eq!("treu", e.value);
+        assert_eq!(Span::new(12, 4), e.span);
+        assert_eq!(vec!["true"], e.suggestions);
+        assert_eq!(Some("did you mean 'true'?".into()), e.help());
+        assert_eq!("'treu' is not a recognised boolean (did you mean 'true'?)", e.to_string());
+
+        let e = classify_spanned("orange", Span::new(0, 6)).unwrap_err();
+
+        assert_eq!(None, e.help());
+        assert_eq!("'orange' is not a recognised boolean", e.to_string());
+
+        let e = UnrecognisedValue {
+            value :       "x".into(),
+            span :        Span::new(0, 1),
+            suggestions : vec!["a", "b", "c"],
+        };
+
+        assert_eq!(Some("did you mean 'a', 'b' or 'c'?".into()), e.help());
+    }
+
+    #[test]
+    fn TEST_UnrecognisedValue_display_in() {
+        let source = "[server]\nport = 8080\nverbose = treu\n";
+        let offset = source.find("treu").unwrap();
+
+        let e = classify_spanned("treu", Span::new(offset, 4)).unwrap_err();
+
+        assert_eq!(
+            "line 3, column 11: 'treu' is not a recognised boolean (did you mean 'true'?)",
+            e.display_in(source)
+        );
+    }
+
+    #[test]
+    fn TEST_term_conflict_with() {
+        let terms = Terms::SortedStrings {
+            falsey_precise_strings :   &["Nope", "no"],
+            falsey_lowercase_strings : &["nein"],
+            truey_precise_strings :    &["Nein", "no"],
+            truey_lowercase_strings :  &["nope"],
+        };
+
+        assert_eq!(
+            Some(TermConflict {
+                falsey_term : "no",
+                truey_term :  "no",
+            }),
+            term_conflict_with("no", &terms)
+        );
+        assert_eq!(Some(("nein", "Nein")), term_conflict_with("Nein", &terms).map(|c| (c.falsey_term, c.truey_term)));
+        assert_eq!(Some(("Nope", "nope")), term_conflict_with("Nope", &terms).map(|c| (c.falsey_term, c.truey_term)));
+        assert_eq!(None, term_conflict_with("NOPE", &terms));
+        assert_eq!(None, term_conflict_with("NEIN", &terms));
+        assert_eq!(None, term_conflict_with("yes", &terms));
+    }
+
+    #[test]
+    fn TEST_classify_detecting_conflicts() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["off", "x"],
+            truey_precise_strings :    &["X"],
+            truey_lowercase_strings :  &["on"],
+        };
+
+        assert_eq!(Ok(Some(true)), classify_detecting_conflicts("ON", terms.clone()));
+        assert_eq!(Ok(Some(false)), classify_detecting_conflicts("x", terms.clone()));
+        assert_eq!(Ok(None), classify_detecting_conflicts("maybe", terms.clone()));
+
+        let e = classify_detecting_conflicts(" X ", terms).unwrap_err();
+
+        assert_eq!("the truey term 'X' and the falsey term 'x' match the same input", e.to_string());
+    }
+
+    #[test]
+    fn TEST_conflicting_terms() {
+        assert!(conflicting_terms(&Terms::Default).is_empty());
+        assert!(conflicting_terms(&crate::stock_term_strings()).is_empty());
+
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["0"],
+            falsey_lowercase_strings : &["false", "disabled"],
+            truey_precise_strings :    &["0", "FALSE"],
+            truey_lowercase_strings :  &["true", "disabled"],
+        };
+
+        let conflicts : Vec<(&str, &str)> =
+            conflicting_terms(&terms).into_iter().map(|c| (c.falsey_term, c.truey_term)).collect();
+
+        assert_eq!(vec![("0", "0"), ("disabled", "disabled"), ("false", "FALSE")], conflicts);
+    }
+
+    #[test]
+    fn TEST_assertion_macros_PASS() {
+        crate::assert_truey!("yes");
+        crate::assert_truey!(String::from("TRUE"), "should be truey");
+        crate::assert_falsey!(" off ");
+        crate::assert_falsey!("0", "value #{} should be falsey", 1);
+        crate::assert_truthy_eq!(None, "maybe");
+        crate::assert_truthy_eq!(Some(true), "On",);
+    }
+
+    #[test]
+    #[should_panic(expected = "did you mean: \"true\"")]
+    fn TEST_assert_truey_FAIL_NEAR_MISS() {
+        crate::assert_truey!("treu");
+    }
+
+    #[test]
+    #[should_panic(expected = "matched: \"No\"")]
+    fn TEST_assert_truey_FAIL_MATCHED() {
+        crate::assert_truey!("No");
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed: flag 'X' must be off")]
+    fn TEST_assert_falsey_FAIL_MESSAGE() {
+        crate::assert_falsey!("on", "flag '{}' must be off", "X");
+    }
+
+    #[test]
+    fn TEST___assertion_failure_message() {
+        let m = __assertion_failure_message(Some(true), None, "yse", None);
+
+        assert_eq!(
+            "assertion failed: input is not truey
+     input: \"yse\"
+  expected: truey
+    actual: not truthy
+   matched: <none>
+did you mean: \"yes\"",
+            m
+        );
+    }
+}
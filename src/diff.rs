@@ -0,0 +1,353 @@
+// diff.rs - differences between flag configurations
+
+//! Differences between two snapshots of (raw) flag configuration, in terms
+//! of the classification of their values rather than of the values
+//! themselves, so that, say, deployment tooling can report that a feature
+//! was turned off, without reporting a change from `"yes"` to `"on"`.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::{
+//!     diff::diff_flags,
+//!     Terms,
+//! };
+//!
+//! use std::collections::HashMap;
+//!
+//! let old = HashMap::from([("cache", "yes"), ("metrics", "on"), ("beta", "true")]);
+//! let new = HashMap::from([("cache", "on"), ("metrics", "off"), ("tracing", "1")]);
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! let report : Vec<String> = diff_flags(&old, &new, &Terms::Default).iter().map(ToString::to_string).collect();
+//!
+//! assert_eq!(vec!["'beta' removed (was on)", "'metrics' turned off", "'tracing' added (on)"], report);
+//! # }
+//! ```
+
+use super::{
+    string_is_truthy_with,
+    Terms,
+    Truthiness,
+};
+
+use std::{
+    collections::BTreeMap,
+    fmt,
+};
+
+
+/// A change in a flag between two configurations.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub enum FlagChange {
+    /// The flag is present only in the new configuration.
+    Added {
+        key :   String,
+        /// The classification of the new value.
+        value : Truthiness,
+    },
+    /// The flag is present only in the old configuration.
+    Removed {
+        key :   String,
+        /// The classification of the old value.
+        value : Truthiness,
+    },
+    /// The flag changed from "truey" to "falsey", or vice versa.
+    Flipped {
+        key :   String,
+        /// The new state.
+        value : bool,
+    },
+    /// The flag changed from a "truthy" value to one that is not.
+    BecameUnrecognised {
+        key :       String,
+        /// The old state.
+        old_value : bool,
+        /// The new (raw) value.
+        new_value : String,
+    },
+    /// The flag changed from a value that is not "truthy" to one that is.
+    BecameRecognised {
+        key :   String,
+        /// The new state.
+        value : bool,
+    },
+}
+
+impl FlagChange {
+    /// The key of the flag.
+    pub fn key(&self) -> &str {
+        match self {
+            Self::Added {
+                key, ..
+            }
+            | Self::Removed {
+                key, ..
+            }
+            | Self::Flipped {
+                key, ..
+            }
+            | Self::BecameUnrecognised {
+                key, ..
+            }
+            | Self::BecameRecognised {
+                key, ..
+            } => key,
+        }
+    }
+}
+
+fn state_(b : bool) -> &'static str {
+    if b {
+        "on"
+    } else {
+        "off"
+    }
+}
+
+fn truthiness_(truthiness : Truthiness) -> &'static str {
+    match truthiness {
+        Truthiness::False => "off",
+        Truthiness::Unknown => "unrecognised",
+        Truthiness::True => "on",
+    }
+}
+
+impl fmt::Display for FlagChange {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Added {
+                key,
+                value,
+            } => write!(f, "'{key}' added ({})", truthiness_(*value)),
+            Self::Removed {
+                key,
+                value,
+            } => write!(f, "'{key}' removed (was {})", truthiness_(*value)),
+            Self::Flipped {
+                key,
+                value,
+            } => write!(f, "'{key}' turned {}", state_(*value)),
+            Self::BecameUnrecognised {
+                key,
+                old_value,
+                new_value,
+            } => write!(f, "'{key}' became unrecognised ({new_value:?}; was {})", state_(*old_value)),
+            Self::BecameRecognised {
+                key,
+                value,
+            } => write!(f, "'{key}' became {} (was unrecognised)", state_(*value)),
+        }
+    }
+}
+
+/// Collects the given entries into a map ordered by key, in which the last
+/// of any duplicate keys prevails.
+fn collect_<M, K, V>(map : M) -> BTreeMap<String, String>
+where
+    M : IntoIterator<Item = (K, V)>,
+    K : AsRef<str>,
+    V : AsRef<str>,
+{
+    map.into_iter()
+        .map(|(key, value)| (key.as_ref().to_owned(), value.as_ref().to_owned()))
+        .collect()
+}
+
+/// Obtains the changes in the flags between the given old and new
+/// configurations - each of which may be any collection of key/value
+/// pairs, such as `&HashMap<String, String>` - classifying the values
+/// according to the given terms.
+///
+/// A change in value that does not change its classification (e.g. from
+/// `"yes"` to `"on"`, or between two values that are not "truthy") is not
+/// reported.
+///
+/// # Returns:
+/// The changes, in order of key.
+pub fn diff_flags<O, N, OK, OV, NK, NV>(
+    old : O,
+    new : N,
+    terms : &Terms,
+) -> Vec<FlagChange>
+where
+    O : IntoIterator<Item = (OK, OV)>,
+    N : IntoIterator<Item = (NK, NV)>,
+    OK : AsRef<str>,
+    OV : AsRef<str>,
+    NK : AsRef<str>,
+    NV : AsRef<str>,
+{
+    let classify = |value : &str| Truthiness::from(string_is_truthy_with(value, terms.clone()));
+
+    let old = collect_(old);
+    let mut new = collect_(new);
+    let mut changes = Vec::new();
+
+    for (key, old_value) in old {
+        let old_truthiness = classify(&old_value);
+
+        let Some(new_value) = new.remove(&key) else {
+            changes.push(FlagChange::Removed {
+                key,
+                value : old_truthiness,
+            });
+
+            continue;
+        };
+
+        let new_truthiness = classify(&new_value);
+
+        let change = match (old_truthiness, new_truthiness) {
+            (Truthiness::True, Truthiness::False) => FlagChange::Flipped {
+                key,
+                value : false,
+            },
+            (Truthiness::False, Truthiness::True) => FlagChange::Flipped {
+                key,
+                value : true,
+            },
+            (Truthiness::True, Truthiness::Unknown) | (Truthiness::False, Truthiness::Unknown) => {
+                FlagChange::BecameUnrecognised {
+                    key,
+                    old_value : Truthiness::True == old_truthiness,
+                    new_value,
+                }
+            },
+            (Truthiness::Unknown, Truthiness::True) | (Truthiness::Unknown, Truthiness::False) => {
+                FlagChange::BecameRecognised {
+                    key,
+                    value : Truthiness::True == new_truthiness,
+                }
+            },
+            _ => continue,
+        };
+
+        changes.push(change);
+    }
+
+    changes.extend(new.into_iter().map(|(key, new_value)| {
+        FlagChange::Added {
+            key,
+            value : classify(&new_value),
+        }
+    }));
+
+    changes.sort_by(|lhs, rhs| lhs.key().cmp(rhs.key()));
+
+    changes
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::collections::HashMap;
+
+
+    fn terms_() -> Terms<'static> {
+        Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["nein", "aus"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["ja", "an"],
+        }
+    }
+
+    #[test]
+    fn TEST_diff_flags() {
+        let old = HashMap::from([
+            ("a", "ja"),
+            ("b", "ja"),
+            ("c", "nein"),
+            ("d", "ja"),
+            ("e", "vielleicht"),
+            ("f", "vielleicht"),
+            ("g", "aus"),
+        ]);
+        let new : Vec<(String, String)> = [
+            ("a", "an"),
+            ("b", "aus"),
+            ("c", "JA"),
+            ("d", "jein"),
+            ("e", "nein"),
+            ("f", "egal"),
+            ("h", "x"),
+        ]
+        .iter()
+        .map(|&(key, value)| (key.to_owned(), value.to_owned()))
+        .collect();
+
+        assert_eq!(
+            vec![
+                FlagChange::Flipped {
+                    key :   "b".to_owned(),
+                    value : false,
+                },
+                FlagChange::Flipped {
+                    key :   "c".to_owned(),
+                    value : true,
+                },
+                FlagChange::BecameUnrecognised {
+                    key :       "d".to_owned(),
+                    old_value : true,
+                    new_value : "jein".to_owned(),
+                },
+                FlagChange::BecameRecognised {
+                    key :   "e".to_owned(),
+                    value : false,
+                },
+                FlagChange::Removed {
+                    key :   "g".to_owned(),
+                    value : Truthiness::False,
+                },
+                FlagChange::Added {
+                    key :   "h".to_owned(),
+                    value : Truthiness::Unknown,
+                },
+            ],
+            diff_flags(&old, new.iter().map(|(key, value)| (key, value)), &terms_())
+        );
+    }
+
+    #[test]
+    fn TEST_diff_flags_UNCHANGED() {
+        let flags = [("a", "ja"), ("b", "?")];
+
+        assert!(diff_flags(flags, flags, &terms_()).is_empty());
+        assert!(diff_flags(Vec::<(&str, &str)>::new(), Vec::<(&str, &str)>::new(), &terms_()).is_empty());
+    }
+
+    #[test]
+    fn TEST_FlagChange_Display() {
+        let lines : Vec<String> = diff_flags(
+            [("a", "ja"), ("b", "nein"), ("c", "?"), ("d", "ja")],
+            [("a", "aus"), ("b", "?"), ("c", "an"), ("e", "nein")],
+            &terms_(),
+        )
+        .iter()
+        .map(ToString::to_string)
+        .collect();
+
+        assert_eq!(
+            vec![
+                "'a' turned off",
+                "'b' became unrecognised (\"?\"; was off)",
+                "'c' became on (was unrecognised)",
+                "'d' removed (was on)",
+                "'e' added (off)",
+            ],
+            lines
+        );
+    }
+}
@@ -0,0 +1,771 @@
+// env.rs - flags in environment variables
+
+//! Reading of flags from environment variables.
+//!
+//! Values are obtained by `std::env::var_os()`, rather than by
+//! `std::env::var()` - which fails for values that are not valid Unicode -
+//! so that a flag whose value is not valid Unicode (as may be set by
+//! exotic shells, or in other locales) may be distinguished from one that
+//! is not set.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::env::{
+//!     env_flag,
+//!     env_flag_status,
+//!     EnvFlag,
+//! };
+//!
+//! assert_eq!(EnvFlag::Unset, env_flag_status("MY_SERVICE_UNSET_FLAG"));
+//!
+//! let verbose = env_flag("MY_SERVICE_VERBOSE").unwrap_or(false);
+//! ```
+//!
+//! With the `"derive"` feature, the boolean switches of a service may be
+//! declared as the fields of a struct, from which
+//! `#[derive(FromEnvTruthy)]` implements [FromEnvTruthy].
+
+use super::{
+    os_string_is_truthy_with,
+    Terms,
+    Truthiness,
+};
+
+use std::{
+    collections::HashMap,
+    env,
+    error,
+    ffi::{
+        OsStr,
+        OsString,
+    },
+    fmt,
+};
+
+
+/// The status of a flag in an environment variable.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum EnvFlag {
+    /// The variable is not set.
+    Unset,
+    /// The value of the variable is not valid Unicode.
+    NotUnicode,
+    /// The value of the variable is not "truthy".
+    Unrecognised,
+    /// The value of the variable is "truthy", and is "truey" or "falsey"
+    /// as given.
+    Value(bool),
+}
+
+impl EnvFlag {
+    /// The value of the flag, if "truthy".
+    pub fn value(&self) -> Option<bool> {
+        match self {
+            Self::Value(b) => Some(*b),
+            _ => None,
+        }
+    }
+}
+
+/// An error in obtaining a flag from an environment variable.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct EnvFlagError {
+    /// The name of the variable.
+    pub name :   String,
+    /// The status of the flag, which is not acceptable for the type of the
+    /// field.
+    pub status : EnvFlag,
+}
+
+impl fmt::Display for EnvFlagError {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        let name = &self.name;
+
+        match self.status {
+            EnvFlag::Unset => write!(f, "environment variable '{name}' is not set"),
+            EnvFlag::NotUnicode => write!(f, "environment variable '{name}' is not valid Unicode"),
+            EnvFlag::Unrecognised => write!(f, "environment variable '{name}' is not a recognised flag"),
+            EnvFlag::Value(b) => write!(f, "environment variable '{name}' may not be {b}"),
+        }
+    }
+}
+
+impl error::Error for EnvFlagError {}
+
+/// The errors in obtaining a struct from environment variables, by
+/// [FromEnvTruthy::from_env()].
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct FromEnvError {
+    /// The errors, one for each field in error, in the order of the fields.
+    pub errors : Vec<EnvFlagError>,
+}
+
+impl fmt::Display for FromEnvError {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        for (index, error) in self.errors.iter().enumerate() {
+            if 0 != index {
+                f.write_str("; ")?;
+            }
+
+            write!(f, "{error}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl error::Error for FromEnvError {}
+
+
+/// A type that may be obtained from the status of a flag in an
+/// environment variable.
+///
+/// It is implemented for:
+/// - `bool`, for which the variable must be set to a "truthy" value;
+/// - `Option<bool>`, for which the variable may also be unset (`None`);
+/// - [Truthiness], for which the variable may also be unset or not
+///   "truthy" ([Truthiness::Unknown]);
+pub trait FromEnvFlag : Sized {
+    /// Obtains the value from the given status.
+    ///
+    /// # Returns:
+    /// - `None` - the status is not acceptable for the type;
+    /// - `Some(value)` - the value;
+    fn from_env_flag(status : EnvFlag) -> Option<Self>;
+}
+
+impl FromEnvFlag for bool {
+    fn from_env_flag(status : EnvFlag) -> Option<Self> {
+        status.value()
+    }
+}
+
+impl FromEnvFlag for Option<bool> {
+    fn from_env_flag(status : EnvFlag) -> Option<Self> {
+        match status {
+            EnvFlag::Unset => Some(None),
+            EnvFlag::Value(b) => Some(Some(b)),
+            _ => None,
+        }
+    }
+}
+
+impl FromEnvFlag for Truthiness {
+    fn from_env_flag(status : EnvFlag) -> Option<Self> {
+        match status {
+            EnvFlag::NotUnicode => None,
+            status => Some(status.value().into()),
+        }
+    }
+}
+
+/// A type that may be obtained from environment variables, typically a
+/// struct of boolean switches, as implemented by
+/// `#[derive(FromEnvTruthy)]`.
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(feature = "derive")]
+/// # {
+/// use to_be::{
+///     env::FromEnvTruthy,
+///     Truthiness,
+/// };
+///
+/// #[derive(FromEnvTruthy)]
+/// #[env(prefix = "MY_SERVICE_")]
+/// struct Switches {
+///     // from `MY_SERVICE_VERBOSE`, which is "falsey" if unset
+///     #[env(default = false)]
+///     verbose : bool,
+///     // from `MY_SERVICE_METRICS`, which may be unset
+///     metrics : Option<bool>,
+///     // from `NEW_UI`, which may be unset or not "truthy"
+///     #[env(name = "NEW_UI")]
+///     new_ui :  Truthiness,
+/// }
+///
+/// match Switches::from_env() {
+///     Ok(switches) => assert!(!switches.verbose),
+///     Err(e) => eprintln!("invalid switches: {e}"),
+/// };
+/// # }
+/// ```
+pub trait FromEnvTruthy : Sized {
+    /// Obtains the value from the environment.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - the errors of all fields whose variables are not
+    ///   acceptable;
+    /// - `Ok(value)` - the value;
+    fn from_env() -> Result<Self, FromEnvError>;
+}
+
+/// Derives [FromEnvTruthy] for a struct of flags (see **to-be-derive** for
+/// details).
+///
+/// # Note:
+/// It may be derived only for structs with named fields:
+///
+/// ```compile_fail
+/// #[derive(to_be::env::FromEnvTruthy)]
+/// struct Switches(bool);
+/// ```
+///
+/// the only attribute of the struct is `#[env(prefix = "...")]`:
+///
+/// ```compile_fail
+/// #[derive(to_be::env::FromEnvTruthy)]
+/// #[env(prefx = "MY_SERVICE_")]
+/// struct Switches {
+///     verbose : bool,
+/// }
+/// ```
+///
+/// and those of each field are `#[env(name = "...")]` and
+/// `#[env(default = true|false)]`, neither
+///
+/// ```compile_fail
+/// #[derive(to_be::env::FromEnvTruthy)]
+/// struct Switches {
+///     #[env(default = "no")]
+///     verbose : bool,
+/// }
+/// ```
+///
+/// nor
+///
+/// ```compile_fail
+/// #[derive(to_be::env::FromEnvTruthy)]
+/// struct Switches {
+///     #[env(rename = "VERBOSE")]
+///     verbose : bool,
+/// }
+/// ```
+///
+/// is permitted.
+#[cfg(feature = "derive")]
+pub use to_be_derive::FromEnvTruthy;
+
+/// Obtains a field from the environment variable of the given name - whose
+/// flag is the given default, if given, when the variable is unset - or
+/// records the error. Not part of the public API.
+#[doc(hidden)]
+pub fn __field_from_env<T>(
+    name : &str,
+    default : Option<bool>,
+    errors : &mut Vec<EnvFlagError>,
+) -> Option<T>
+where
+    T : FromEnvFlag,
+{
+    let status = match (env_flag_status(name), default) {
+        (EnvFlag::Unset, Some(b)) => EnvFlag::Value(b),
+        (status, _) => status,
+    };
+
+    let value = T::from_env_flag(status);
+
+    if value.is_none() {
+        errors.push(EnvFlagError {
+            name : name.to_owned(),
+            status,
+        });
+    }
+
+    value
+}
+
+/// Obtains the status of the flag of the given value, if any.
+fn status_of_(
+    value : Option<&OsStr>,
+    terms : Terms,
+) -> EnvFlag {
+    let Some(value) = value else {
+        return EnvFlag::Unset;
+    };
+
+    // the value is classified directly, and only when it is not "truthy" is
+    // it examined further, to distinguish content that is not valid Unicode
+
+    match os_string_is_truthy_with(value, terms) {
+        Some(b) => EnvFlag::Value(b),
+        None if value.to_str().is_none() => EnvFlag::NotUnicode,
+        None => EnvFlag::Unrecognised,
+    }
+}
+
+/// Obtains the status of the flag in the environment variable of the given
+/// name, whose value is classified according to the default vocabulary.
+pub fn env_flag_status<K>(name : K) -> EnvFlag
+where
+    K : AsRef<OsStr>,
+{
+    env_flag_status_with(name, Terms::Default)
+}
+
+/// Obtains the status of the flag in the environment variable of the given
+/// name, whose value is classified according to the given terms.
+pub fn env_flag_status_with<K>(
+    name : K,
+    terms : Terms,
+) -> EnvFlag
+where
+    K : AsRef<OsStr>,
+{
+    status_of_(env::var_os(name).as_deref(), terms)
+}
+
+/// Indicates whether the environment variable of the given name is set to
+/// a "truthy" value - according to the default vocabulary - and, if so,
+/// whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - variable is not set, or its value is not valid Unicode or
+///   is not classified as "truthy" (as distinguished by
+///   [env_flag_status()]);
+/// - `Some(false)` - value (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - value (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn env_flag<K>(name : K) -> Option<bool>
+where
+    K : AsRef<OsStr>,
+{
+    env_flag_status(name).value()
+}
+
+/// Indicates whether the environment variable of the given name is set to
+/// a "truthy" value - according to the given terms - and, if so, whether
+/// it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - variable is not set, or its value is not valid Unicode or
+///   is not classified as "truthy" (as distinguished by
+///   [env_flag_status_with()]);
+/// - `Some(false)` - value (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - value (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn env_flag_with<K>(
+    name : K,
+    terms : Terms,
+) -> Option<bool>
+where
+    K : AsRef<OsStr>,
+{
+    env_flag_status_with(name, terms).value()
+}
+
+/// Obtains the flags of the given variables whose names begin with the
+/// given prefix.
+fn flags_with_prefix_<I>(
+    vars : I,
+    prefix : &str,
+    terms : Terms,
+) -> HashMap<String, Option<bool>>
+where
+    I : IntoIterator<Item = (OsString, OsString)>,
+{
+    vars.into_iter()
+        .filter_map(|(name, value)| {
+            let name = name.to_str()?.strip_prefix(prefix)?;
+
+            if name.is_empty() {
+                return None;
+            }
+
+            Some((name.to_owned(), status_of_(Some(&value), terms.clone()).value()))
+        })
+        .collect()
+}
+
+/// Obtains, in one pass over the environment, the flags of all variables
+/// whose names begin with the given prefix, keyed by their names without
+/// the prefix, whose values are classified according to the default
+/// vocabulary.
+///
+/// Variables whose names are not valid Unicode, or are exactly the prefix,
+/// are ignored; the flag of a variable whose value is not valid Unicode or
+/// is not "truthy" is `None`.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::env::env_flags_with_prefix;
+///
+/// let features = env_flags_with_prefix("MY_SERVICE_FEATURE_");
+///
+/// let new_ui = features.get("NEW_UI").copied().flatten().unwrap_or(false);
+/// ```
+pub fn env_flags_with_prefix(prefix : &str) -> HashMap<String, Option<bool>> {
+    env_flags_with_prefix_with(prefix, Terms::Default)
+}
+
+/// Obtains, in one pass over the environment, the flags of all variables
+/// whose names begin with the given prefix, keyed by their names without
+/// the prefix, whose values are classified according to the given terms.
+///
+/// Variables are treated as by [env_flags_with_prefix()].
+pub fn env_flags_with_prefix_with(
+    prefix : &str,
+    terms : Terms,
+) -> HashMap<String, Option<bool>> {
+    flags_with_prefix_(env::vars_os(), prefix, terms)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn terms_() -> Terms<'static> {
+        Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["nein"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["ja", "jä"],
+        }
+    }
+
+    #[test]
+    fn TEST_status_of_() {
+        assert_eq!(EnvFlag::Unset, status_of_(None, terms_()));
+        assert_eq!(EnvFlag::Value(true), status_of_(Some(OsStr::new("JA")), terms_()));
+        assert_eq!(EnvFlag::Value(true), status_of_(Some(OsStr::new("jä")), terms_()));
+        assert_eq!(EnvFlag::Value(false), status_of_(Some(OsStr::new(" nein ")), terms_()));
+        assert_eq!(EnvFlag::Unrecognised, status_of_(Some(OsStr::new("")), terms_()));
+        assert_eq!(EnvFlag::Unrecognised, status_of_(Some(OsStr::new("vielleicht")), terms_()));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn TEST_status_of__NOT_UNICODE() {
+        use std::os::unix::ffi::OsStrExt as _;
+
+        assert_eq!(EnvFlag::NotUnicode, status_of_(Some(OsStr::from_bytes(b"j\xE4")), terms_()));
+        assert_eq!(EnvFlag::NotUnicode, status_of_(Some(OsStr::from_bytes(b"\xFF")), terms_()));
+        assert_eq!(EnvFlag::NotUnicode, status_of_(Some(OsStr::from_bytes(b" ja\xFF ")), terms_()));
+    }
+
+    #[test]
+    fn TEST_status_of__ASCII() {
+        assert_eq!(EnvFlag::Value(true), status_of_(Some(OsStr::new("\t Ja \r\n")), terms_()));
+        assert_eq!(EnvFlag::Value(false), status_of_(Some(OsStr::new("NEIN")), terms_()));
+        assert_eq!(EnvFlag::Unrecognised, status_of_(Some(OsStr::new(" 2 ")), terms_()));
+    }
+
+    #[test]
+    fn TEST_EnvFlag_value() {
+        assert_eq!(None, EnvFlag::Unset.value());
+        assert_eq!(None, EnvFlag::NotUnicode.value());
+        assert_eq!(None, EnvFlag::Unrecognised.value());
+        assert_eq!(Some(false), EnvFlag::Value(false).value());
+        assert_eq!(Some(true), EnvFlag::Value(true).value());
+    }
+
+    #[test]
+    fn TEST_env_flag_with() {
+        let name = "TO_BE_TEST_ENV_FLAG_WITH";
+
+        assert_eq!(EnvFlag::Unset, env_flag_status_with(name, terms_()));
+
+        env::set_var(name, "Ja");
+
+        assert_eq!(Some(true), env_flag_with(name, terms_()));
+
+        env::set_var(name, "egal");
+
+        assert_eq!(EnvFlag::Unrecognised, env_flag_status_with(name, terms_()));
+        assert_eq!(None, env_flag_with(name, terms_()));
+
+        env::remove_var(name);
+    }
+
+    #[test]
+    fn TEST_flags_with_prefix_() {
+        let vars = [
+            ("FEATURE_A", "ja"),
+            ("FEATURE_B", "NEIN"),
+            ("FEATURE_C", "egal"),
+            ("FEATURE_", "ja"),
+            ("feature_D", "ja"),
+            ("OTHER", "ja"),
+        ]
+        .map(|(name, value)| (OsString::from(name), OsString::from(value)));
+
+        let flags = flags_with_prefix_(vars.clone(), "FEATURE_", terms_());
+
+        assert_eq!(3, flags.len());
+        assert_eq!(Some(&Some(true)), flags.get("A"));
+        assert_eq!(Some(&Some(false)), flags.get("B"));
+        assert_eq!(Some(&None), flags.get("C"));
+
+        assert_eq!(6, flags_with_prefix_(vars, "", terms_()).len());
+    }
+
+    #[test]
+    fn TEST_env_flags_with_prefix_with() {
+        env::set_var("TO_BE_TEST_ENV_PREFIX_X", "ja");
+        env::set_var("TO_BE_TEST_ENV_PREFIX_Y", "nein");
+
+        let flags = env_flags_with_prefix_with("TO_BE_TEST_ENV_PREFIX_", terms_());
+
+        assert_eq!(Some(&Some(true)), flags.get("X"));
+        assert_eq!(Some(&Some(false)), flags.get("Y"));
+
+        env::remove_var("TO_BE_TEST_ENV_PREFIX_X");
+        env::remove_var("TO_BE_TEST_ENV_PREFIX_Y");
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_env_flag() {
+        let name = "TO_BE_TEST_ENV_FLAG";
+
+        assert_eq!(None, env_flag(name));
+
+        env::set_var(name, "off");
+
+        assert_eq!(Some(false), env_flag(name));
+        assert_eq!(EnvFlag::Value(false), env_flag_status(name));
+
+        env::remove_var(name);
+    }
+
+    #[test]
+    fn TEST_FromEnvFlag() {
+        for status in [EnvFlag::Unset, EnvFlag::NotUnicode, EnvFlag::Unrecognised] {
+            assert_eq!(None, bool::from_env_flag(status));
+        }
+        assert_eq!(Some(true), bool::from_env_flag(EnvFlag::Value(true)));
+
+        assert_eq!(Some(None), Option::<bool>::from_env_flag(EnvFlag::Unset));
+        assert_eq!(None, Option::<bool>::from_env_flag(EnvFlag::NotUnicode));
+        assert_eq!(None, Option::<bool>::from_env_flag(EnvFlag::Unrecognised));
+        assert_eq!(Some(Some(false)), Option::<bool>::from_env_flag(EnvFlag::Value(false)));
+
+        assert_eq!(Some(Truthiness::Unknown), Truthiness::from_env_flag(EnvFlag::Unset));
+        assert_eq!(None, Truthiness::from_env_flag(EnvFlag::NotUnicode));
+        assert_eq!(Some(Truthiness::Unknown), Truthiness::from_env_flag(EnvFlag::Unrecognised));
+        assert_eq!(Some(Truthiness::True), Truthiness::from_env_flag(EnvFlag::Value(true)));
+    }
+
+    #[test]
+    fn TEST_FromEnvError_Display() {
+        let e = FromEnvError {
+            errors : vec![
+                EnvFlagError {
+                    name :   "A".to_owned(),
+                    status : EnvFlag::Unset,
+                },
+                EnvFlagError {
+                    name :   "B".to_owned(),
+                    status : EnvFlag::Unrecognised,
+                },
+            ],
+        };
+
+        assert_eq!(
+            "environment variable 'A' is not set; environment variable 'B' is not a recognised flag",
+            e.to_string()
+        );
+    }
+
+    #[cfg(feature = "derive")]
+    mod test_derive {
+        #![allow(non_snake_case)]
+        #![allow(dead_code)]
+
+        use super::*;
+
+
+        #[derive(FromEnvTruthy)]
+        #[env(prefix = "TO_BE_TEST_DERIVE_")]
+        struct Switches {
+            required :  bool,
+            #[env(default = true)]
+            defaulted : bool,
+            optional :  Option<bool>,
+            #[env(name = "TO_BE_TEST_DERIVE_OTHER_NAME")]
+            renamed :   Truthiness,
+        }
+
+        #[derive(FromEnvTruthy)]
+        struct Unprefixed {
+            to_be_test_derive_unprefixed : Option<bool>,
+        }
+
+        #[derive(FromEnvTruthy)]
+        #[env(prefix = "TO_BE_TEST_DERIVE_COLLIDING_")]
+        struct Colliding {
+            errors :         Option<bool>,
+            __to_be_errors : Option<bool>,
+            #[env(default = false)]
+            r#type :         bool,
+        }
+
+        #[derive(FromEnvTruthy)]
+        #[env(prefix = "TO_BE_TEST_DERIVE_")]
+        struct Attributed {
+            #[env(name = "TO_BE_TEST_DERIVE_RENAMED_A", default = false)]
+            a : bool,
+            #[env(default = true)]
+            #[env(name = "TO_BE_TEST_DERIVE_RENAMED_B")]
+            b : Truthiness,
+            #[env(name = "TO_BE_TEST_DERIVE_RENAMED_C")]
+            c : bool,
+            #[env(default = true)]
+            d : Option<bool>,
+        }
+
+
+        #[test]
+        fn TEST_derive_FromEnvTruthy_NAME_AND_DEFAULT() {
+            let errors = Attributed::from_env().map(|_| ()).unwrap_err().errors;
+
+            assert_eq!(
+                vec![EnvFlagError {
+                    name :   "TO_BE_TEST_DERIVE_RENAMED_C".to_owned(),
+                    status : EnvFlag::Unset,
+                }],
+                errors
+            );
+
+            env::set_var("TO_BE_TEST_DERIVE_RENAMED_C", "not-a-flag");
+
+            let errors = Attributed::from_env().map(|_| ()).unwrap_err().errors;
+
+            assert_eq!(
+                vec![EnvFlagError {
+                    name :   "TO_BE_TEST_DERIVE_RENAMED_C".to_owned(),
+                    status : EnvFlag::Unrecognised,
+                }],
+                errors
+            );
+
+            #[cfg(to_be_stock_default)]
+            {
+                env::set_var("TO_BE_TEST_DERIVE_RENAMED_C", "yes");
+                env::set_var("TO_BE_TEST_DERIVE_RENAMED_B", "no");
+                env::set_var("TO_BE_TEST_DERIVE_D", "no");
+
+                let attributed = Attributed::from_env().unwrap();
+
+                assert!(!attributed.a);
+                assert_eq!(Truthiness::False, attributed.b);
+                assert!(attributed.c);
+                assert_eq!(Some(false), attributed.d);
+
+                env::remove_var("TO_BE_TEST_DERIVE_RENAMED_B");
+                env::remove_var("TO_BE_TEST_DERIVE_D");
+
+                let attributed = Attributed::from_env().unwrap();
+
+                assert!(!attributed.a);
+                assert_eq!(Truthiness::True, attributed.b);
+                assert_eq!(Some(true), attributed.d);
+            }
+
+            env::remove_var("TO_BE_TEST_DERIVE_RENAMED_C");
+        }
+
+        #[test]
+        fn TEST_derive_FromEnvTruthy_COLLIDING_FIELD_NAMES() {
+            let colliding = Colliding::from_env().unwrap();
+
+            assert_eq!(None, colliding.errors);
+            assert_eq!(None, colliding.__to_be_errors);
+            assert!(!colliding.r#type);
+
+            env::set_var("TO_BE_TEST_DERIVE_COLLIDING_ERRORS", "not-a-flag");
+
+            let errors = Colliding::from_env().map(|_| ()).unwrap_err().errors;
+
+            assert_eq!(
+                vec![EnvFlagError {
+                    name :   "TO_BE_TEST_DERIVE_COLLIDING_ERRORS".to_owned(),
+                    status : EnvFlag::Unrecognised,
+                }],
+                errors
+            );
+
+            env::remove_var("TO_BE_TEST_DERIVE_COLLIDING_ERRORS");
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_derive_FromEnvTruthy() {
+            let errors = Switches::from_env().map(|_| ()).unwrap_err().errors;
+
+            assert_eq!(
+                vec![EnvFlagError {
+                    name :   "TO_BE_TEST_DERIVE_REQUIRED".to_owned(),
+                    status : EnvFlag::Unset,
+                }],
+                errors
+            );
+
+            env::set_var("TO_BE_TEST_DERIVE_REQUIRED", "yes");
+            env::set_var("TO_BE_TEST_DERIVE_OTHER_NAME", "no");
+
+            let switches = Switches::from_env().unwrap();
+
+            assert!(switches.required);
+            assert!(switches.defaulted);
+            assert_eq!(None, switches.optional);
+            assert_eq!(Truthiness::False, switches.renamed);
+
+            env::set_var("TO_BE_TEST_DERIVE_DEFAULTED", "maybe");
+            env::set_var("TO_BE_TEST_DERIVE_OPTIONAL", "perhaps");
+
+            let errors = Switches::from_env().map(|_| ()).unwrap_err().errors;
+            let names : Vec<&str> = errors.iter().map(|error| error.name.as_str()).collect();
+
+            assert_eq!(vec!["TO_BE_TEST_DERIVE_DEFAULTED", "TO_BE_TEST_DERIVE_OPTIONAL"], names);
+
+            for name in [
+                "TO_BE_TEST_DERIVE_REQUIRED",
+                "TO_BE_TEST_DERIVE_DEFAULTED",
+                "TO_BE_TEST_DERIVE_OPTIONAL",
+                "TO_BE_TEST_DERIVE_OTHER_NAME",
+            ] {
+                env::remove_var(name);
+            }
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_derive_FromEnvTruthy_UNPREFIXED() {
+            assert_eq!(None, Unprefixed::from_env().unwrap().to_be_test_derive_unprefixed);
+
+            env::set_var("TO_BE_TEST_DERIVE_UNPREFIXED", "on");
+
+            assert_eq!(Some(true), Unprefixed::from_env().unwrap().to_be_test_derive_unprefixed);
+
+            env::remove_var("TO_BE_TEST_DERIVE_UNPREFIXED");
+        }
+    }
+}
@@ -0,0 +1,574 @@
+// expr.rs - boolean expressions over truthy atoms
+
+//! Boolean expressions - such as feature-flag rules, e.g.
+//! `"FEATURE_A && !FEATURE_B || yes"` - whose atoms are resolved by
+//! classification, and which are evaluated according to Kleene's
+//! three-valued logic (see [Truthiness::and()] and [Truthiness::or()]), so
+//! that an atom that is not "truthy" yields an unknown result only where it
+//! matters.
+//!
+//! The grammar, in order of increasing precedence, is:
+//! - `a || b` - disjunction;
+//! - `a && b` - conjunction;
+//! - `!a` - negation;
+//! - `(a)` - grouping;
+//!
+//! and an atom is any sequence of characters other than whitespace and
+//! `()!&|`.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::{
+//!     expr::evaluate_with,
+//!     Truthiness,
+//! };
+//!
+//! let lookup = |name : &str| match name {
+//!     "FEATURE_A" => Some("on".to_owned()),
+//!     "FEATURE_B" => Some("off".to_owned()),
+//!     _ => None,
+//! };
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! assert_eq!(Ok(Truthiness::True), evaluate_with("FEATURE_A && !FEATURE_B", lookup));
+//! assert_eq!(Ok(Truthiness::True), evaluate_with("FEATURE_C || yes", lookup));
+//! assert_eq!(Ok(Truthiness::Unknown), evaluate_with("FEATURE_A && FEATURE_C", lookup));
+//! assert_eq!(Ok(Truthiness::False), evaluate_with("FEATURE_B && FEATURE_C", lookup));
+//! # }
+//! assert!(evaluate_with("FEATURE_A &&", lookup).is_err());
+//! ```
+
+use super::{
+    string_is_truthy,
+    Truthiness,
+};
+
+use std::{
+    error,
+    fmt,
+    str::FromStr,
+};
+
+
+/// The maximum depth to which negations and parentheses may be nested, so
+/// that parsing (and evaluating, and dropping) an untrusted expression
+/// cannot exhaust the stack.
+///
+/// # Note:
+/// Chains of conjunctions and disjunctions are not limited, since they are
+/// held - and evaluated - as sequences, rather than nested (see
+/// [Expression]).
+pub const MAX_DEPTH : usize = 64;
+
+/// An error in parsing an expression.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub enum ExpressionError {
+    /// The expression ended where an operand, or a closing parenthesis,
+    /// was expected.
+    UnexpectedEnd,
+    /// A token was found where it is not permitted.
+    UnexpectedToken {
+        /// The (0-based) byte position of the token.
+        position : usize,
+        /// The text of the token.
+        token :    String,
+    },
+    /// Negations and parentheses are nested more deeply than
+    /// [MAX_DEPTH].
+    TooDeep {
+        /// The (0-based) byte position at which the limit was exceeded.
+        position : usize,
+    },
+}
+
+impl fmt::Display for ExpressionError {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::UnexpectedEnd => write!(f, "unexpected end of expression"),
+            Self::UnexpectedToken {
+                position,
+                token,
+            } => write!(f, "unexpected '{token}' at position {position}"),
+            Self::TooDeep {
+                position,
+            } => write!(f, "expression is nested more deeply than {MAX_DEPTH} at position {position}"),
+        }
+    }
+}
+
+impl error::Error for ExpressionError {}
+
+
+/// A parsed expression.
+///
+/// A chain of conjunctions (or disjunctions), such as `a && b && c`, is
+/// held as a single conjunction (or disjunction) of (at least two)
+/// operands, so that the depth of an expression is bounded by the nesting
+/// of its negations and parentheses (see [MAX_DEPTH]), however long it is.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub enum Expression {
+    /// An atom, which is resolved by classification.
+    Atom(String),
+    /// The negation of the expression.
+    Not(Box<Expression>),
+    /// The conjunction of the expressions.
+    And(Vec<Expression>),
+    /// The disjunction of the expressions.
+    Or(Vec<Expression>),
+}
+
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+enum Token<'a> {
+    LeftParenthesis,
+    RightParenthesis,
+    Not,
+    And,
+    Or,
+    Atom(&'a str),
+}
+
+/// Splits the given expression into its tokens, each with its position.
+fn tokenise_(s : &str) -> Result<Vec<(usize, Token<'_>)>, ExpressionError> {
+    let mut tokens = Vec::new();
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((position, c)) = chars.next() {
+        let token = match c {
+            _ if c.is_whitespace() => continue,
+            '(' => Token::LeftParenthesis,
+            ')' => Token::RightParenthesis,
+            '!' => Token::Not,
+            '&' | '|' => {
+                if chars.next_if(|&(_, next)| next == c).is_none() {
+                    return Err(ExpressionError::UnexpectedToken {
+                        position,
+                        token : c.to_string(),
+                    });
+                }
+
+                if '&' == c {
+                    Token::And
+                } else {
+                    Token::Or
+                }
+            },
+            _ => {
+                let mut end = position + c.len_utf8();
+
+                while let Some((index, next)) = chars.next_if(|&(_, next)| !is_delimiter_(next)) {
+                    end = index + next.len_utf8();
+                }
+
+                Token::Atom(&s[position..end])
+            },
+        };
+
+        tokens.push((position, token));
+    }
+
+    Ok(tokens)
+}
+
+fn is_delimiter_(c : char) -> bool {
+    c.is_whitespace() || "()!&|".contains(c)
+}
+
+/// A recursive-descent parser over the tokens of an expression.
+struct Parser<'a> {
+    tokens : Vec<(usize, Token<'a>)>,
+    index :  usize,
+    depth :  usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek_(&self) -> Option<Token<'a>> {
+        self.tokens.get(self.index).map(|&(_, token)| token)
+    }
+
+    fn unexpected_(&self) -> ExpressionError {
+        match self.tokens.get(self.index) {
+            Some(&(position, token)) => ExpressionError::UnexpectedToken {
+                position,
+                token : match token {
+                    Token::LeftParenthesis => "(",
+                    Token::RightParenthesis => ")",
+                    Token::Not => "!",
+                    Token::And => "&&",
+                    Token::Or => "||",
+                    Token::Atom(atom) => atom,
+                }
+                .to_owned(),
+            },
+            None => ExpressionError::UnexpectedEnd,
+        }
+    }
+
+    fn parse_or_(&mut self) -> Result<Expression, ExpressionError> {
+        let mut operands = vec![self.parse_and_()?];
+
+        while Some(Token::Or) == self.peek_() {
+            self.index += 1;
+
+            operands.push(self.parse_and_()?);
+        }
+
+        Ok(Self::chain_(operands, Expression::Or))
+    }
+
+    fn parse_and_(&mut self) -> Result<Expression, ExpressionError> {
+        let mut operands = vec![self.parse_unary_()?];
+
+        while Some(Token::And) == self.peek_() {
+            self.index += 1;
+
+            operands.push(self.parse_unary_()?);
+        }
+
+        Ok(Self::chain_(operands, Expression::And))
+    }
+
+    /// Obtains the sole operand, if only one, or otherwise the chain of the
+    /// operands.
+    fn chain_(
+        mut operands : Vec<Expression>,
+        f : fn(Vec<Expression>) -> Expression,
+    ) -> Expression {
+        if 1 == operands.len() {
+            operands.pop().unwrap()
+        } else {
+            f(operands)
+        }
+    }
+
+    fn parse_unary_(&mut self) -> Result<Expression, ExpressionError> {
+        match self.peek_() {
+            Some(Token::Atom(atom)) => {
+                self.index += 1;
+
+                Ok(Expression::Atom(atom.to_owned()))
+            },
+            Some(Token::Not | Token::LeftParenthesis) => {
+                if MAX_DEPTH == self.depth {
+                    return Err(ExpressionError::TooDeep {
+                        position : self.tokens[self.index].0,
+                    });
+                }
+
+                self.depth += 1;
+
+                let expression = if Some(Token::Not) == self.peek_() {
+                    self.index += 1;
+
+                    Expression::Not(Box::new(self.parse_unary_()?))
+                } else {
+                    self.index += 1;
+
+                    let expression = self.parse_or_()?;
+
+                    if Some(Token::RightParenthesis) != self.peek_() {
+                        return Err(self.unexpected_());
+                    }
+
+                    self.index += 1;
+
+                    expression
+                };
+
+                self.depth -= 1;
+
+                Ok(expression)
+            },
+            _ => Err(self.unexpected_()),
+        }
+    }
+}
+
+impl Expression {
+    /// Parses the given string as an expression.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - the string is not a well-formed expression;
+    /// - `Ok(expression)` - the expression;
+    pub fn parse(s : &str) -> Result<Self, ExpressionError> {
+        let mut parser = Parser {
+            tokens : tokenise_(s)?,
+            index :  0,
+            depth :  0,
+        };
+
+        let expression = parser.parse_or_()?;
+
+        match parser.peek_() {
+            None => Ok(expression),
+            Some(_) => Err(parser.unexpected_()),
+        }
+    }
+
+    /// Evaluates the expression, classifying each atom itself as by
+    /// [string_is_truthy()](crate::string_is_truthy()), so that an atom
+    /// that is not "truthy" is unknown.
+    pub fn evaluate(&self) -> Truthiness {
+        self.evaluate_with(|_| None)
+    }
+
+    /// Evaluates the expression, classifying, as by
+    /// [string_is_truthy()](crate::string_is_truthy()), the value obtained
+    /// by the given lookup for each atom or, if the lookup obtains `None`,
+    /// the atom itself (so that literals, such as `yes`, may be used
+    /// alongside variables), such that an atom that is not "truthy" is
+    /// unknown.
+    ///
+    /// # Note:
+    /// Evaluation stops as soon as the result of a conjunction or
+    /// disjunction is determined, so the lookup is not necessarily invoked
+    /// for every atom.
+    pub fn evaluate_with<F>(
+        &self,
+        mut lookup : F,
+    ) -> Truthiness
+    where
+        F : FnMut(&str) -> Option<String>,
+    {
+        self.evaluate_(&mut lookup)
+    }
+
+    fn evaluate_(
+        &self,
+        lookup : &mut dyn FnMut(&str) -> Option<String>,
+    ) -> Truthiness {
+        match self {
+            Self::Atom(atom) => {
+                let r = match lookup(atom) {
+                    Some(value) => string_is_truthy(&value),
+                    None => string_is_truthy(atom),
+                };
+
+                Truthiness::from(r)
+            },
+            Self::Not(expression) => !expression.evaluate_(lookup),
+            Self::And(operands) => {
+                let mut r = Truthiness::True;
+
+                for operand in operands {
+                    r = r.and(operand.evaluate_(lookup));
+
+                    if Truthiness::False == r {
+                        break;
+                    }
+                }
+
+                r
+            },
+            Self::Or(operands) => {
+                let mut r = Truthiness::False;
+
+                for operand in operands {
+                    r = r.or(operand.evaluate_(lookup));
+
+                    if Truthiness::True == r {
+                        break;
+                    }
+                }
+
+                r
+            },
+        }
+    }
+}
+
+impl FromStr for Expression {
+    type Err = ExpressionError;
+
+    fn from_str(s : &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// Parses and evaluates the given expression, as by [Expression::parse()]
+/// and [Expression::evaluate()].
+pub fn evaluate(s : &str) -> Result<Truthiness, ExpressionError> {
+    Expression::parse(s).map(|expression| expression.evaluate())
+}
+
+/// Parses and evaluates the given expression, as by [Expression::parse()]
+/// and [Expression::evaluate_with()].
+pub fn evaluate_with<F>(
+    s : &str,
+    lookup : F,
+) -> Result<Truthiness, ExpressionError>
+where
+    F : FnMut(&str) -> Option<String>,
+{
+    Expression::parse(s).map(|expression| expression.evaluate_with(lookup))
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn atom_(s : &str) -> Expression {
+        Expression::Atom(s.to_owned())
+    }
+
+    fn not_(expression : Expression) -> Expression {
+        Expression::Not(Box::new(expression))
+    }
+
+    #[test]
+    fn TEST_Expression_parse() {
+        assert_eq!(Ok(atom_("a")), Expression::parse(" a "));
+        assert_eq!(
+            Ok(Expression::Or(vec![Expression::And(vec![atom_("a"), not_(atom_("b"))]), atom_("c")])),
+            Expression::parse("a && !b || c")
+        );
+        assert_eq!(
+            Ok(Expression::And(vec![atom_("a"), Expression::Or(vec![atom_("b"), atom_("c")])])),
+            "a&&(b||c)".parse()
+        );
+        assert_eq!(Ok(Expression::Or(vec![atom_("a"), atom_("b"), atom_("c")])), Expression::parse("a || b || c"));
+        assert_eq!(
+            Ok(Expression::Or(vec![Expression::Or(vec![atom_("a"), atom_("b")]), atom_("c")])),
+            Expression::parse("(a || b) || c")
+        );
+        assert_eq!(Ok(not_(not_(atom_("x.y-z:1")))), Expression::parse("!!x.y-z:1"));
+    }
+
+    #[test]
+    fn TEST_Expression_LONG_CHAINS() {
+        const N : usize = 100_000;
+
+        let and_chain = "yes && ".repeat(N) + "yes";
+        let or_chain = "no || ".repeat(N) + "!(no)";
+
+        let and_expression = Expression::parse(&and_chain).unwrap();
+        let or_expression = Expression::parse(&or_chain).unwrap();
+
+        assert!(matches!(&and_expression, Expression::And(operands) if N + 1 == operands.len()));
+        assert!(matches!(&or_expression, Expression::Or(operands) if N + 1 == operands.len()));
+
+        #[cfg(to_be_stock_default)]
+        {
+            assert_eq!(Truthiness::True, and_expression.evaluate());
+            assert_eq!(Truthiness::True, or_expression.evaluate());
+        }
+
+        let mut looked_up = 0;
+
+        assert_eq!(
+            Truthiness::Unknown,
+            and_expression.evaluate_with(|_| {
+                looked_up += 1;
+
+                Some("?".to_owned())
+            })
+        );
+        assert_eq!(N + 1, looked_up);
+
+        drop(and_expression);
+        drop(or_expression);
+    }
+
+    #[test]
+    fn TEST_Expression_parse_ERRORS() {
+        let unexpected = |position, token : &str| {
+            Err(ExpressionError::UnexpectedToken {
+                position,
+                token : token.to_owned(),
+            })
+        };
+
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), Expression::parse(""));
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), Expression::parse("a &&"));
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), Expression::parse("(a"));
+        assert_eq!(Err(ExpressionError::UnexpectedEnd), Expression::parse("!"));
+        assert_eq!(unexpected(2, "&"), Expression::parse("a & b"));
+        assert_eq!(unexpected(2, "|"), Expression::parse("a |"));
+        assert_eq!(unexpected(1, ")"), Expression::parse("a)"));
+        assert_eq!(unexpected(2, "b"), Expression::parse("a b"));
+        assert_eq!(unexpected(0, "||"), Expression::parse("|| a"));
+        assert_eq!(unexpected(1, ")"), Expression::parse("()"));
+
+        let deep = "!".repeat(MAX_DEPTH) + "a";
+
+        assert!(Expression::parse(&deep).is_ok());
+        assert_eq!(
+            Err(ExpressionError::TooDeep {
+                position : MAX_DEPTH,
+            }),
+            Expression::parse(&format!("!{}", "(".repeat(MAX_DEPTH)))
+        );
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_evaluate_with() {
+        let lookup = |name : &str| {
+            match name {
+                "T" => Some("1".to_owned()),
+                "F" => Some("0".to_owned()),
+                "U" => Some("?".to_owned()),
+                _ => None,
+            }
+        };
+
+        for (expected, s) in [
+            (Truthiness::True, "T"),
+            (Truthiness::False, "F"),
+            (Truthiness::Unknown, "U"),
+            (Truthiness::Unknown, "missing"),
+            (Truthiness::True, "1"),
+            (Truthiness::False, "!T"),
+            (Truthiness::Unknown, "!U"),
+            (Truthiness::False, "F && U"),
+            (Truthiness::Unknown, "T && U"),
+            (Truthiness::True, "U || T"),
+            (Truthiness::Unknown, "U || F"),
+            (Truthiness::True, "T && !F || U"),
+            (Truthiness::False, "T && !(F || T)"),
+            (Truthiness::True, "F || 1"),
+        ] {
+            assert_eq!(Ok(expected), evaluate_with(s, lookup), "'{s}'");
+        }
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_evaluate_with_SHORT_CIRCUITS() {
+        let mut looked_up = Vec::new();
+
+        let r = evaluate_with("a && b || c", |name| {
+            looked_up.push(name.to_owned());
+
+            Some(if "c" == name { "1" } else { "0" }.to_owned())
+        });
+
+        assert_eq!(Ok(Truthiness::True), r);
+        assert_eq!(vec!["a", "c"], looked_up);
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_evaluate() {
+        assert_eq!(Ok(Truthiness::True), evaluate("yes && !off"));
+        assert_eq!(Ok(Truthiness::Unknown), evaluate("yes && maybe"));
+        assert_eq!(Ok(Truthiness::False), evaluate("(TRUE || maybe) && FALSE"));
+    }
+}
@@ -0,0 +1,363 @@
+// ffi.rs - C-compatible API
+
+//! C-compatible API, whose declarations are in the header `include/to_be.h`.
+//!
+//! The crate may be built as a shared or static library for use from C
+//! (and C++) by, e.g.:
+//!
+//! ```text
+//! cargo rustc --release --features ffi --crate-type cdylib
+//! cargo rustc --release --features ffi --crate-type staticlib
+//! ```
+//!
+//! All strings passed to the API must be nul-terminated; those that are not
+//! valid UTF-8 are not "truthy".
+
+use super::{
+    locale,
+    CompiledTerms,
+    Terms,
+};
+
+use std::{
+    ffi::CStr,
+    os::raw::{
+        c_char,
+        c_int,
+    },
+    ptr,
+};
+
+
+/// Custom terms, prepared for classification, that are created by
+/// `to_be_terms_create()` (or `to_be_terms_create_for_locale()`) and
+/// destroyed by `to_be_terms_destroy()`.
+pub struct ToBeTerms {
+    terms : CompiledTerms,
+}
+
+
+/// Obtains the string from the given pointer.
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul-terminated string.
+unsafe fn str_from_ptr_<'a>(s : *const c_char) -> Option<Result<&'a str, ()>> {
+    if s.is_null() {
+        None
+    } else {
+        Some(unsafe { CStr::from_ptr(s) }.to_str().map_err(|_| ()))
+    }
+}
+
+/// Obtains the strings from the given array of pointers.
+///
+/// # Safety
+///
+/// `strings` must be null (in which case `num_strings` must be 0) or point
+/// to `num_strings` pointers to nul-terminated strings.
+unsafe fn strs_from_ptrs_<'a>(
+    strings : *const *const c_char,
+    num_strings : usize,
+) -> Option<Vec<&'a str>> {
+    if 0 == num_strings {
+        return Some(Vec::new());
+    }
+
+    if strings.is_null() {
+        return None;
+    }
+
+    unsafe { std::slice::from_raw_parts(strings, num_strings) }
+        .iter()
+        .map(|&s| match unsafe { str_from_ptr_(s) } {
+            Some(Ok(s)) => Some(s),
+            _ => None,
+        })
+        .collect()
+}
+
+/// Translates the given classification into the return value and, if
+/// `out` is not null, the output parameter of the `*_is_truthy()`
+/// functions.
+///
+/// # Safety
+///
+/// `out` must be null or point to a writable `int`.
+unsafe fn report_(
+    classification : Option<bool>,
+    out : *mut c_int,
+) -> c_int {
+    match classification {
+        Some(truey) => {
+            if !out.is_null() {
+                unsafe { *out = c_int::from(truey) };
+            }
+
+            1
+        },
+        None => 0,
+    }
+}
+
+
+/// Indicates whether the given string, when trimmed, is "truthy" according
+/// to the default vocabulary and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `-1` - `s` is null;
+/// - `0` - string is not classified as "truthy";
+/// - `1` - string is classified as "truthy", and, if `out` is not null,
+///   `*out` is set to `1` if it is "truey" and to `0` if it is "falsey";
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul-terminated string, and `out` must be
+/// null or point to a writable `int`.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_string_is_truthy(
+    s : *const c_char,
+    out : *mut c_int,
+) -> c_int {
+    match unsafe { str_from_ptr_(s) } {
+        None => -1,
+        Some(Ok(s)) => unsafe { report_(super::string_is_truthy(s), out) },
+        Some(Err(())) => 0,
+    }
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "falsey"
+/// according to the default vocabulary.
+///
+/// # Returns:
+/// `1` if so, otherwise (including if `s` is null) `0`.
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_string_is_falsey(s : *const c_char) -> c_int {
+    c_int::from(matches!(unsafe { str_from_ptr_(s) }, Some(Ok(s)) if super::string_is_falsey(s)))
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "truey"
+/// according to the default vocabulary.
+///
+/// # Returns:
+/// `1` if so, otherwise (including if `s` is null) `0`.
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_string_is_truey(s : *const c_char) -> c_int {
+    c_int::from(matches!(unsafe { str_from_ptr_(s) }, Some(Ok(s)) if super::string_is_truey(s)))
+}
+
+/// Creates custom terms from the given "falsey" and "truey" term strings,
+/// which are matched case-insensitively.
+///
+/// # Returns:
+/// - `NULL` - an array is null (with a non-zero count), or a term string is
+///   null or not valid UTF-8;
+/// - otherwise, the terms, which must be destroyed by
+///   `to_be_terms_destroy()`;
+///
+/// # Safety
+///
+/// Each of `falsey_terms` and `truey_terms` must be null (in which case the
+/// corresponding count must be 0) or point to the given number of pointers
+/// to nul-terminated strings.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_terms_create(
+    falsey_terms : *const *const c_char,
+    num_falsey_terms : usize,
+    truey_terms : *const *const c_char,
+    num_truey_terms : usize,
+) -> *mut ToBeTerms {
+    let falsey_strings = unsafe { strs_from_ptrs_(falsey_terms, num_falsey_terms) };
+    let truey_strings = unsafe { strs_from_ptrs_(truey_terms, num_truey_terms) };
+
+    let (Some(falsey_strings), Some(truey_strings)) = (falsey_strings, truey_strings) else {
+        return ptr::null_mut();
+    };
+
+    let terms = CompiledTerms::new(Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &falsey_strings,
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &truey_strings,
+    });
+
+    Box::into_raw(Box::new(ToBeTerms {
+        terms,
+    }))
+}
+
+/// Creates the terms registered for the given locale, whose tag is matched
+/// as by `to_be::locale::terms_for_locale()`.
+///
+/// # Returns:
+/// - `NULL` - `tag` is null or not valid UTF-8, or no terms are registered
+///   for the locale;
+/// - otherwise, the terms, which must be destroyed by
+///   `to_be_terms_destroy()`;
+///
+/// # Safety
+///
+/// `tag` must be null or point to a nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_terms_create_for_locale(tag : *const c_char) -> *mut ToBeTerms {
+    let Some(Ok(tag)) = (unsafe { str_from_ptr_(tag) }) else {
+        return ptr::null_mut();
+    };
+
+    match locale::terms_for_locale(tag) {
+        Some(terms) => Box::into_raw(Box::new(ToBeTerms {
+            terms : CompiledTerms::new(terms),
+        })),
+        None => ptr::null_mut(),
+    }
+}
+
+/// Destroys the given terms. Does nothing if `terms` is null.
+///
+/// # Safety
+///
+/// `terms` must be null or have been returned by `to_be_terms_create()` (or
+/// `to_be_terms_create_for_locale()`) and not already destroyed.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_terms_destroy(terms : *mut ToBeTerms) {
+    if !terms.is_null() {
+        drop(unsafe { Box::from_raw(terms) });
+    }
+}
+
+/// Indicates whether the given string, when trimmed, is "truthy" according
+/// to the given terms and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `-1` - `terms` or `s` is null;
+/// - `0` - string is not classified as "truthy";
+/// - `1` - string is classified as "truthy", and, if `out` is not null,
+///   `*out` is set to `1` if it is "truey" and to `0` if it is "falsey";
+///
+/// # Safety
+///
+/// `terms` must be null or a live pointer returned by
+/// `to_be_terms_create()` (or `to_be_terms_create_for_locale()`), `s` must
+/// be null or point to a nul-terminated string, and `out` must be null or
+/// point to a writable `int`.
+#[no_mangle]
+pub unsafe extern "C" fn to_be_terms_string_is_truthy(
+    terms : *const ToBeTerms,
+    s : *const c_char,
+    out : *mut c_int,
+) -> c_int {
+    let Some(terms) = (unsafe { terms.as_ref() }) else {
+        return -1;
+    };
+
+    match unsafe { str_from_ptr_(s) } {
+        None => -1,
+        Some(Ok(s)) => unsafe { report_(terms.terms.string_is_truthy(s), out) },
+        Some(Err(())) => 0,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::ffi::CString;
+
+
+    #[test]
+    fn TEST_header_IS_UP_TO_DATE() {
+        assert_eq!(
+            include_str!(concat!(env!("OUT_DIR"), "/to_be.h")),
+            include_str!("../include/to_be.h"),
+            "include/to_be.h is out of date: copy it from the build directory"
+        );
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_to_be_string_is_truthy() {
+        let mut out : c_int = -1;
+
+        unsafe {
+            assert_eq!(1, to_be_string_is_truthy(c" Yes ".as_ptr(), &mut out));
+            assert_eq!(1, out);
+            assert_eq!(1, to_be_string_is_truthy(c"off".as_ptr(), &mut out));
+            assert_eq!(0, out);
+            assert_eq!(1, to_be_string_is_truthy(c"0".as_ptr(), ptr::null_mut()));
+            assert_eq!(0, to_be_string_is_truthy(c"maybe".as_ptr(), &mut out));
+            assert_eq!(0, to_be_string_is_truthy(c"\xff".as_ptr(), &mut out));
+            assert_eq!(-1, to_be_string_is_truthy(ptr::null(), &mut out));
+
+            assert_eq!(1, to_be_string_is_truey(c"true".as_ptr()));
+            assert_eq!(0, to_be_string_is_truey(c"false".as_ptr()));
+            assert_eq!(0, to_be_string_is_truey(ptr::null()));
+            assert_eq!(1, to_be_string_is_falsey(c"false".as_ptr()));
+            assert_eq!(0, to_be_string_is_falsey(c"true".as_ptr()));
+        }
+    }
+
+    #[test]
+    fn TEST_to_be_terms_create() {
+        let falsey_terms = [CString::new("Non").unwrap()];
+        let truey_terms = [CString::new("oui").unwrap(), CString::new("Si").unwrap()];
+        let falsey_ptrs : Vec<*const c_char> = falsey_terms.iter().map(|s| s.as_ptr()).collect();
+        let truey_ptrs : Vec<*const c_char> = truey_terms.iter().map(|s| s.as_ptr()).collect();
+
+        let mut out : c_int = -1;
+
+        unsafe {
+            let terms = to_be_terms_create(falsey_ptrs.as_ptr(), falsey_ptrs.len(), truey_ptrs.as_ptr(), truey_ptrs.len());
+
+            assert!(!terms.is_null());
+
+            assert_eq!(1, to_be_terms_string_is_truthy(terms, c"OUI".as_ptr(), &mut out));
+            assert_eq!(1, out);
+            assert_eq!(1, to_be_terms_string_is_truthy(terms, c" non ".as_ptr(), &mut out));
+            assert_eq!(0, out);
+            assert_eq!(0, to_be_terms_string_is_truthy(terms, c"yes".as_ptr(), &mut out));
+            assert_eq!(-1, to_be_terms_string_is_truthy(terms, ptr::null(), &mut out));
+            assert_eq!(-1, to_be_terms_string_is_truthy(ptr::null(), c"oui".as_ptr(), &mut out));
+
+            to_be_terms_destroy(terms);
+            to_be_terms_destroy(ptr::null_mut());
+
+            assert!(to_be_terms_create(ptr::null(), 1, ptr::null(), 0).is_null());
+
+            let empty = to_be_terms_create(ptr::null(), 0, ptr::null(), 0);
+
+            assert!(!empty.is_null());
+            assert_eq!(0, to_be_terms_string_is_truthy(empty, c"yes".as_ptr(), &mut out));
+
+            to_be_terms_destroy(empty);
+        }
+    }
+
+    #[test]
+    fn TEST_to_be_terms_create_for_locale() {
+        let mut out : c_int = -1;
+
+        unsafe {
+            let terms = to_be_terms_create_for_locale(c"en".as_ptr());
+
+            assert!(!terms.is_null());
+            assert_eq!(1, to_be_terms_string_is_truthy(terms, c"yes".as_ptr(), &mut out));
+            assert_eq!(1, out);
+
+            to_be_terms_destroy(terms);
+
+            assert!(to_be_terms_create_for_locale(c"xx-unknown".as_ptr()).is_null());
+            assert!(to_be_terms_create_for_locale(ptr::null()).is_null());
+        }
+    }
+}
@@ -0,0 +1,670 @@
+// fmt.rs - rendering of booleans as words
+
+//! Rendering of `bool` values as words - the inverse of classification -
+//! so that programs that must write, say, `"yes"`/`"no"` or `"on"`/`"off"`
+//! into legacy formats may use the same crate in both directions.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::fmt::{
+//!     render,
+//!     BoolStyle,
+//!     Capitalization,
+//! };
+//!
+//! assert_eq!("yes", render(true, &BoolStyle::YES_NO));
+//! assert_eq!("OFF", render(false, &BoolStyle::new("ON", "OFF")));
+//! assert_eq!("Yes", render(true, &BoolStyle::YES_NO.with_capitalization(Capitalization::Capitalized)));
+//! ```
+//!
+//! Words may also be obtained from the locale registry, by
+//! [render_localized()].
+//!
+//! A value may be interpolated directly into `format!()` (and so on) in a
+//! given style, by [styled()], e.g.:
+//!
+//! ```
+//! use to_be::fmt::{
+//!     styled,
+//!     Style,
+//! };
+//!
+//! assert_eq!("verbose: yes", format!("verbose: {}", styled(true, Style::YesNo)));
+//! ```
+//!
+//! The trait [ToTruthyString] provides - as the output counterpart of
+//! [Truthy](crate::Truthy) - rendering of `bool`, `Option<bool>`, and
+//! [Truthiness] values.
+
+use super::{
+    diagnostics::{
+        classify_spanned,
+        Span,
+        UnrecognisedValue,
+    },
+    locale,
+    Truthiness,
+};
+
+use std::{
+    borrow::Cow,
+    fmt,
+};
+
+
+/// The capitalization applied to the words of a [BoolStyle].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum Capitalization {
+    /// The words are rendered as they are.
+    #[default]
+    AsIs,
+    /// The words are rendered in lowercase, e.g. `"yes"`.
+    Lowercase,
+    /// The words are rendered with the first character in uppercase and
+    /// the others in lowercase, e.g. `"Yes"`.
+    Capitalized,
+    /// The words are rendered in uppercase, e.g. `"YES"`.
+    Uppercase,
+}
+
+/// Indicates whether the given character is unchanged by conversion to
+/// lowercase.
+fn is_lowercase_(c : char) -> bool {
+    c.to_lowercase().eq([c])
+}
+
+/// Indicates whether the given character is unchanged by conversion to
+/// uppercase.
+fn is_uppercase_(c : char) -> bool {
+    c.to_uppercase().eq([c])
+}
+
+impl Capitalization {
+    /// Applies the capitalization to the given word.
+    ///
+    /// # Returns:
+    /// - `Cow::Borrowed(word)` - the word is already in the requested case
+    ///   (and so no allocation is made);
+    /// - `Cow::Owned(s)` - the word in the requested case;
+    pub fn apply<'a>(
+        &self,
+        word : &'a str,
+    ) -> Cow<'a, str> {
+        match self {
+            Self::AsIs => Cow::Borrowed(word),
+            Self::Lowercase => {
+                if word.chars().all(is_lowercase_) {
+                    Cow::Borrowed(word)
+                } else {
+                    Cow::Owned(word.to_lowercase())
+                }
+            },
+            Self::Capitalized => {
+                let mut chars = word.chars();
+
+                match chars.next() {
+                    None => Cow::Borrowed(word),
+                    Some(first) => {
+                        if is_uppercase_(first) && chars.clone().all(is_lowercase_) {
+                            Cow::Borrowed(word)
+                        } else {
+                            Cow::Owned(first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect())
+                        }
+                    },
+                }
+            },
+            Self::Uppercase => {
+                if word.chars().all(is_uppercase_) {
+                    Cow::Borrowed(word)
+                } else {
+                    Cow::Owned(word.to_uppercase())
+                }
+            },
+        }
+    }
+}
+
+
+/// A style in which `bool` values are rendered, comprising the word for
+/// each of `true` and `false`, the word for an unknown value (as used by
+/// [ToTruthyString] for `None` and [Truthiness::Unknown]), and the
+/// capitalization applied to them.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct BoolStyle<'a> {
+    /// The word with which `true` is rendered.
+    pub truey :          &'a str,
+    /// The word with which `false` is rendered.
+    pub falsey :         &'a str,
+    /// The word with which an unknown value is rendered.
+    pub unknown :        &'a str,
+    /// The capitalization applied to the words.
+    pub capitalization : Capitalization,
+}
+
+impl BoolStyle<'static> {
+    /// `"true"` / `"false"`.
+    pub const TRUE_FALSE : Self = Self::new("true", "false");
+    /// `"yes"` / `"no"`.
+    pub const YES_NO : Self = Self::new("yes", "no");
+    /// `"on"` / `"off"`.
+    pub const ON_OFF : Self = Self::new("on", "off");
+    /// `"enabled"` / `"disabled"`.
+    pub const ENABLED_DISABLED : Self = Self::new("enabled", "disabled");
+    /// `"1"` / `"0"`.
+    pub const ONE_ZERO : Self = Self::new("1", "0");
+    /// `"y"` / `"n"`.
+    pub const Y_N : Self = Self::new("y", "n");
+    /// `"t"` / `"f"`.
+    pub const T_F : Self = Self::new("t", "f");
+
+    /// All the predefined styles.
+    pub const ALL : &'static [Self] = &[
+        Self::TRUE_FALSE,
+        Self::YES_NO,
+        Self::ON_OFF,
+        Self::ENABLED_DISABLED,
+        Self::ONE_ZERO,
+        Self::Y_N,
+        Self::T_F,
+    ];
+}
+
+impl<'a> BoolStyle<'a> {
+    /// Creates a style from the given words, in which an unknown value is
+    /// rendered as the empty string, and the words are rendered as they
+    /// are.
+    pub const fn new(
+        truey : &'a str,
+        falsey : &'a str,
+    ) -> Self {
+        Self {
+            truey,
+            falsey,
+            unknown : "",
+            capitalization : Capitalization::AsIs,
+        }
+    }
+
+    /// Obtains a copy of the style in which an unknown value is rendered
+    /// with the given word.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::fmt::{
+    ///     BoolStyle,
+    ///     ToTruthyString as _,
+    /// };
+    ///
+    /// let style = BoolStyle::YES_NO.with_unknown("n/a");
+    ///
+    /// assert_eq!("n/a", None::<bool>.to_truthy_string(&style));
+    /// ```
+    pub const fn with_unknown(
+        self,
+        unknown : &'a str,
+    ) -> Self {
+        Self {
+            unknown,
+            ..self
+        }
+    }
+
+    /// Obtains a copy of the style in which the words are rendered with
+    /// the given capitalization.
+    pub const fn with_capitalization(
+        self,
+        capitalization : Capitalization,
+    ) -> Self {
+        Self {
+            capitalization,
+            ..self
+        }
+    }
+
+    /// Obtains the word with which the given value is rendered.
+    ///
+    /// # Note:
+    /// No allocation is made unless the word must be changed to the
+    /// style's capitalization.
+    pub fn render(
+        &self,
+        b : bool,
+    ) -> Cow<'a, str> {
+        self.capitalization.apply(if b { self.truey } else { self.falsey })
+    }
+
+    /// Obtains the word with which an unknown value is rendered.
+    pub fn render_unknown(&self) -> Cow<'a, str> {
+        self.capitalization.apply(self.unknown)
+    }
+}
+
+impl Default for BoolStyle<'static> {
+    /// [BoolStyle::TRUE_FALSE].
+    fn default() -> Self {
+        Self::TRUE_FALSE
+    }
+}
+
+/// The predefined styles, each corresponding to a [BoolStyle] constant,
+/// of which the default is [Style::TrueFalse].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum Style {
+    /// [BoolStyle::TRUE_FALSE].
+    #[default]
+    TrueFalse,
+    /// [BoolStyle::YES_NO].
+    YesNo,
+    /// [BoolStyle::ON_OFF].
+    OnOff,
+    /// [BoolStyle::ENABLED_DISABLED].
+    EnabledDisabled,
+    /// [BoolStyle::ONE_ZERO].
+    OneZero,
+    /// [BoolStyle::Y_N].
+    YN,
+    /// [BoolStyle::T_F].
+    TF,
+}
+
+impl Style {
+    /// All styles.
+    pub const ALL : &'static [Self] = &[
+        Self::TrueFalse,
+        Self::YesNo,
+        Self::OnOff,
+        Self::EnabledDisabled,
+        Self::OneZero,
+        Self::YN,
+        Self::TF,
+    ];
+
+    /// Obtains the style of the given name, which is matched
+    /// case-insensitively, and in which `'_'` or `'/'` is accepted in
+    /// place of `'-'`.
+    ///
+    /// The names are those returned by [Style::name()].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::fmt::Style;
+    ///
+    /// assert_eq!(Some(Style::YesNo), Style::from_name("yes-no"));
+    /// assert_eq!(Some(Style::OnOff), Style::from_name("ON/OFF"));
+    /// assert_eq!(None, Style::from_name("maybe"));
+    /// ```
+    pub fn from_name(name : &str) -> Option<Self> {
+        let name = name.to_ascii_lowercase().replace(['_', '/'], "-");
+
+        Self::ALL.iter().copied().find(|style| style.name() == name)
+    }
+
+    /// The name of the style.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::TrueFalse => "true-false",
+            Self::YesNo => "yes-no",
+            Self::OnOff => "on-off",
+            Self::EnabledDisabled => "enabled-disabled",
+            Self::OneZero => "1-0",
+            Self::YN => "y-n",
+            Self::TF => "t-f",
+        }
+    }
+
+    /// Obtains the corresponding [BoolStyle].
+    pub const fn bool_style(&self) -> BoolStyle<'static> {
+        match self {
+            Self::TrueFalse => BoolStyle::TRUE_FALSE,
+            Self::YesNo => BoolStyle::YES_NO,
+            Self::OnOff => BoolStyle::ON_OFF,
+            Self::EnabledDisabled => BoolStyle::ENABLED_DISABLED,
+            Self::OneZero => BoolStyle::ONE_ZERO,
+            Self::YN => BoolStyle::Y_N,
+            Self::TF => BoolStyle::T_F,
+        }
+    }
+}
+
+impl From<Style> for BoolStyle<'_> {
+    fn from(style : Style) -> Self {
+        style.bool_style()
+    }
+}
+
+
+/// A `bool` value and the style in which it is displayed.
+///
+/// # Note:
+/// The word is displayed with [fmt::Formatter::pad()], so width, fill, and
+/// alignment are respected, e.g. `format!("{:>5}", styled(true, Style::OnOff))`
+/// is `"   on"`.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct StyledBool<'a>(pub bool, pub BoolStyle<'a>);
+
+impl fmt::Display for StyledBool<'_> {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.pad(&self.1.render(self.0))
+    }
+}
+
+/// Obtains a wrapper that displays the given value in the given style,
+/// which may be a [Style] or a [BoolStyle].
+pub fn styled<'a, S : Into<BoolStyle<'a>>>(
+    b : bool,
+    style : S,
+) -> StyledBool<'a> {
+    StyledBool(b, style.into())
+}
+
+/// Obtains the word with which the given value is rendered in the given
+/// style.
+pub fn render<'a>(
+    b : bool,
+    style : &BoolStyle<'a>,
+) -> Cow<'a, str> {
+    style.render(b)
+}
+
+/// Obtains the word with which the given value is rendered in the given
+/// locale, as the canonical "truey" or "falsey" term of that locale (see
+/// [bool_style_for_locale()]).
+///
+/// If no terms are registered for the given locale, those of its primary
+/// language subtag (e.g. `"fr"` for `"fr-CA"`) are used and, failing that,
+/// those of English (i.e. `"yes"` / `"no"`).
+///
+/// # Example:
+///
+/// ```
+/// use to_be::fmt::render_localized;
+///
+/// assert_eq!("yes", render_localized(true, "en-GB"));
+/// assert_eq!("no", render_localized(false, "xx"));
+/// ```
+///
+/// [bool_style_for_locale()]: crate::locale::bool_style_for_locale()
+pub fn render_localized(
+    b : bool,
+    tag : &str,
+) -> Cow<'static, str> {
+    let primary_tag = tag.split(['-', '_']).next().unwrap_or(tag);
+
+    locale::bool_style_for_locale(tag)
+        .or_else(|| locale::bool_style_for_locale(primary_tag))
+        .unwrap_or(BoolStyle::YES_NO)
+        .render(b)
+}
+
+
+/// Classifies the given string, as by
+/// [string_is_truthy()](crate::string_is_truthy()), and obtains the word
+/// with which the result is rendered in the given style, so as to
+/// normalise the string to a canonical form.
+///
+/// # Returns:
+/// - `Err(e)` - string is not recognised, where `e` describes any near
+///   misses and the span of the (trimmed) value within the string;
+/// - `Ok(word)` - the word with which the string's classification is
+///   rendered;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::fmt::{
+///     normalize_bool_string,
+///     BoolStyle,
+/// };
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!("true", normalize_bool_string(" YeS ", &BoolStyle::TRUE_FALSE).unwrap());
+/// assert_eq!("off", normalize_bool_string("0", &BoolStyle::ON_OFF).unwrap());
+/// assert_eq!(
+///     "'treu' is not a recognised boolean (did you mean 'true'?)",
+///     normalize_bool_string("treu", &BoolStyle::TRUE_FALSE).unwrap_err().to_string()
+/// );
+/// # }
+/// ```
+pub fn normalize_bool_string<'a>(
+    s : &str,
+    style : &BoolStyle<'a>,
+) -> Result<Cow<'a, str>, UnrecognisedValue> {
+    classify_spanned(s, Span::new(0, s.len())).map(|b| style.render(b))
+}
+
+
+/// Trait that allows a value to be rendered as a word in a given style,
+/// as the output counterpart of [Truthy](crate::Truthy).
+pub trait ToTruthyString {
+    /// Obtains the word with which the instance is rendered in the given
+    /// style.
+    fn to_truthy_string<'a>(
+        &self,
+        style : &BoolStyle<'a>,
+    ) -> Cow<'a, str>;
+}
+
+impl ToTruthyString for bool {
+    fn to_truthy_string<'a>(
+        &self,
+        style : &BoolStyle<'a>,
+    ) -> Cow<'a, str> {
+        style.render(*self)
+    }
+}
+
+impl ToTruthyString for Option<bool> {
+    /// `None` is rendered with the style's unknown word.
+    fn to_truthy_string<'a>(
+        &self,
+        style : &BoolStyle<'a>,
+    ) -> Cow<'a, str> {
+        match self {
+            Some(b) => b.to_truthy_string(style),
+            None => style.render_unknown(),
+        }
+    }
+}
+
+impl ToTruthyString for Truthiness {
+    /// [Truthiness::Unknown] is rendered with the style's unknown word.
+    fn to_truthy_string<'a>(
+        &self,
+        style : &BoolStyle<'a>,
+    ) -> Cow<'a, str> {
+        match self {
+            Self::False => style.render(false),
+            Self::Unknown => style.render_unknown(),
+            Self::True => style.render(true),
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_render() {
+        assert_eq!("true", render(true, &BoolStyle::default()));
+        assert_eq!("false", render(false, &BoolStyle::default()));
+        assert_eq!("on", render(true, &BoolStyle::ON_OFF));
+        assert_eq!("0", render(false, &BoolStyle::ONE_ZERO));
+
+        let style = BoolStyle::new("ja", "nein");
+
+        assert_eq!("ja", style.render(true));
+        assert_eq!("nein", style.render(false));
+    }
+
+    #[test]
+    fn TEST_Capitalization_apply() {
+        fn is_borrowed_(s : Cow<str>) -> bool {
+            matches!(s, Cow::Borrowed(_))
+        }
+
+        for (word, lowercase, capitalized, uppercase) in [
+            ("yes", "yes", "Yes", "YES"),
+            ("Yes", "yes", "Yes", "YES"),
+            ("YES", "yes", "Yes", "YES"),
+            ("yEs", "yes", "Yes", "YES"),
+            ("1", "1", "1", "1"),
+            ("", "", "", ""),
+            ("ja", "ja", "Ja", "JA"),
+            ("да", "да", "Да", "ДА"),
+            ("sí", "sí", "Sí", "SÍ"),
+        ] {
+            assert_eq!(word, Capitalization::AsIs.apply(word));
+            assert_eq!(lowercase, Capitalization::Lowercase.apply(word));
+            assert_eq!(capitalized, Capitalization::Capitalized.apply(word));
+            assert_eq!(uppercase, Capitalization::Uppercase.apply(word));
+
+            assert!(is_borrowed_(Capitalization::AsIs.apply(word)));
+            assert_eq!(word == lowercase, is_borrowed_(Capitalization::Lowercase.apply(word)));
+            assert_eq!(word == capitalized, is_borrowed_(Capitalization::Capitalized.apply(word)));
+            assert_eq!(word == uppercase, is_borrowed_(Capitalization::Uppercase.apply(word)));
+        }
+    }
+
+    #[test]
+    fn TEST_BoolStyle_with_capitalization() {
+        let style = BoolStyle::ON_OFF.with_unknown("unknown").with_capitalization(Capitalization::Uppercase);
+
+        assert_eq!("ON", style.render(true));
+        assert_eq!("OFF", false.to_truthy_string(&style));
+        assert_eq!("UNKNOWN", Truthiness::Unknown.to_truthy_string(&style));
+        assert_eq!("[ OFF]", format!("[{:>4}]", styled(false, style)));
+
+        let style = BoolStyle::new("YES", "NO").with_capitalization(Capitalization::Capitalized);
+
+        assert_eq!("Yes", render(true, &style));
+        assert_eq!("No", render(false, &style));
+    }
+
+    #[test]
+    fn TEST_StyledBool() {
+        assert_eq!("yes", styled(true, Style::YesNo).to_string());
+        assert_eq!("no", styled(false, Style::YesNo).to_string());
+        assert_eq!("disabled", format!("{}", styled(false, Style::EnabledDisabled)));
+        assert_eq!("[   on]", format!("[{:>5}]", styled(true, Style::OnOff)));
+        assert_eq!("[0    ]", format!("[{:<5}]", StyledBool(false, BoolStyle::ONE_ZERO)));
+        assert_eq!("ja", styled(true, BoolStyle::new("ja", "nein")).to_string());
+    }
+
+    #[test]
+    fn TEST_Style_bool_style() {
+        let bool_styles : Vec<BoolStyle> = Style::ALL.iter().copied().map(BoolStyle::from).collect();
+
+        assert_eq!(BoolStyle::ALL, bool_styles);
+        assert_eq!(BoolStyle::default(), Style::default().bool_style());
+    }
+
+    #[test]
+    fn TEST_Style_from_name() {
+        for &style in Style::ALL {
+            assert_eq!(Some(style), Style::from_name(style.name()));
+            assert_eq!(Some(style), Style::from_name(&style.name().to_ascii_uppercase()));
+        }
+
+        assert_eq!(Some(Style::EnabledDisabled), Style::from_name("Enabled_Disabled"));
+        assert_eq!(Some(Style::OneZero), Style::from_name("1/0"));
+        assert_eq!(None, Style::from_name("yesno"));
+        assert_eq!(None, Style::from_name(""));
+    }
+
+    #[test]
+    fn TEST_ToTruthyString() {
+        let style = BoolStyle::ON_OFF.with_unknown("?");
+
+        assert_eq!("", BoolStyle::ON_OFF.unknown);
+        assert_eq!("on", true.to_truthy_string(&style));
+        assert_eq!("off", false.to_truthy_string(&style));
+        assert_eq!("on", Some(true).to_truthy_string(&style));
+        assert_eq!("off", Some(false).to_truthy_string(&style));
+        assert_eq!("?", None.to_truthy_string(&style));
+        assert_eq!("on", Truthiness::True.to_truthy_string(&style));
+        assert_eq!("off", Truthiness::False.to_truthy_string(&style));
+        assert_eq!("?", Truthiness::Unknown.to_truthy_string(&style));
+        assert_eq!("", Truthiness::Unknown.to_truthy_string(&BoolStyle::default()));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_normalize_bool_string() {
+        assert_eq!("true", normalize_bool_string(" YeS ", &BoolStyle::TRUE_FALSE).unwrap());
+        assert_eq!("false", normalize_bool_string("off", &BoolStyle::TRUE_FALSE).unwrap());
+        assert_eq!("1", normalize_bool_string("\ttrue\n", &BoolStyle::ONE_ZERO).unwrap());
+        assert_eq!(
+            "NO",
+            normalize_bool_string("0", &BoolStyle::YES_NO.with_capitalization(Capitalization::Uppercase)).unwrap()
+        );
+
+        let e = normalize_bool_string("  ys ", &BoolStyle::TRUE_FALSE).unwrap_err();
+
+        assert_eq!("ys", e.value);
+        assert_eq!(Span::new(2, 2), e.span);
+        assert_eq!(vec!["yes"], e.suggestions);
+
+        assert!(normalize_bool_string("", &BoolStyle::TRUE_FALSE).is_err());
+    }
+
+    #[test]
+    fn TEST_render_localized() {
+        assert_eq!("yes", render_localized(true, "en"));
+        assert_eq!("no", render_localized(false, "EN_us"));
+        assert_eq!("yes", render_localized(true, "xx-unknown"));
+        assert_eq!("no", render_localized(false, ""));
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn TEST_render_localized_fr() {
+        assert_eq!("oui", render_localized(true, "fr"));
+        assert_eq!("non", render_localized(false, "fr-CA"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_render_ROUND_TRIP() {
+        // the styles whose words are all in the default vocabulary
+
+        for style in [BoolStyle::TRUE_FALSE, BoolStyle::YES_NO, BoolStyle::ON_OFF, BoolStyle::ONE_ZERO] {
+            for b in [false, true] {
+                assert_eq!(Some(b), crate::string_is_truthy(&render(b, &style)), "{style:?}");
+            }
+        }
+    }
+}
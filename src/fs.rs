@@ -0,0 +1,237 @@
+// fs.rs - filesystem path truthiness
+
+//! Truthiness of filesystem paths, according to the "flag file"
+//! convention, whereby the presence of an existing, non-empty file (such
+//! as `/etc/my-service/maintenance`) enables a behaviour, and according to
+//! the (also common) convention whereby the first line of a file holds a
+//! value such as `"yes"` or `"off"`.
+//!
+//! # Example:
+//!
+//! ```no_run
+//! use to_be::fs::{
+//!     path_first_line_is_truthy,
+//!     path_is_truey,
+//! };
+//!
+//! if path_is_truey("/etc/my-service/maintenance") {
+//!     // ...
+//! }
+//!
+//! match path_first_line_is_truthy("/etc/my-service/enabled") {
+//!     Some(true) => {
+//!         // ...
+//!     },
+//!     Some(false) => {
+//!         // ...
+//!     },
+//!     None => {
+//!         // ...
+//!     },
+//! };
+//! ```
+
+use super::{
+    string_is_truthy,
+    string_is_truthy_with,
+    CompiledTerms,
+    Terms,
+};
+
+use std::{
+    fs,
+    io::{
+        self,
+        BufRead as _,
+        BufReader,
+        Read as _,
+    },
+    path::Path,
+};
+
+
+/// The maximum length, in bytes, of the first line of a file - excluding
+/// any line terminator - beyond which it is not classified, so that a large
+/// file without line terminators is not read in full.
+const MAX_FIRST_LINE_LEN : usize = CompiledTerms::DEFAULT_MAX_LEN;
+
+/// Indicates whether the given path is "truthy", by the flag file
+/// convention, and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - path exists but does not denote a file (e.g. it is a
+///   directory), or its metadata cannot be obtained (e.g. for lack of
+///   permission);
+/// - `Some(false)` - path does not exist, or denotes an empty file;
+/// - `Some(true)` - path denotes a non-empty file;
+///
+/// # Note:
+/// Symbolic links are followed, so a link to an existing, non-empty file
+/// is "truey" and a dangling link is "falsey".
+pub fn path_is_truthy<P : AsRef<Path>>(path : P) -> Option<bool> {
+    match fs::metadata(path) {
+        Ok(metadata) => metadata.is_file().then_some(0 != metadata.len()),
+        Err(e) if io::ErrorKind::NotFound == e.kind() => Some(false),
+        Err(_) => None,
+    }
+}
+
+/// Indicates that the given path is deemed as "falsey", by the flag file
+/// convention, i.e. that it does not exist or denotes an empty file.
+pub fn path_is_falsey<P : AsRef<Path>>(path : P) -> bool {
+    Some(false) == path_is_truthy(path)
+}
+
+/// Indicates that the given path is deemed as "truey", by the flag file
+/// convention, i.e. that it denotes a non-empty file.
+pub fn path_is_truey<P : AsRef<Path>>(path : P) -> bool {
+    Some(true) == path_is_truthy(path)
+}
+
+/// Obtains the first line, including any line terminator, of the file at
+/// the given path, failing if it is longer than [MAX_FIRST_LINE_LEN].
+fn first_line_(path : &Path) -> io::Result<String> {
+    let mut line = String::new();
+
+    // allows for a "\r\n" terminator of a line of the maximum length
+
+    BufReader::new(fs::File::open(path)?.take(MAX_FIRST_LINE_LEN as u64 + 2)).read_line(&mut line)?;
+
+    if line.trim_end_matches(['\r', '\n']).len() > MAX_FIRST_LINE_LEN {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "first line is too long"));
+    }
+
+    Ok(line)
+}
+
+/// Indicates whether the first line of the file at the given path, when
+/// trimmed, is "truthy" according to the default vocabulary and, if so,
+/// whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - file cannot be read (including because it does not exist),
+///   or its first line is longer than 256 bytes (excluding any line
+///   terminator) or is not classified as "truthy";
+/// - `Some(false)` - first line (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - first line (is classified as "truthy" and) is deemed
+///   "truey";
+///
+/// # Note:
+/// Only the first line is read, so the remainder of the file may hold,
+/// say, an explanatory comment.
+pub fn path_first_line_is_truthy<P : AsRef<Path>>(path : P) -> Option<bool> {
+    first_line_(path.as_ref()).ok().and_then(|line| string_is_truthy(&line))
+}
+
+/// Indicates whether the first line of the file at the given path, when
+/// trimmed, is "truthy" according to the given terms and, if so, whether
+/// it is "truey" or "falsey".
+///
+/// # Returns:
+/// As [path_first_line_is_truthy()];
+pub fn path_first_line_is_truthy_with<P : AsRef<Path>>(
+    path : P,
+    terms : Terms,
+) -> Option<bool> {
+    first_line_(path.as_ref()).ok().and_then(|line| string_is_truthy_with(&line, terms))
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::path::PathBuf;
+
+
+    /// Obtains a path, unique to the given test, in the temporary
+    /// directory.
+    fn temp_path_(name : &str) -> PathBuf {
+        std::env::temp_dir().join(format!("to-be-fs-{}-{name}", std::process::id()))
+    }
+
+    #[test]
+    fn TEST_path_is_truthy() {
+        let path = temp_path_("flag");
+
+        assert_eq!(Some(false), path_is_truthy(&path));
+        assert!(path_is_falsey(&path));
+
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(Some(false), path_is_truthy(&path));
+
+        fs::write(&path, "\n").unwrap();
+
+        assert_eq!(Some(true), path_is_truthy(&path));
+        assert!(path_is_truey(&path));
+
+        assert_eq!(None, path_is_truthy(std::env::temp_dir()));
+        assert!(!path_is_falsey(std::env::temp_dir()));
+        assert!(!path_is_truey(std::env::temp_dir()));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_path_first_line_is_truthy() {
+        let path = temp_path_("first-line");
+
+        assert_eq!(None, path_first_line_is_truthy(&path));
+
+        fs::write(&path, "").unwrap();
+
+        assert_eq!(None, path_first_line_is_truthy(&path));
+
+        fs::write(&path, " Yes \r\n# enables the frobnicator\n").unwrap();
+
+        assert_eq!(Some(true), path_first_line_is_truthy(&path));
+
+        fs::write(&path, "off").unwrap();
+
+        assert_eq!(Some(false), path_first_line_is_truthy(&path));
+
+        fs::write(&path, "\nyes\n").unwrap();
+
+        assert_eq!(None, path_first_line_is_truthy(&path));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn TEST_path_first_line_is_truthy_with() {
+        let path = temp_path_("first-line-with");
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["non"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["oui"],
+        };
+
+        fs::write(&path, "OUI\nnon\n").unwrap();
+
+        assert_eq!(Some(true), path_first_line_is_truthy_with(&path, terms.clone()));
+
+        fs::write(&path, "yes\n").unwrap();
+
+        assert_eq!(None, path_first_line_is_truthy_with(&path, terms.clone()));
+
+        fs::write(&path, format!("{:<256}\r\n", "oui")).unwrap();
+
+        assert_eq!(Some(true), path_first_line_is_truthy_with(&path, terms.clone()));
+
+        fs::write(&path, format!("{:<257}\n", "oui")).unwrap();
+
+        assert_eq!(None, path_first_line_is_truthy_with(&path, terms.clone()));
+
+        fs::write(&path, "x".repeat(1 << 20)).unwrap();
+
+        assert_eq!(None, path_first_line_is_truthy_with(&path, terms));
+
+        let _ = fs::remove_file(&path);
+    }
+}
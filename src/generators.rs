@@ -0,0 +1,233 @@
+// generators.rs - quickcheck generators of classified strings
+
+//! Generators, for use with **quickcheck**, of strings that are known to
+//! be "truey", known to be "falsey", or guaranteed to be unrecognised by
+//! the default vocabulary (i.e. by [string_is_truthy()]).
+//!
+//! Each is available as a function of a [Gen] and as a wrapper type that
+//! implements [Arbitrary], so that properties may be expressed directly in
+//! terms of the kind of string, e.g.:
+//!
+//! ```
+//! use to_be::generators::TrueyString;
+//!
+//! fn prop(s : TrueyString) -> bool {
+//!     to_be::string_is_truey(&s.0)
+//! }
+//!
+//! quickcheck::quickcheck(prop as fn(TrueyString) -> bool);
+//! ```
+//!
+//! Generated "truey" and "falsey" strings are drawn from the default
+//! vocabulary, with lowercase terms in arbitrarily mixed (ASCII) case, and
+//! are arbitrarily padded with whitespace.
+//!
+//! [string_is_truthy()]: crate::string_is_truthy()
+
+use super::{
+    defaults,
+    string_is_truthy,
+};
+
+use quickcheck::{
+    Arbitrary,
+    Gen,
+};
+
+
+/// The number of attempts made to generate a string of the required
+/// classification before resorting to a fallback.
+const MAX_ATTEMPTS : usize = 16;
+
+/// Obtains arbitrary whitespace, which is empty 1 time in 2.
+fn whitespace_(g : &mut Gen) -> &'static str {
+    g.choose(&["", "", "", "", "", " ", "  ", "\t", "\n", " \r\n"]).copied().unwrap_or_default()
+}
+
+/// Obtains a term of the given precise and lowercase term strings, in
+/// arbitrary case (if lowercase) and arbitrarily padded with whitespace.
+fn term_(
+    g : &mut Gen,
+    precise_strings : &[&str],
+    lowercase_strings : &[&str],
+) -> String {
+    let use_precise = lowercase_strings.is_empty() || (!precise_strings.is_empty() && bool::arbitrary(g));
+
+    let term = if use_precise {
+        g.choose(precise_strings).copied().unwrap_or_default().to_owned()
+    } else {
+        g.choose(lowercase_strings)
+            .copied()
+            .unwrap_or_default()
+            .chars()
+            .map(|c| if bool::arbitrary(g) { c.to_ascii_uppercase() } else { c })
+            .collect()
+    };
+
+    format!("{}{term}{}", whitespace_(g), whitespace_(g))
+}
+
+/// Obtains a string, formed as by `f`, that is classified as `expected`,
+/// or, failing that, the first (unpadded) term of the given precise and
+/// lowercase term strings.
+fn classified_term_(
+    g : &mut Gen,
+    precise_strings : &[&str],
+    lowercase_strings : &[&str],
+    expected : bool,
+) -> String {
+    for _ in 0..MAX_ATTEMPTS {
+        let s = term_(g, precise_strings, lowercase_strings);
+
+        if Some(expected) == string_is_truthy(&s) {
+            return s;
+        }
+    }
+
+    precise_strings
+        .iter()
+        .chain(lowercase_strings.iter())
+        .find(|s| Some(expected) == string_is_truthy(s))
+        .map(|&s| s.to_owned())
+        .expect("the default vocabulary has no terms of the required classification")
+}
+
+/// Obtains a string that is deemed "truey" by the default vocabulary.
+pub fn truey_string(g : &mut Gen) -> String {
+    classified_term_(g, defaults::TRUEY_PRECISE_STRINGS, defaults::TRUEY_LOWERCASE_STRINGS, true)
+}
+
+/// Obtains a string that is deemed "falsey" by the default vocabulary.
+pub fn falsey_string(g : &mut Gen) -> String {
+    classified_term_(g, defaults::FALSEY_PRECISE_STRINGS, defaults::FALSEY_LOWERCASE_STRINGS, false)
+}
+
+/// Obtains a string that is not classified as "truthy" by the default
+/// vocabulary.
+pub fn unrecognised_string(g : &mut Gen) -> String {
+    for _ in 0..MAX_ATTEMPTS {
+        let s = String::arbitrary(g);
+
+        if string_is_truthy(&s).is_none() {
+            return s;
+        }
+    }
+
+    // no term contains a control character between other characters
+
+    "\u{1}\u{7f}\u{1}".into()
+}
+
+
+/// A string that is deemed "truey" by the default vocabulary.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct TrueyString(pub String);
+
+/// A string that is deemed "falsey" by the default vocabulary.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct FalseyString(pub String);
+
+/// A string that is not classified as "truthy" by the default vocabulary.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct UnrecognisedString(pub String);
+
+impl Arbitrary for TrueyString {
+    fn arbitrary(g : &mut Gen) -> Self {
+        Self(truey_string(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // shrinks to the trimmed form, which is also "truey"
+
+        let t = self.0.trim();
+
+        if t.len() == self.0.len() {
+            quickcheck::empty_shrinker()
+        } else {
+            quickcheck::single_shrinker(Self(t.to_owned()))
+        }
+    }
+}
+
+impl Arbitrary for FalseyString {
+    fn arbitrary(g : &mut Gen) -> Self {
+        Self(falsey_string(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        // shrinks to the trimmed form, which is also "falsey"
+
+        let t = self.0.trim();
+
+        if t.len() == self.0.len() {
+            quickcheck::empty_shrinker()
+        } else {
+            quickcheck::single_shrinker(Self(t.to_owned()))
+        }
+    }
+}
+
+impl Arbitrary for UnrecognisedString {
+    fn arbitrary(g : &mut Gen) -> Self {
+        Self(unrecognised_string(g))
+    }
+
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        Box::new(self.0.shrink().filter(|s| string_is_truthy(s).is_none()).map(Self))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use quickcheck::quickcheck;
+
+
+    #[test]
+    fn TEST_TrueyString() {
+        fn prop(s : TrueyString) -> bool {
+            Some(true) == string_is_truthy(&s.0) && s.shrink().all(|s| Some(true) == string_is_truthy(&s.0))
+        }
+
+        quickcheck(prop as fn(TrueyString) -> bool);
+    }
+
+    #[test]
+    fn TEST_FalseyString() {
+        fn prop(s : FalseyString) -> bool {
+            Some(false) == string_is_truthy(&s.0) && s.shrink().all(|s| Some(false) == string_is_truthy(&s.0))
+        }
+
+        quickcheck(prop as fn(FalseyString) -> bool);
+    }
+
+    #[test]
+    fn TEST_UnrecognisedString() {
+        fn prop(s : UnrecognisedString) -> bool {
+            string_is_truthy(&s.0).is_none()
+        }
+
+        quickcheck(prop as fn(UnrecognisedString) -> bool);
+    }
+
+    #[test]
+    fn TEST_unrecognised_string_FALLBACK() {
+        assert_eq!(None, string_is_truthy("\u{1}\u{7f}\u{1}"));
+    }
+}
@@ -0,0 +1,333 @@
+// hooks.rs - process-wide callbacks on classification
+
+//! A process-wide callback that is invoked whenever a classification does
+//! not recognise its input, so that, say, a service can log, count, or
+//! forward unexpected values - to inform the tuning of its vocabulary -
+//! without wrapping every call site.
+//!
+//! The callback is process-wide because the free functions, such as
+//! [string_is_truthy()], have no state of their own through which one
+//! might be given. Compiled terms may, though, be given their own callback
+//! (see [CompiledTerms::with_on_unrecognised()]), which is then invoked
+//! instead, so that, say, a library can observe its own classifications
+//! without disturbing (or being disturbed by) the program's.
+//!
+//! The callback is invoked by [string_is_truthy()],
+//! [string_is_truthy_with()], [first_determinate_with()] (and so
+//! [first_determinate()]) - only if no candidate is recognised, with the
+//! last candidate - [CompiledTerms::string_is_truthy()] (and so
+//! its `string_is_falsey()` and `string_is_truey()`), and
+//! [BatchClassifier::classify()] (and so its `classify_into()`), with the
+//! trimmed input, truncated to at most [MAX_INPUT_CHARS] characters, and a
+//! context label. The label is that given to the innermost enclosing
+//! [with_context()] on the current thread, if any, or otherwise the name of
+//! the evaluator.
+//!
+//! The callback is invoked on the classifying thread, so it should be
+//! cheap (and must not itself set or clear the callback).
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::hooks;
+//!
+//! use std::sync::Mutex;
+//!
+//! static UNRECOGNISED : Mutex<Vec<String>> = Mutex::new(Vec::new());
+//!
+//! hooks::set_on_unrecognised(|u| UNRECOGNISED.lock().unwrap().push(format!("{}: {}", u.context, u.input)));
+//!
+//! let key = "verbose";
+//!
+//! hooks::with_context(format!("config:{key}"), || to_be::string_is_truthy(" treu "));
+//! to_be::string_is_truthy("maybe");
+//!
+//! hooks::clear_on_unrecognised();
+//!
+//! assert_eq!(
+//!     vec!["config:verbose: treu", "string_is_truthy: maybe"],
+//!     *UNRECOGNISED.lock().unwrap()
+//! );
+//! ```
+//!
+//! [BatchClassifier::classify()]: crate::batch::BatchClassifier::classify()
+//! [CompiledTerms::string_is_truthy()]: crate::CompiledTerms::string_is_truthy()
+//! [CompiledTerms::with_on_unrecognised()]: crate::CompiledTerms::with_on_unrecognised()
+//! [first_determinate()]: crate::first_determinate()
+//! [first_determinate_with()]: crate::first_determinate_with()
+//! [string_is_truthy()]: crate::string_is_truthy()
+//! [string_is_truthy_with()]: crate::string_is_truthy_with()
+
+use std::{
+    cell::RefCell,
+    fmt,
+    rc::Rc,
+    sync::{
+        atomic::{
+            AtomicBool,
+            Ordering,
+        },
+        Arc,
+        RwLock,
+    },
+};
+
+
+/// The maximum number of characters of the input passed to the callback,
+/// so that it does not receive arbitrarily long - and, potentially,
+/// sensitive - values in full.
+pub const MAX_INPUT_CHARS : usize = 64;
+
+/// The details of an unrecognised input, as passed to the callback.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct Unrecognised<'a> {
+    /// The trimmed input, truncated to at most [MAX_INPUT_CHARS]
+    /// characters.
+    pub input :     &'a str,
+    /// Indicates whether the input was truncated.
+    pub truncated : bool,
+    /// The context label.
+    pub context :   &'a str,
+}
+
+type Callback = Box<dyn Fn(&Unrecognised<'_>) + Send + Sync>;
+
+static IS_SET : AtomicBool = AtomicBool::new(false);
+static ON_UNRECOGNISED : RwLock<Option<Callback>> = RwLock::new(None);
+
+thread_local! {
+    static CONTEXT : RefCell<Option<Rc<str>>> = const { RefCell::new(None) };
+}
+
+/// A callback that is invoked whenever a classification by particular
+/// compiled terms does not recognise its input (see
+/// [CompiledTerms::with_on_unrecognised()](crate::CompiledTerms::with_on_unrecognised())).
+#[derive(Clone)]
+pub struct OnUnrecognised(Arc<dyn Fn(&Unrecognised<'_>) + Send + Sync>);
+
+impl OnUnrecognised {
+    /// Creates an instance from the given callback.
+    pub fn new<F>(callback : F) -> Self
+    where
+        F : Fn(&Unrecognised<'_>) + Send + Sync + 'static,
+    {
+        Self(Arc::new(callback))
+    }
+}
+
+impl fmt::Debug for OnUnrecognised {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("OnUnrecognised(..)")
+    }
+}
+
+/// Sets the callback that is invoked whenever a classification does not
+/// recognise its input, replacing any previous callback.
+pub fn set_on_unrecognised<F>(callback : F)
+where
+    F : Fn(&Unrecognised<'_>) + Send + Sync + 'static,
+{
+    let mut on_unrecognised = ON_UNRECOGNISED.write().unwrap_or_else(|e| e.into_inner());
+
+    *on_unrecognised = Some(Box::new(callback));
+
+    IS_SET.store(true, Ordering::Release);
+}
+
+/// Clears the callback, if any.
+pub fn clear_on_unrecognised() {
+    let mut on_unrecognised = ON_UNRECOGNISED.write().unwrap_or_else(|e| e.into_inner());
+
+    IS_SET.store(false, Ordering::Release);
+
+    *on_unrecognised = None;
+}
+
+/// Invokes the given function with the given context label - which may be
+/// borrowed or owned, e.g. `format!("config:{key}")` - in effect, on the
+/// current thread, for the reporting of unrecognised inputs.
+pub fn with_context<C, F, R>(
+    context : C,
+    f : F,
+) -> R
+where
+    C : AsRef<str>,
+    F : FnOnce() -> R,
+{
+    struct Restore(Option<Rc<str>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CONTEXT.with(|c| *c.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(CONTEXT.with(|c| c.borrow_mut().replace(Rc::from(context.as_ref()))));
+
+    f()
+}
+
+/// Invokes the given callback with the given unrecognised input, classified
+/// by the evaluator of the given name.
+fn invoke_(
+    callback : &dyn Fn(&Unrecognised<'_>),
+    evaluator : &'static str,
+    input : &str,
+) {
+    let input = input.trim();
+    let (input, truncated) = match input.char_indices().nth(MAX_INPUT_CHARS) {
+        Some((index, _)) => (&input[..index], true),
+        None => (input, false),
+    };
+    let context = CONTEXT.with(|c| c.borrow().clone());
+
+    callback(&Unrecognised {
+        input,
+        truncated,
+        context : context.as_deref().unwrap_or(evaluator),
+    });
+}
+
+/// Reports the given unrecognised input, classified by the evaluator of
+/// the given name, to the given callback, if any, or otherwise to the
+/// process-wide callback, if any.
+pub(crate) fn report_(
+    on_unrecognised : Option<&OnUnrecognised>,
+    evaluator : &'static str,
+    input : &str,
+) {
+    if let Some(OnUnrecognised(callback)) = on_unrecognised {
+        invoke_(callback.as_ref(), evaluator, input);
+
+        return;
+    }
+
+    if !IS_SET.load(Ordering::Acquire) {
+        return;
+    }
+
+    let on_unrecognised = ON_UNRECOGNISED.read().unwrap_or_else(|e| e.into_inner());
+
+    if let Some(callback) = on_unrecognised.as_ref() {
+        invoke_(callback.as_ref(), evaluator, input);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::sync::Mutex;
+
+
+    #[test]
+    fn TEST_on_unrecognised() {
+        static REPORTS : Mutex<Vec<(String, bool, String)>> = Mutex::new(Vec::new());
+
+        // as the callback is process-wide, only inputs specific to this
+        // test are considered
+
+        set_on_unrecognised(|u| {
+            if u.input.starts_with("hooks-test") {
+                REPORTS.lock().unwrap().push((u.input.to_owned(), u.truncated, u.context.to_owned()));
+            }
+        });
+
+        let long_input = format!("hooks-test-{}", "x".repeat(100));
+
+        crate::string_is_truthy(" hooks-test-1 ");
+        with_context(String::from("outer"), || {
+            crate::string_is_truthy_with("hooks-test-2", crate::Terms::Default);
+
+            with_context("inner", || crate::CompiledTerms::new(crate::Terms::Default).string_is_truthy("hooks-test-3"));
+
+            crate::batch::BatchClassifier::new(crate::Terms::Default).classify("hooks-test-4");
+        });
+        crate::string_is_truthy(&long_input);
+        crate::string_is_truthy("true");
+
+        // candidates that are passed over are not reported, and nor are
+        // any if one is recognised
+
+        let terms = crate::Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["off"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["on"],
+        };
+
+        assert_eq!(
+            Some(true),
+            crate::first_determinate_with(["hooks-test-skipped", "", "hooks-test-skipped-2", "on"], terms.clone())
+        );
+        assert_eq!(None, crate::first_determinate_with(["hooks-test-skipped-3", "hooks-test-6"], terms.clone()));
+        assert_eq!(None, crate::first_determinate_with(Vec::<&str>::new(), terms));
+
+        clear_on_unrecognised();
+
+        crate::string_is_truthy("hooks-test-5");
+
+        assert_eq!(
+            vec![
+                ("hooks-test-1".to_owned(), false, "string_is_truthy".to_owned()),
+                ("hooks-test-2".to_owned(), false, "outer".to_owned()),
+                ("hooks-test-3".to_owned(), false, "inner".to_owned()),
+                ("hooks-test-4".to_owned(), false, "outer".to_owned()),
+                (long_input[..MAX_INPUT_CHARS].to_owned(), true, "string_is_truthy".to_owned()),
+                ("hooks-test-6".to_owned(), false, "first_determinate_with".to_owned()),
+            ],
+            *REPORTS.lock().unwrap()
+        );
+    }
+
+    #[test]
+    fn TEST_on_unrecognised_OF_CompiledTerms() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+
+        let strings = crate::Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["off"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["on"],
+        };
+
+        let terms = crate::CompiledTerms::new(strings.clone()).with_on_unrecognised({
+            let reports = Arc::clone(&reports);
+
+            move |u : &Unrecognised<'_>| reports.lock().unwrap().push((u.input.to_owned(), u.context.to_owned()))
+        });
+
+        let key = "verbose";
+        let context = format!("config:{key}");
+
+        terms.string_is_truthy("compiled-hooks-test-1");
+        with_context(context.as_str(), || terms.string_is_truthy("compiled-hooks-test-2"));
+        terms.string_is_truthy("off");
+
+        assert_eq!(
+            vec![
+                ("compiled-hooks-test-1".to_owned(), "CompiledTerms".to_owned()),
+                ("compiled-hooks-test-2".to_owned(), "config:verbose".to_owned()),
+            ],
+            *reports.lock().unwrap()
+        );
+
+        // the callback is not compared, so instances with the same terms
+        // and settings are equal, whatever their callbacks
+
+        assert_eq!(terms.clone(), terms);
+        assert_eq!(crate::CompiledTerms::new(strings.clone()), terms);
+        assert_eq!(crate::CompiledTerms::new(strings.clone()).with_on_unrecognised(|_| {}), terms);
+        assert_ne!(crate::CompiledTerms::new(strings).with_strictness(crate::Strictness::Strict), terms);
+    }
+}
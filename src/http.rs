@@ -0,0 +1,298 @@
+// http.rs - interpretation of HTTP header values as flags
+
+//! Interpretation of HTTP header values - such as those of `DNT`,
+//! `Sec-GPC`, or custom `X-Feature-*` headers - and of cookies as flags,
+//! with the leniency appropriate to headers.
+//!
+//! (The truthiness of response statuses, as `http::StatusCode`, is
+//! provided by the `"http"` feature's implementation of
+//! [Truthy](crate::Truthy).)
+
+
+use super::string_is_truthy;
+
+
+/// Splits the given string at each of the given separator that is not
+/// within a quoted string.
+fn split_unquoted_(
+    s : &str,
+    separator : char,
+) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut start = 0;
+    let mut in_quotes = false;
+    let mut escaped = false;
+
+    for (index, c) in s.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_quotes => escaped = true,
+            '"' => in_quotes = !in_quotes,
+            _ if c == separator && !in_quotes => {
+                parts.push(&s[start..index]);
+                start = index + c.len_utf8();
+            },
+            _ => (),
+        };
+    }
+
+    parts.push(&s[start..]);
+
+    parts
+}
+
+/// Obtains the content of the given quoted string, or `None` if it is not
+/// a (well-formed) quoted string.
+fn unquote_(s : &str) -> Option<String> {
+    let inner = s.strip_prefix('"')?.strip_suffix('"')?;
+    let mut content = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => content.push(chars.next()?),
+            '"' => return None,
+            _ => content.push(c),
+        };
+    }
+
+    Some(content)
+}
+
+/// Obtains the percent-decoded form of the given string, or `None` if it
+/// contains an invalid escape or is not valid UTF-8 once decoded.
+fn percent_decode_(s : &str) -> Option<String> {
+    if !s.contains('%') {
+        return Some(s.to_owned());
+    }
+
+    let mut bytes = Vec::with_capacity(s.len());
+    let mut iter = s.bytes();
+
+    while let Some(b) = iter.next() {
+        if b'%' == b {
+            let hi = char::from(iter.next()?).to_digit(16)?;
+            let lo = char::from(iter.next()?).to_digit(16)?;
+
+            bytes.push((hi * 16 + lo) as u8);
+        } else {
+            bytes.push(b);
+        }
+    }
+
+    String::from_utf8(bytes).ok()
+}
+
+/// Interprets the given (unquoted) value, for which `1` and `0` are
+/// recognised regardless of the default vocabulary.
+fn value_flag_(value : &str) -> Option<bool> {
+    match value.trim() {
+        "0" => Some(false),
+        "1" => Some(true),
+        value => string_is_truthy(value),
+    }
+}
+
+/// Interprets the given member of a list, disregarding any parameters.
+fn member_flag_(member : &str) -> Option<bool> {
+    let item = split_unquoted_(member, ';')[0].trim();
+
+    match item {
+        "?0" => Some(false),
+        "?1" => Some(true),
+        _ if item.starts_with('?') => None,
+        _ if item.starts_with('"') => value_flag_(&unquote_(item)?),
+        _ => value_flag_(item),
+    }
+}
+
+/// Interprets the given HTTP header value as a flag.
+///
+/// The value is interpreted leniently, as follows:
+/// - a Structured Field boolean (RFC 8941) - `?1` or `?0` - is recognised;
+/// - `1` and `0` are recognised (as for, e.g., `DNT` and `Sec-GPC`)
+///   regardless of the default vocabulary;
+/// - a quoted string is recognised by its content;
+/// - a token is recognised according to the default vocabulary (see
+///   [string_is_truthy()](crate::string_is_truthy()));
+/// - any parameters (after `';'`) of an item are disregarded;
+/// - a list (as results from a repeated header) is recognised if all its
+///   members are recognised and agree;
+///
+/// # Returns:
+/// - `None` - value is not recognised, is empty, or is a list whose
+///   members disagree;
+/// - `Some(false)` - value is deemed "falsey";
+/// - `Some(true)` - value is deemed "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::http::header_flag;
+///
+/// assert_eq!(Some(true), header_flag("1"));
+/// assert_eq!(Some(true), header_flag("?1;reason=\"opt-in\""));
+/// assert_eq!(Some(false), header_flag("?0"));
+/// assert_eq!(Some(true), header_flag("1, 1"));
+/// assert_eq!(None, header_flag("1, 0"));
+/// assert_eq!(None, header_flag(""));
+/// ```
+pub fn header_flag(value : &str) -> Option<bool> {
+    let mut flag = None;
+
+    for member in split_unquoted_(value, ',') {
+        let member_flag = member_flag_(member)?;
+
+        if flag.is_some_and(|flag| flag != member_flag) {
+            return None;
+        }
+
+        flag = Some(member_flag);
+    }
+
+    flag
+}
+
+
+/// Finds the cookie of the given name in the given `Cookie` header value,
+/// and interprets its value as a flag.
+///
+/// The name is matched exactly (i.e. case-sensitively) and, if there is
+/// more than one cookie of that name, the first is used. The value may be
+/// quoted, and is percent-decoded, and is then interpreted as by
+/// [header_flag()] (save that it is not a list).
+///
+/// # Returns:
+/// - `None` - there is no such cookie, or its value is not recognised (or
+///   is not validly percent-encoded);
+/// - `Some(false)` - value is deemed "falsey";
+/// - `Some(true)` - value is deemed "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::http::cookie_flag;
+///
+/// let cookie_header = "session=abc123; consent=\"1\"; beta=%31";
+///
+/// assert_eq!(Some(true), cookie_flag(cookie_header, "consent"));
+/// assert_eq!(Some(true), cookie_flag(cookie_header, "beta"));
+/// assert_eq!(None, cookie_flag(cookie_header, "session"));
+/// assert_eq!(None, cookie_flag(cookie_header, "dark-mode"));
+/// ```
+pub fn cookie_flag(
+    cookie_header : &str,
+    name : &str,
+) -> Option<bool> {
+    let value = cookie_header.split(';').find_map(|pair| {
+        let (pair_name, value) = pair.split_once('=')?;
+
+        (pair_name.trim() == name).then_some(value.trim())
+    })?;
+
+    let value = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')).unwrap_or(value);
+
+    value_flag_(&percent_decode_(value)?)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_split_unquoted_() {
+        assert_eq!(vec!["a", " b"], split_unquoted_("a, b", ','));
+        assert_eq!(vec!["\"a,b\"", " c"], split_unquoted_("\"a,b\", c", ','));
+        assert_eq!(vec!["\"a\\\",b\""], split_unquoted_("\"a\\\",b\"", ','));
+        assert_eq!(vec![""], split_unquoted_("", ','));
+    }
+
+    #[test]
+    fn TEST_unquote_() {
+        assert_eq!(Some("yes".to_owned()), unquote_("\"yes\""));
+        assert_eq!(Some("a\"b".to_owned()), unquote_("\"a\\\"b\""));
+        assert_eq!(Some("".to_owned()), unquote_("\"\""));
+        assert_eq!(None, unquote_("\"yes"));
+        assert_eq!(None, unquote_("yes"));
+        assert_eq!(None, unquote_("\"a\"b\""));
+    }
+
+    #[test]
+    fn TEST_percent_decode_() {
+        assert_eq!(Some("yes".to_owned()), percent_decode_("yes"));
+        assert_eq!(Some("a b".to_owned()), percent_decode_("a%20b"));
+        assert_eq!(Some("sí".to_owned()), percent_decode_("s%C3%AD"));
+        assert_eq!(Some("+".to_owned()), percent_decode_("+"));
+        assert_eq!(None, percent_decode_("%2"));
+        assert_eq!(None, percent_decode_("%zz"));
+        assert_eq!(None, percent_decode_("%+1"));
+        assert_eq!(None, percent_decode_("%C3"));
+    }
+
+    #[test]
+    fn TEST_header_flag_STRUCTURED_AND_NUMERIC() {
+        assert_eq!(Some(true), header_flag("?1"));
+        assert_eq!(Some(false), header_flag("?0"));
+        assert_eq!(Some(true), header_flag(" 1 "));
+        assert_eq!(Some(false), header_flag("0"));
+        assert_eq!(Some(true), header_flag("?1;a=1;b=?0"));
+        assert_eq!(None, header_flag("?2"));
+        assert_eq!(None, header_flag("?true"));
+        assert_eq!(None, header_flag("2"));
+        assert_eq!(None, header_flag(""));
+        assert_eq!(None, header_flag(","));
+    }
+
+    #[test]
+    fn TEST_header_flag_LISTS() {
+        assert_eq!(Some(true), header_flag("1, ?1"));
+        assert_eq!(Some(false), header_flag("0,0,?0"));
+        assert_eq!(None, header_flag("1, ?0"));
+        assert_eq!(None, header_flag("1, 2"));
+        assert_eq!(None, header_flag("1,"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_header_flag_TOKENS_AND_QUOTED_STRINGS() {
+        assert_eq!(Some(true), header_flag("yes"));
+        assert_eq!(Some(false), header_flag("Off"));
+        assert_eq!(Some(true), header_flag("\"true\""));
+        assert_eq!(Some(true), header_flag("\" on \"; q=0.5"));
+        assert_eq!(None, header_flag("\"on, please\""));
+        assert_eq!(Some(false), header_flag("no, \"false\", ?0"));
+        assert_eq!(None, header_flag("\"yes"));
+        assert_eq!(None, header_flag("maybe"));
+    }
+
+    #[test]
+    fn TEST_cookie_flag() {
+        let cookie_header = "a=1;b=0; c=\"1\" ;d=%30; e=?1; f=; a=0; g=%zz";
+
+        assert_eq!(Some(true), cookie_flag(cookie_header, "a"));
+        assert_eq!(Some(false), cookie_flag(cookie_header, "b"));
+        assert_eq!(Some(true), cookie_flag(cookie_header, "c"));
+        assert_eq!(Some(false), cookie_flag(cookie_header, "d"));
+        assert_eq!(None, cookie_flag(cookie_header, "e"));
+        assert_eq!(None, cookie_flag(cookie_header, "f"));
+        assert_eq!(None, cookie_flag(cookie_header, "g"));
+        assert_eq!(None, cookie_flag(cookie_header, "A"));
+        assert_eq!(None, cookie_flag(cookie_header, "h"));
+        assert_eq!(None, cookie_flag("", "a"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_cookie_flag_TOKENS() {
+        let cookie_header = "consent=Yes; analytics=%22off%22; theme=dark; beta=%20true%20";
+
+        assert_eq!(Some(true), cookie_flag(cookie_header, "consent"));
+        assert_eq!(None, cookie_flag(cookie_header, "analytics"));
+        assert_eq!(None, cookie_flag(cookie_header, "theme"));
+        assert_eq!(Some(true), cookie_flag(cookie_header, "beta"));
+    }
+}
@@ -0,0 +1,359 @@
+// ini.rs - flags in Java .properties and INI files
+
+//! Reading of flags from Java `.properties` and INI-style files, in which
+//! deployments (particularly JVM-adjacent ones) frequently store booleans,
+//! without the need for a full configuration parser.
+//!
+//! The syntax understood is the union of the two formats:
+//! - lines whose first non-whitespace character is `'#'`, `'!'`, or `';'`
+//!   are comments;
+//! - a line of the form `[name]` begins the section `name` (and entries
+//!   that precede any section belong to no section, as do all those of a
+//!   `.properties` file);
+//! - an entry is of the form `key=value` or `key:value` (or `key` alone,
+//!   whose value is empty), with whitespace around the key and before the
+//!   value ignored;
+//! - a line ending in an odd number of backslashes is continued on the next
+//!   line, whose leading whitespace is ignored;
+//! - keys and values may contain the escapes `\t`, `\n`, `\r`, `\f`,
+//!   `\uXXXX`, and `\` followed by any other character (e.g. `\=`), which
+//!   denotes that character;
+//!
+//! and section names and keys are case-sensitive. If a key occurs more
+//! than once in a section, the last occurrence prevails.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::ini::{
+//!     ini_flag,
+//!     IniSource,
+//! };
+//!
+//! let text = "
+//! ## service settings
+//! verbose = yes
+//!
+//! [cache]
+//! enabled : off
+//! ";
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! assert!(ini_flag(IniSource::Text(text), None, "verbose", false));
+//! assert!(!ini_flag(IniSource::Text(text), Some("cache"), "enabled", true));
+//! # }
+//! assert!(ini_flag(IniSource::Text(text), Some("cache"), "missing", true));
+//! ```
+
+use super::string_is_truthy;
+
+use std::{
+    fs,
+    path::{
+        Path,
+        PathBuf,
+    },
+};
+
+
+/// The source of the content of a `.properties` or INI file.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+pub enum IniSource<'a> {
+    /// The file at the given path, whose content is read as UTF-8.
+    Path(&'a Path),
+    /// The given content.
+    Text(&'a str),
+}
+
+impl<'a> From<&'a Path> for IniSource<'a> {
+    fn from(path : &'a Path) -> Self {
+        Self::Path(path)
+    }
+}
+
+impl<'a> From<&'a PathBuf> for IniSource<'a> {
+    fn from(path : &'a PathBuf) -> Self {
+        Self::Path(path)
+    }
+}
+
+/// Indicates whether the given line ends with an odd number of
+/// backslashes, and so is continued on the next line.
+fn is_continued_(line : &str) -> bool {
+    1 == line.bytes().rev().take_while(|&b| b'\\' == b).count() % 2
+}
+
+/// Splits the given text into logical lines, joining continued lines.
+fn logical_lines_(text : &str) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current : Option<String> = None;
+
+    for line in text.lines() {
+        let line = match current {
+            Some(_) => line.trim_start(),
+            None => line,
+        };
+
+        let is_comment = current.is_none() && line.trim_start().starts_with(['#', '!', ';']);
+
+        if !is_comment && is_continued_(line) {
+            current.get_or_insert_with(String::new).push_str(&line[..line.len() - 1]);
+        } else {
+            let mut logical = current.take().unwrap_or_default();
+
+            logical.push_str(line);
+
+            lines.push(logical);
+        }
+    }
+
+    lines.extend(current);
+
+    lines
+}
+
+/// Obtains the given key or value with its escapes replaced, or `None` if
+/// it contains a malformed `\uXXXX` escape.
+fn unescape_(s : &str) -> Option<String> {
+    let mut unescaped = String::with_capacity(s.len());
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if '\\' != c {
+            unescaped.push(c);
+
+            continue;
+        }
+
+        match chars.next() {
+            Some('t') => unescaped.push('\t'),
+            Some('n') => unescaped.push('\n'),
+            Some('r') => unescaped.push('\r'),
+            Some('f') => unescaped.push('\u{0C}'),
+            Some('u') => {
+                let hex : String = chars.by_ref().take(4).collect();
+
+                if 4 != hex.len() {
+                    return None;
+                }
+
+                unescaped.push(char::from_u32(u32::from_str_radix(&hex, 16).ok()?)?);
+            },
+            Some(c) => unescaped.push(c),
+            None => (),
+        };
+    }
+
+    Some(unescaped)
+}
+
+/// Splits the given entry into its (raw) key and value, at the first
+/// unescaped `'='` or `':'`, if any.
+fn split_entry_(entry : &str) -> (&str, &str) {
+    let mut escaped = false;
+
+    for (index, c) in entry.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '=' | ':' => return (entry[..index].trim(), entry[index + 1..].trim_start()),
+            _ => (),
+        };
+    }
+
+    (entry.trim(), "")
+}
+
+/// Obtains the (unescaped) value of the given key in the given section of
+/// the given text.
+fn value_in_text_(
+    text : &str,
+    section : Option<&str>,
+    key : &str,
+) -> Option<String> {
+    let mut current_section : Option<String> = None;
+    let mut value = None;
+
+    for line in logical_lines_(text) {
+        let line = line.trim_start();
+
+        if line.is_empty() || line.starts_with(['#', '!', ';']) {
+            continue;
+        }
+
+        if let Some(name) = line.trim_end().strip_prefix('[').and_then(|line| line.strip_suffix(']')) {
+            current_section = Some(name.trim().to_owned());
+
+            continue;
+        }
+
+        if section != current_section.as_deref() {
+            continue;
+        }
+
+        let (raw_key, raw_value) = split_entry_(line);
+
+        if unescape_(raw_key).as_deref() == Some(key) {
+            value = Some(unescape_(raw_value));
+        }
+    }
+
+    value.flatten()
+}
+
+/// Obtains the (unescaped) value of the given key in the given section -
+/// or, if `None`, among the entries that precede any section - of the
+/// given `.properties` or INI source.
+///
+/// # Returns:
+/// - `None` - source cannot be read, or does not contain the key, or the
+///   value (of the last occurrence) of the key contains a malformed
+///   `\uXXXX` escape;
+/// - `Some(value)` - the value;
+pub fn ini_value<'a, S>(
+    source : S,
+    section : Option<&str>,
+    key : &str,
+) -> Option<String>
+where
+    S : Into<IniSource<'a>>,
+{
+    match source.into() {
+        IniSource::Path(path) => value_in_text_(&fs::read_to_string(path).ok()?, section, key),
+        IniSource::Text(text) => value_in_text_(text, section, key),
+    }
+}
+
+/// Obtains the value of the flag of the given key in the given section -
+/// or, if `None`, among the entries that precede any section - of the
+/// given `.properties` or INI source, classified as by
+/// [string_is_truthy()](crate::string_is_truthy()), or the given default
+/// if the source cannot be read, or does not contain the key, or its value
+/// is not recognised.
+///
+/// # Example:
+///
+/// ```no_run
+/// use to_be::ini::ini_flag;
+///
+/// use std::path::Path;
+///
+/// let metrics = ini_flag(Path::new("/opt/app/conf/app.properties"), None, "metrics.enabled", false);
+/// ```
+pub fn ini_flag<'a, S>(
+    source : S,
+    section : Option<&str>,
+    key : &str,
+    default : bool,
+) -> bool
+where
+    S : Into<IniSource<'a>>,
+{
+    ini_value(source, section, key)
+        .and_then(|value| string_is_truthy(&value))
+        .unwrap_or(default)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    const TEXT : &str = r"
+# comment = 1
+! also = 1
+; and.this = 1
+a=1
+b : 0
+c
+  d  =   1
+e\=f = 0
+g = 1
+g = 0
+long = \
+    0\
+    x1
+escaped = \u0031\t
+bad = \u00
+path = C:\\dir
+
+[one]
+a = 0
+[ two ]
+a : 1
+[one]
+b = 1
+";
+
+    fn value_(
+        section : Option<&str>,
+        key : &str,
+    ) -> Option<String> {
+        ini_value(IniSource::Text(TEXT), section, key)
+    }
+
+    #[test]
+    fn TEST_ini_value() {
+        assert_eq!(Some("1"), value_(None, "a").as_deref());
+        assert_eq!(Some("0"), value_(None, "b").as_deref());
+        assert_eq!(Some(""), value_(None, "c").as_deref());
+        assert_eq!(Some("1"), value_(None, "d").as_deref());
+        assert_eq!(Some("0"), value_(None, "e=f").as_deref());
+        assert_eq!(Some("0"), value_(None, "g").as_deref());
+        assert_eq!(Some("0x1"), value_(None, "long").as_deref());
+        assert_eq!(Some("1\t"), value_(None, "escaped").as_deref());
+        assert_eq!(None, value_(None, "bad"));
+        assert_eq!(Some(r"C:\dir"), value_(None, "path").as_deref());
+        assert_eq!(None, value_(None, "comment"));
+        assert_eq!(None, value_(None, "also"));
+        assert_eq!(None, value_(None, "and.this"));
+        assert_eq!(None, value_(None, "e"));
+
+        assert_eq!(Some("0"), value_(Some("one"), "a").as_deref());
+        assert_eq!(Some("1"), value_(Some("one"), "b").as_deref());
+        assert_eq!(Some("1"), value_(Some("two"), "a").as_deref());
+        assert_eq!(None, value_(Some("two"), "b"));
+        assert_eq!(None, value_(Some("One"), "a"));
+        assert_eq!(None, value_(Some("three"), "a"));
+    }
+
+    #[test]
+    fn TEST_logical_lines_() {
+        assert_eq!(vec!["a", "b"], logical_lines_("a\r\nb"));
+        assert_eq!(vec!["a = bc", "d"], logical_lines_("a = b\\\n   c\nd"));
+        assert_eq!(vec!["a = b\\\\", "c"], logical_lines_("a = b\\\\\nc"));
+        assert_eq!(vec!["# x\\", "y"], logical_lines_("# x\\\ny"));
+        assert_eq!(vec!["a"], logical_lines_("a\\"));
+    }
+
+    #[test]
+    fn TEST_ini_flag() {
+        assert!(ini_flag(IniSource::Text(TEXT), None, "a", false));
+        assert!(!ini_flag(IniSource::Text(TEXT), None, "b", true));
+        assert!(ini_flag(IniSource::Text(TEXT), None, "c", true));
+        assert!(!ini_flag(IniSource::Text(TEXT), None, "c", false));
+        assert!(ini_flag(IniSource::Text(TEXT), Some("two"), "a", false));
+        assert!(ini_flag(IniSource::Text(TEXT), None, "bad", true));
+        assert!(!ini_flag(IniSource::Text(TEXT), None, "missing", false));
+    }
+
+    #[test]
+    fn TEST_ini_flag_PATH() {
+        let path = std::env::temp_dir().join(format!("to-be-ini-{}.properties", std::process::id()));
+
+        assert!(ini_flag(&path, None, "enabled", true));
+
+        fs::write(&path, "enabled=0\n").unwrap();
+
+        assert!(!ini_flag(&path, None, "enabled", true));
+        assert!(!ini_flag(path.as_path(), None, "enabled", true));
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,155 @@
+// js.rs - JavaScript ToBoolean semantics
+
+
+/// A JavaScript value, for the purposes of [to_boolean()].
+///
+/// # Note:
+/// All objects - including the wrapper objects `new Boolean(false)`,
+/// `new Number(0)`, and `new String("")` - are represented by
+/// `Value::Object`, and are true.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+pub enum Value<'a> {
+    /// `undefined`.
+    Undefined,
+    /// `null`.
+    Null,
+    /// A boolean primitive.
+    Boolean(bool),
+    /// A number primitive.
+    Number(f64),
+    /// A BigInt primitive.
+    BigInt(i128),
+    /// A string primitive.
+    String(&'a str),
+    /// A symbol.
+    Symbol,
+    /// An object, including a function, an array, or a wrapper object.
+    Object,
+}
+
+impl Value<'_> {
+    /// Converts the value to `bool`, as would the JavaScript abstract
+    /// operation ToBoolean (and so `Boolean(value)` and `!!value`).
+    pub fn to_boolean(&self) -> bool {
+        to_boolean(self)
+    }
+}
+
+/// Converts the given value to `bool`, as would the JavaScript abstract
+/// operation ToBoolean (and so `Boolean(value)` and `!!value`).
+///
+/// # Returns:
+/// `false` for `undefined`, `null`, `false`, `+0`, `-0`, `NaN`, `0n`, and
+/// `""`; `true` for all other values, including `"0"`, `"false"`, `" "`,
+/// and all objects;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::js::{
+///     to_boolean,
+///     Value,
+/// };
+///
+/// assert_eq!(false, to_boolean(&Value::String("")));
+/// assert_eq!(true, to_boolean(&Value::String("false")));
+/// assert_eq!(false, to_boolean(&Value::Number(f64::NAN)));
+/// assert_eq!(true, to_boolean(&Value::Object));
+/// ```
+pub fn to_boolean(value : &Value) -> bool {
+    match *value {
+        Value::Undefined | Value::Null => false,
+        Value::Boolean(b) => b,
+        Value::Number(n) => number_to_boolean(n),
+        Value::BigInt(n) => 0 != n,
+        Value::String(s) => string_to_boolean(s),
+        Value::Symbol | Value::Object => true,
+    }
+}
+
+/// Converts the given string to `bool`, as would JavaScript ToBoolean.
+///
+/// # Note:
+/// Only the empty string is false: unlike
+/// [string_is_truthy()](crate::string_is_truthy()), `"0"`, `"false"`, and
+/// (whitespace-only) `" "` are all true.
+pub fn string_to_boolean(s : &str) -> bool {
+    !s.is_empty()
+}
+
+/// Converts the given number to `bool`, as would JavaScript ToBoolean.
+///
+/// # Returns:
+/// `false` for `+0`, `-0`, and `NaN`; `true` otherwise;
+pub fn number_to_boolean(n : f64) -> bool {
+    !(0.0 == n || n.is_nan())
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::bool_assert_comparison)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_to_boolean_FALSY_VALUES() {
+        for value in [
+            Value::Undefined,
+            Value::Null,
+            Value::Boolean(false),
+            Value::Number(0.0),
+            Value::Number(-0.0),
+            Value::Number(f64::NAN),
+            Value::BigInt(0),
+            Value::String(""),
+        ] {
+            assert_eq!(false, to_boolean(&value), "{value:?}");
+            assert_eq!(false, value.to_boolean(), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn TEST_to_boolean_TRUTHY_VALUES() {
+        for value in [
+            Value::Boolean(true),
+            Value::Number(1.0),
+            Value::Number(-0.5),
+            Value::Number(f64::INFINITY),
+            Value::Number(f64::NEG_INFINITY),
+            Value::BigInt(-1),
+            Value::String("0"),
+            Value::String("false"),
+            Value::String(" "),
+            Value::String("null"),
+            Value::String("undefined"),
+            Value::String("NaN"),
+            Value::Symbol,
+            Value::Object,
+        ] {
+            assert_eq!(true, to_boolean(&value), "{value:?}");
+            assert_eq!(true, value.to_boolean(), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn TEST_string_to_boolean_DIFFERS_FROM_string_is_truthy() {
+        assert_eq!(false, string_to_boolean(""));
+        assert_eq!(true, string_to_boolean("0"));
+        assert_eq!(true, string_to_boolean("false"));
+        assert_eq!(true, string_to_boolean("\t"));
+    }
+
+    #[test]
+    fn TEST_number_to_boolean() {
+        assert_eq!(false, number_to_boolean(0.0));
+        assert_eq!(false, number_to_boolean(-0.0));
+        assert_eq!(false, number_to_boolean(f64::NAN));
+        assert_eq!(true, number_to_boolean(f64::MIN_POSITIVE));
+    }
+}
@@ -1,6 +1,253 @@
 // lib.rs - to-be API
 
 
+// allows the derive macros' generated code to refer to `::to_be` within this crate
+extern crate self as to_be;
+
+
+/// Records the given classification by the given evaluator (an expression
+/// of type `metrics::Evaluator`), if the `"metrics"` feature is enabled;
+/// otherwise does nothing.
+macro_rules! record_classification_ {
+    ($evaluator:expr, $classification:expr) => {
+        #[cfg(feature = "metrics")]
+        $crate::metrics::record_($evaluator, $classification);
+    };
+}
+
+/// Reports the given input, if its given classification is `None`, as
+/// unrecognised by the evaluator of the given name - to the given
+/// callback, if any, or otherwise to the process-wide callback - if the
+/// `"hooks"` feature is enabled; otherwise does nothing.
+macro_rules! report_unrecognised_ {
+    ($evaluator:expr, $input:expr, $classification:expr) => {
+        report_unrecognised_!($evaluator, $input, $classification, None)
+    };
+    ($evaluator:expr, $input:expr, $classification:expr, $on_unrecognised:expr) => {
+        #[cfg(feature = "hooks")]
+        if $classification.is_none() {
+            $crate::hooks::report_($on_unrecognised, $evaluator, $input);
+        }
+    };
+}
+
+/// Emits a **tracing** event at level TRACE, if the `"tracing"` feature is
+/// enabled; otherwise does nothing.
+macro_rules! trace_event_ {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::trace!($($arg)+);
+    };
+}
+
+/// Emits a **tracing** event at level DEBUG, if the `"tracing"` feature is
+/// enabled; otherwise does nothing.
+macro_rules! debug_event_ {
+    ($($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        ::tracing::debug!($($arg)+);
+    };
+}
+
+
+pub mod args;
+pub mod audit;
+pub mod batch;
+pub mod diagnostics;
+pub mod diff;
+pub mod env;
+pub mod expr;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+pub mod fmt;
+#[cfg(feature = "fs")]
+pub mod fs;
+#[cfg(feature = "quickcheck")]
+pub mod generators;
+#[cfg(feature = "hooks")]
+pub mod hooks;
+pub mod http;
+#[cfg(feature = "ini")]
+pub mod ini;
+#[cfg(feature = "js-semantics")]
+pub mod js;
+pub mod locale;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+pub mod migrate;
+#[cfg(feature = "uniffi")]
+pub mod mobile;
+pub mod preset;
+#[cfg(feature = "prompt")]
+pub mod prompt;
+#[cfg(feature = "python")]
+pub mod python;
+#[cfg(feature = "winreg")]
+pub mod registry;
+pub mod rollout;
+#[cfg(feature = "ruby-semantics")]
+pub mod ruby;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "watch")]
+pub mod watch;
+
+mod aggregate;
+mod chained_terms;
+mod compiled_terms;
+#[cfg(feature = "regex")]
+mod pattern_terms;
+mod predicate_terms;
+mod strictness;
+mod term_entry;
+mod terms_summary;
+mod toggle_command;
+mod truthiness;
+mod truthy_len;
+
+pub use aggregate::{
+    all_truey,
+    any_truey,
+    TruthyIterator,
+    TruthyStats,
+    UnknownHandling,
+};
+pub use chained_terms::ChainedTerms;
+pub use compiled_terms::{
+    CompiledTerms,
+    InputTooLong,
+    TermDeprecation,
+};
+#[cfg(feature = "regex")]
+pub use pattern_terms::PatternTerms;
+pub use predicate_terms::{
+    PredicateOrder,
+    PredicateTerms,
+};
+pub use strictness::{
+    string_is_truthy_with_strictness,
+    string_is_truthy_with_terms_and_strictness,
+    Strictness,
+};
+pub use term_entry::{
+    CaseMode,
+    TermEntry,
+    TermOrigin,
+};
+pub use toggle_command::ToggleCommand;
+pub use truthiness::{
+    if_truthy,
+    IfTruthy,
+    KleeneLogic,
+    Truthiness,
+    UnknownTruthiness,
+};
+pub use truthy_len::TruthyLen;
+
+#[cfg(feature = "uniffi")]
+uniffi::setup_scaffolding!("to_be");
+
+/// Derives [Truthy] for enums and for structs that delegate to a field (see
+/// **to-be-derive** for details).
+///
+/// # Example:
+///
+/// ```
+/// use to_be::Truthy;
+///
+/// #[derive(Truthy)]
+/// enum Status {
+///     #[truthy(true)]
+///     Enabled,
+///     #[truthy(false)]
+///     Disabled,
+///     #[truthy(unknown)]
+///     Unknown,
+/// }
+///
+/// assert_eq!(Some(true), Status::Enabled.is_truthy());
+/// assert_eq!(Some(false), Status::Disabled.is_truthy());
+/// assert_eq!(None, Status::Unknown.is_truthy());
+///
+/// #[derive(Truthy)]
+/// struct Setting {
+///     name :      String,
+///     #[truthy]
+///     raw_value : bool,
+/// }
+///
+/// let setting = Setting {
+///     name :      "verbose".to_owned(),
+///     raw_value : true,
+/// };
+///
+/// assert_eq!(Some(true), setting.is_truthy());
+/// ```
+///
+/// # Note:
+/// Each variant of an enum must be marked with `#[truthy(...)]`:
+///
+/// ```compile_fail
+/// #[derive(to_be::Truthy)]
+/// enum Status {
+///     #[truthy(true)]
+///     Enabled,
+///     Disabled,
+/// }
+/// ```
+///
+/// with one of `true`, `false`, or `unknown`:
+///
+/// ```compile_fail
+/// #[derive(to_be::Truthy)]
+/// enum Status {
+///     #[truthy(true)]
+///     Enabled,
+///     #[truthy(maybe)]
+///     Disabled,
+/// }
+/// ```
+///
+/// Exactly one field of a struct must be marked with `#[truthy]`, so
+/// neither
+///
+/// ```compile_fail
+/// #[derive(to_be::Truthy)]
+/// struct Setting {
+///     name :      String,
+///     raw_value : String,
+/// }
+/// ```
+///
+/// nor
+///
+/// ```compile_fail
+/// #[derive(to_be::Truthy)]
+/// struct Setting {
+///     #[truthy]
+///     name :      String,
+///     #[truthy]
+///     raw_value : String,
+/// }
+/// ```
+///
+/// is permitted, and it cannot be derived for unions:
+///
+/// ```compile_fail
+/// #[derive(to_be::Truthy)]
+/// union Setting {
+///     #[truthy]
+///     flag : bool,
+/// }
+/// ```
+#[cfg(feature = "derive")]
+pub use to_be_derive::Truthy;
+
+use std::ffi::{
+    CStr,
+    OsStr,
+};
+
 #[rustfmt::skip]
 mod constants {
     #![allow(clippy::redundant_static_lifetimes)]
@@ -47,6 +294,209 @@ mod constants {
         "on",
         "1",
     ];
+
+    // NOTE: `STOCK_TERMS_BY_FOLDED_FORM` must hold the folded form of every
+    // one of the above strings, and no key may be longer than
+    // `STOCK_TERM_MAX_LEN`.
+
+    #[cfg(all(feature = "phf", to_be_stock_default))]
+    pub(super) const STOCK_TERM_MAX_LEN : usize = 5;
+
+    #[cfg(all(feature = "phf", to_be_stock_default))]
+    pub(super) static STOCK_TERMS_BY_FOLDED_FORM : phf::Map<&'static str, bool> = phf::phf_map! {
+        "0"     => false,
+        "false" => false,
+        "no"    => false,
+        "off"   => false,
+        "1"     => true,
+        "on"    => true,
+        "true"  => true,
+        "yes"   => true,
+    };
+}
+
+
+/// The tables of the default vocabulary, as selected by the (mutually
+/// exclusive, as enforced by the build script) `"default-locale-*"` and
+/// `"default-preset-*"` features, or the stock tables if none is enabled.
+mod defaults {
+    #[cfg(to_be_stock_default)]
+    pub(super) use super::constants::{
+        FALSEY_LOWERCASE_STRINGS,
+        FALSEY_PRECISE_STRINGS,
+        TRUEY_LOWERCASE_STRINGS,
+        TRUEY_PRECISE_STRINGS,
+    };
+
+    /// The tag of the locale whose terms are the default vocabulary, if
+    /// any.
+    #[cfg(to_be_stock_default)]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = None;
+
+    #[cfg(feature = "default-locale-cs")]
+    pub(super) use {
+        super::locale::constants::{
+            CS_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            CS_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-cs")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("cs");
+
+    #[cfg(feature = "default-locale-de")]
+    pub(super) use {
+        super::locale::constants::{
+            DE_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            DE_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-de")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("de");
+
+    #[cfg(feature = "default-locale-es")]
+    pub(super) use {
+        super::locale::constants::{
+            ES_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            ES_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-es")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("es");
+
+    #[cfg(feature = "default-locale-fr")]
+    pub(super) use {
+        super::locale::constants::{
+            FR_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            FR_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-fr")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("fr");
+
+    #[cfg(feature = "default-locale-it")]
+    pub(super) use {
+        super::locale::constants::{
+            IT_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            IT_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-it")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("it");
+
+    #[cfg(feature = "default-locale-ja")]
+    pub(super) use {
+        super::locale::constants::{
+            JA_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            JA_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-ja")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("ja");
+
+    #[cfg(feature = "default-locale-ko")]
+    pub(super) use {
+        super::locale::constants::{
+            KO_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            KO_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-ko")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("ko");
+
+    #[cfg(feature = "default-locale-pl")]
+    pub(super) use {
+        super::locale::constants::{
+            PL_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            PL_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-pl")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("pl");
+
+    #[cfg(feature = "default-locale-pt")]
+    pub(super) use {
+        super::locale::constants::{
+            PT_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            PT_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-pt")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("pt");
+
+    #[cfg(feature = "default-locale-ru")]
+    pub(super) use {
+        super::locale::constants::{
+            RU_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            RU_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-ru")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("ru");
+
+    #[cfg(feature = "default-locale-uk")]
+    pub(super) use {
+        super::locale::constants::{
+            UK_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            UK_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-uk")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("uk");
+
+    #[cfg(feature = "default-locale-zh")]
+    pub(super) use {
+        super::locale::constants::{
+            ZH_FALSEY_LOWERCASE_STRINGS as FALSEY_LOWERCASE_STRINGS,
+            ZH_TRUEY_LOWERCASE_STRINGS as TRUEY_LOWERCASE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_PRECISE_STRINGS,
+        NO_STRINGS as TRUEY_PRECISE_STRINGS,
+    };
+    #[cfg(feature = "default-locale-zh")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = Some("zh");
+
+    #[cfg(feature = "default-preset-yaml11")]
+    pub(super) use {
+        super::preset::constants::{
+            YAML11_FALSEY_PRECISE_STRINGS as FALSEY_PRECISE_STRINGS,
+            YAML11_TRUEY_PRECISE_STRINGS as TRUEY_PRECISE_STRINGS,
+        },
+        NO_STRINGS as FALSEY_LOWERCASE_STRINGS,
+        NO_STRINGS as TRUEY_LOWERCASE_STRINGS,
+    };
+    #[cfg(feature = "default-preset-yaml11")]
+    pub(super) const DEFAULT_LOCALE_TAG : Option<&str> = None;
+
+    /// The preset whose terms are the default vocabulary, if any.
+    #[cfg(feature = "default-preset-yaml11")]
+    pub(super) const DEFAULT_PRESET : Option<super::preset::Preset> = Some(super::preset::Preset::Yaml11);
+    #[cfg(not(feature = "default-preset-yaml11"))]
+    pub(super) const DEFAULT_PRESET : Option<super::preset::Preset> = None;
+
+    #[allow(dead_code)]
+    pub(super) const NO_STRINGS : &[&str] = &[];
 }
 
 
@@ -54,7 +504,9 @@ mod constants {
 #[derive(Clone)]
 #[derive(Debug)]
 pub enum Terms<'a> {
-    /// Use the built-in comparison strings.
+    /// Use the built-in comparison strings, which are the stock term strings
+    /// unless another default vocabulary has been selected via one of the
+    /// `"default-locale-*"` or `"default-preset-*"` features.
     Default,
     /// Use the given `*precise_strings` and, optionally, the given
     /// `*lower_strings` to evaluate the truthyness of a given string.
@@ -64,8 +516,247 @@ pub enum Terms<'a> {
         truey_precise_strings :    &'a [&'a str],
         truey_lowercase_strings :  &'a [&'a str],
     },
+    /// As `Strings`, except that each of the `*_precise_strings` is in
+    /// (strictly ascending) sorted order, and so is searched by binary search
+    /// rather than linearly. This is better suited to large vocabularies,
+    /// such as those merged from several locales.
+    ///
+    /// # Note:
+    /// Classification is unreliable if the `*_precise_strings` are not
+    /// sorted, so prefer to construct via [Terms::sorted_strings], which
+    /// validates this.
+    SortedStrings {
+        falsey_precise_strings :   &'a [&'a str],
+        falsey_lowercase_strings : &'a [&'a str],
+        truey_precise_strings :    &'a [&'a str],
+        truey_lowercase_strings :  &'a [&'a str],
+    },
+}
+
+impl<'a> Terms<'a> {
+    /// Creates a `Terms::SortedStrings` instance from the given strings,
+    /// having verified that each of the precise strings is in (strictly
+    /// ascending) sorted order.
+    ///
+    /// # Returns:
+    /// - `None` - either of `falsey_precise_strings` or
+    ///   `truey_precise_strings` is not sorted;
+    /// - `Some(terms)` - the terms;
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     string_is_truthy_with,
+    ///     Terms,
+    /// };
+    ///
+    /// let terms = Terms::sorted_strings(&["Nein", "Non", "No"], &[], &["Ja", "Oui", "Yes"], &[]);
+    ///
+    /// assert!(terms.is_none()); // "No" < "Non"
+    ///
+    /// let terms = Terms::sorted_strings(&["Nein", "No", "Non"], &[], &["Ja", "Oui", "Yes"], &[]).unwrap();
+    ///
+    /// assert_eq!(Some(false), string_is_truthy_with("Non", terms.clone()));
+    /// assert_eq!(Some(true), string_is_truthy_with("Ja", terms.clone()));
+    /// assert_eq!(None, string_is_truthy_with("ja", terms));
+    /// ```
+    pub fn sorted_strings(
+        falsey_precise_strings : &'a [&'a str],
+        falsey_lowercase_strings : &'a [&'a str],
+        truey_precise_strings : &'a [&'a str],
+        truey_lowercase_strings : &'a [&'a str],
+    ) -> Option<Self> {
+        let is_sorted = |strings : &[&str]| strings.windows(2).all(|w| w[0] < w[1]);
+
+        if is_sorted(falsey_precise_strings) && is_sorted(truey_precise_strings) {
+            Some(Self::SortedStrings {
+                falsey_precise_strings,
+                falsey_lowercase_strings,
+                truey_precise_strings,
+                truey_lowercase_strings,
+            })
+        } else {
+            None
+        }
+    }
+}
+
+/// Folds the given string into the form in which it is compared against
+/// the lowercase term strings.
+///
+/// When the `"unicode"` feature is enabled, non-ASCII strings have any
+/// fullwidth forms (U+FF01 - U+FF5E) and the ideographic space (U+3000)
+/// mapped to their ASCII equivalents, are normalised (to NFC), and are then
+/// lowercased according to the Unicode rules; otherwise only ASCII
+/// characters are lowercased.
+fn fold_(s : &str) -> String {
+    #[cfg(feature = "unicode")]
+    if !s.is_ascii() {
+        return fold_unicode_(s).collect();
+    }
+
+    s.to_ascii_lowercase()
+}
+
+/// Obtains the characters of the folded form (see [fold_]) of the given
+/// (non-ASCII) string.
+#[cfg(feature = "unicode")]
+fn fold_unicode_(s : &str) -> impl Iterator<Item = char> + '_ {
+    use unicode_normalization::UnicodeNormalization as _;
+
+    fn narrow_(c : char) -> char {
+        match c {
+            '\u{3000}' => ' ',
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFF01 + 0x21).unwrap_or(c),
+            _ => c,
+        }
+    }
+
+    s.chars().map(narrow_).nfc().flat_map(char::to_lowercase)
+}
+
+/// The size of the stack buffer of [FoldedString], which accommodates the
+/// folded forms of all but unusually long strings.
+const FOLD_BUFFER_SIZE : usize = 32;
+
+/// The folded form (see [fold_]) of a string, held in a stack buffer unless
+/// it is too long, so that typical strings are folded without allocation.
+enum FoldedString {
+    Inline {
+        bytes : [u8; FOLD_BUFFER_SIZE],
+        len :   usize,
+    },
+    Heap(String),
+}
+
+impl FoldedString {
+    fn new(s : &str) -> Self {
+        #[cfg(feature = "unicode")]
+        if !s.is_ascii() {
+            let mut bytes = [0u8; FOLD_BUFFER_SIZE];
+            let mut len = 0;
+
+            for c in fold_unicode_(s) {
+                let n = c.len_utf8();
+
+                if len + n > FOLD_BUFFER_SIZE {
+                    return Self::Heap(fold_(s));
+                }
+
+                c.encode_utf8(&mut bytes[len..]);
+                len += n;
+            }
+
+            return Self::Inline {
+                bytes,
+                len,
+            };
+        }
+
+        if s.len() > FOLD_BUFFER_SIZE {
+            return Self::Heap(s.to_ascii_lowercase());
+        }
+
+        let mut bytes = [0u8; FOLD_BUFFER_SIZE];
+
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        bytes[..s.len()].make_ascii_lowercase();
+
+        Self::Inline {
+            bytes,
+            len : s.len(),
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Inline {
+                bytes,
+                len,
+            } => std::str::from_utf8(&bytes[..*len]).expect("folded string must be valid UTF-8"),
+            Self::Heap(s) => s,
+        }
+    }
+}
+
+/// A string prepared for comparison against lowercase term strings.
+enum Folded<'a> {
+    /// The string is compared as-is, ignoring ASCII case, and so without
+    /// allocation.
+    IgnoreAsciiCase(&'a str),
+    /// The (non-ASCII) string has been folded (see [fold_]).
+    #[cfg(feature = "unicode")]
+    Folded(FoldedString),
+}
+
+impl<'a> Folded<'a> {
+    fn new(s : &'a str) -> Self {
+        #[cfg(feature = "unicode")]
+        if !s.is_ascii() {
+            return Self::Folded(FoldedString::new(s));
+        }
+
+        Self::IgnoreAsciiCase(s)
+    }
+
+    /// Indicates whether the folded string matches the given lowercase
+    /// term string.
+    fn matches(
+        &self,
+        lowercase_string : &str,
+    ) -> bool {
+        match self {
+            Self::IgnoreAsciiCase(s) => lowercase_string.eq_ignore_ascii_case(s),
+            #[cfg(feature = "unicode")]
+            Self::Folded(l) => lowercase_string == l.as_str(),
+        }
+    }
+
+    /// Obtains the first of the given lowercase term strings that the folded
+    /// string matches, if any.
+    fn find_in<'t>(
+        &self,
+        lowercase_strings : &[&'t str],
+    ) -> Option<&'t str> {
+        lowercase_strings.iter().copied().find(|&f| self.matches(f))
+    }
+
+    /// Indicates whether the folded string matches any of the given
+    /// lowercase term strings.
+    fn is_in(
+        &self,
+        lowercase_strings : &[&str],
+    ) -> bool {
+        lowercase_strings.iter().any(|&f| self.matches(f))
+    }
+}
+
+/// Displays an input string in tracing events, truncated (to at most
+/// [TracedInput::MAX_CHARS] characters) so that events do not carry
+/// arbitrarily long - and, potentially, sensitive - values in full.
+#[cfg(feature = "tracing")]
+struct TracedInput<'a>(&'a str);
+
+#[cfg(feature = "tracing")]
+impl TracedInput<'_> {
+    const MAX_CHARS : usize = 16;
+}
+
+#[cfg(feature = "tracing")]
+impl std::fmt::Display for TracedInput<'_> {
+    fn fmt(
+        &self,
+        f : &mut std::fmt::Formatter<'_>,
+    ) -> std::fmt::Result {
+        match self.0.char_indices().nth(Self::MAX_CHARS) {
+            Some((index, _)) => write!(f, "{:?}... ({} bytes)", &self.0[..index], self.0.len()),
+            None => write!(f, "{:?}", self.0),
+        }
+    }
 }
 
+#[cfg_attr(all(feature = "phf", to_be_stock_default), allow(dead_code))]
 fn string_is_truthy_against_(
     s : &str,
     sorted_precise_strings : &[&str],
@@ -76,9 +767,7 @@ fn string_is_truthy_against_(
     if sorted_precise_strings.binary_search(&s).is_ok() {
         true
     } else {
-        let l = s.to_ascii_lowercase();
-
-        lowercase_strings.iter().any(|&f| f == l)
+        Folded::new(s).is_in(lowercase_strings)
     }
 }
 
@@ -92,6 +781,17 @@ fn string_is_truthy_with_(
 ) -> Option<bool> {
     let s = s.trim();
 
+    #[cfg(all(feature = "phf", to_be_stock_default))]
+    if let Terms::Default = terms {
+        let r = string_is_truthy_phf_(s);
+
+        if r.is_none() {
+            debug_event_!(input = %TracedInput(s), "input is not recognised");
+        }
+
+        return r;
+    }
+
     match terms {
         Terms::Default => {
             if stock_falsey_sorted_precise_strings.binary_search(&s).is_ok() {
@@ -113,28 +813,62 @@ fn string_is_truthy_with_(
                 return Some(true);
             }
         },
+        Terms::SortedStrings {
+            falsey_precise_strings,
+            truey_precise_strings,
+            ..
+        } => {
+            if falsey_precise_strings.binary_search(&s).is_ok() {
+                return Some(false);
+            }
+            if truey_precise_strings.binary_search(&s).is_ok() {
+                return Some(true);
+            }
+        },
     };
 
-    let l = s.to_ascii_lowercase();
+    trace_event_!(input = %TracedInput(s), "no precise term matched; falling back to lowercase matching");
+
+    let l = Folded::new(s);
     let (falsey_lowercase_strings, truey_lowercase_strings) = match terms {
         Terms::Default => (stock_falsey_lowercase_strings, stock_truey_lowercase_strings),
         Terms::Strings {
             falsey_lowercase_strings,
             truey_lowercase_strings,
             ..
+        }
+        | Terms::SortedStrings {
+            falsey_lowercase_strings,
+            truey_lowercase_strings,
+            ..
         } => (falsey_lowercase_strings, truey_lowercase_strings),
     };
 
-    if falsey_lowercase_strings.iter().any(|&f| f == l) {
+    if l.is_in(falsey_lowercase_strings) {
         return Some(false);
     }
-    if truey_lowercase_strings.iter().any(|&f| f == l) {
+    if l.is_in(truey_lowercase_strings) {
         return Some(true);
     }
 
+    debug_event_!(input = %TracedInput(s), "input is not recognised");
+
     None
 }
 
+/// Classifies the given (trimmed) string against the stock terms by a
+/// single perfect-hash lookup of its folded form.
+#[cfg(all(feature = "phf", to_be_stock_default))]
+fn string_is_truthy_phf_(s : &str) -> Option<bool> {
+    if s.is_ascii() && s.len() > constants::STOCK_TERM_MAX_LEN {
+        return None;
+    }
+
+    let l = FoldedString::new(s);
+
+    constants::STOCK_TERMS_BY_FOLDED_FORM.get(l.as_str()).copied()
+}
+
 /// Obtain the stock term strings of the library.
 ///
 /// This may be handy when you want to, say, provide your own "truey" term
@@ -153,10 +887,14 @@ pub fn stock_term_strings() -> Terms<'static> {
 /// # Note:
 /// It is NOT guaranteed that `string_is_falsey(x) == !string_is_truey(x)`.
 pub fn string_is_falsey(s : &str) -> bool {
+    #[cfg(all(feature = "phf", to_be_stock_default))]
+    return Some(false) == string_is_truthy_phf_(s.trim());
+
+    #[cfg(not(all(feature = "phf", to_be_stock_default)))]
     string_is_truthy_against_(
         s,
-        constants::FALSEY_PRECISE_STRINGS,
-        constants::FALSEY_LOWERCASE_STRINGS,
+        defaults::FALSEY_PRECISE_STRINGS,
+        defaults::FALSEY_LOWERCASE_STRINGS,
     )
 }
 
@@ -172,10 +910,14 @@ pub fn string_is_falsey(s : &str) -> bool {
 /// - `Some(true)` - string (is classified as "truthy" and) is deemed
 ///   "truey";
 pub fn string_is_truey(s : &str) -> bool {
+    #[cfg(all(feature = "phf", to_be_stock_default))]
+    return Some(true) == string_is_truthy_phf_(s.trim());
+
+    #[cfg(not(all(feature = "phf", to_be_stock_default)))]
     string_is_truthy_against_(
         s,
-        constants::TRUEY_PRECISE_STRINGS,
-        constants::TRUEY_LOWERCASE_STRINGS,
+        defaults::TRUEY_PRECISE_STRINGS,
+        defaults::TRUEY_LOWERCASE_STRINGS,
     )
 }
 
@@ -189,14 +931,19 @@ pub fn string_is_truey(s : &str) -> bool {
 /// - `Some(true)` - string (is classified as "truthy" and) is deemed
 ///   "truey";
 pub fn string_is_truthy(s : &str) -> Option<bool> {
-    string_is_truthy_with_(
+    let r = string_is_truthy_with_(
         s,
         Terms::Default,
-        constants::FALSEY_PRECISE_STRINGS,
-        constants::FALSEY_LOWERCASE_STRINGS,
-        constants::TRUEY_PRECISE_STRINGS,
-        constants::TRUEY_LOWERCASE_STRINGS,
-    )
+        defaults::FALSEY_PRECISE_STRINGS,
+        defaults::FALSEY_LOWERCASE_STRINGS,
+        defaults::TRUEY_PRECISE_STRINGS,
+        defaults::TRUEY_LOWERCASE_STRINGS,
+    );
+
+    record_classification_!(metrics::Evaluator::Default, r);
+    report_unrecognised_!("string_is_truthy", s, r);
+
+    r
 }
 
 /// Indicates whether the instance can be classed as "truthy" when evaluated
@@ -205,414 +952,2192 @@ pub fn string_is_truthy_with(
     s : &str,
     terms : Terms,
 ) -> Option<bool> {
-    string_is_truthy_with_(
+    #[cfg(feature = "metrics")]
+    let evaluator = match terms {
+        Terms::Default => metrics::Evaluator::Default,
+        _ => metrics::Evaluator::Custom,
+    };
+
+    let r = string_is_truthy_with_(
         s,
         terms,
-        constants::FALSEY_PRECISE_STRINGS,
-        constants::FALSEY_LOWERCASE_STRINGS,
-        constants::TRUEY_PRECISE_STRINGS,
-        constants::TRUEY_LOWERCASE_STRINGS,
-    )
-}
+        defaults::FALSEY_PRECISE_STRINGS,
+        defaults::FALSEY_LOWERCASE_STRINGS,
+        defaults::TRUEY_PRECISE_STRINGS,
+        defaults::TRUEY_LOWERCASE_STRINGS,
+    );
 
-/// Trait that provides truthy attributes for an implementing type.
-pub trait Truthy {
-    /// Indicates whether the instance can be classed as "falsey".
-    fn is_falsey(&self) -> bool {
-        Some(false) == self.is_truthy()
-    }
-    /// Indicates whether the instance can be classed as "truey".
-    fn is_truey(&self) -> bool {
-        Some(true) == self.is_truthy()
-    }
-    /// Indicates whether the instance can be classed as "truthy", and, if
-    /// so, whether it is "truey" or "falsey".
-    fn is_truthy(&self) -> Option<bool>;
-}
+    record_classification_!(evaluator, r);
+    report_unrecognised_!("string_is_truthy_with", s, r);
 
-/// Specialisation of [Truthy] for type `T` for any type that implements
-/// [AsStr].
-#[cfg(feature = "implement-Truthy-for-AsStr")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_AsStr {
-    use super::Truthy;
-    use base_traits::AsStr;
+    r
+}
 
-    impl<T> Truthy for T
-    where
-        T : AsStr,
-    {
-        fn is_truthy(&self) -> Option<bool> {
-            super::string_is_truthy(self.as_str())
-        }
-    }
+/// Obtains the classification of the first of the given candidates that is
+/// "truthy", so that a flag may be sought in several places in turn - e.g.
+/// a header, then a query parameter, then a cookie - with any that is not
+/// recognised being passed over.
+///
+/// # Note:
+/// Only the outcome is recorded (with the `"metrics"` feature) and, if no
+/// candidate is "truthy", reported as unrecognised (with the `"hooks"`
+/// feature), as the last candidate; candidates that are passed over are
+/// not.
+///
+/// # Returns:
+/// - `None` - none of the candidates is classified as "truthy";
+/// - `Some(false)` - the first candidate classified as "truthy" is deemed
+///   "falsey";
+/// - `Some(true)` - the first candidate classified as "truthy" is deemed
+///   "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::first_determinate;
+///
+/// let header : Option<&str> = Some("maybe");
+/// let query_param : Option<&str> = None;
+/// let cookie : Option<&str> = Some("off");
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!(Some(false), first_determinate([header, query_param, cookie].into_iter().flatten()));
+/// # }
+/// ```
+pub fn first_determinate<I, S>(candidates : I) -> Option<bool>
+where
+    I : IntoIterator<Item = S>,
+    S : AsRef<str>,
+{
+    first_determinate_with(candidates, Terms::Default)
 }
 
-#[cfg(feature = "implement-Truthy-for-bool")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_bool {
-    use super::Truthy;
+/// Obtains the classification of the first of the given candidates that is
+/// "truthy" when evaluated against the given terms strings.
+///
+/// See [first_determinate()] for details.
+pub fn first_determinate_with<I, S>(
+    candidates : I,
+    terms : Terms,
+) -> Option<bool>
+where
+    I : IntoIterator<Item = S>,
+    S : AsRef<str>,
+{
+    #[cfg(feature = "metrics")]
+    let evaluator = match terms {
+        Terms::Default => metrics::Evaluator::Default,
+        _ => metrics::Evaluator::Custom,
+    };
 
-    impl Truthy for bool {
-        fn is_truthy(&self) -> Option<bool> {
-            Some(*self)
-        }
-    }
+    let mut last = None;
 
-    impl Truthy for &bool {
-        fn is_truthy(&self) -> Option<bool> {
-            Some(**self)
-        }
-    }
+    let r = candidates.into_iter().find_map(|candidate| {
+        let r = string_is_truthy_with_(
+            candidate.as_ref(),
+            terms.clone(),
+            defaults::FALSEY_PRECISE_STRINGS,
+            defaults::FALSEY_LOWERCASE_STRINGS,
+            defaults::TRUEY_PRECISE_STRINGS,
+            defaults::TRUEY_LOWERCASE_STRINGS,
+        );
 
-    impl Truthy for Option<bool> {
-        fn is_truthy(&self) -> Option<bool> {
-            *self
-        }
-    }
+        last = Some(candidate);
 
-    impl Truthy for &Option<bool> {
-        fn is_truthy(&self) -> Option<bool> {
-            **self
-        }
-    }
+        r
+    });
 
-    impl Truthy for Option<&bool> {
-        fn is_truthy(&self) -> Option<bool> {
-            self.map(|&b| b)
-        }
+    record_classification_!(evaluator, r);
+    if let Some(_last) = last {
+        report_unrecognised_!("first_determinate_with", _last.as_ref(), r);
     }
 
-    impl Truthy for &Option<&bool> {
-        fn is_truthy(&self) -> Option<bool> {
-            self.map(|&b| b)
-        }
-    }
+    r
 }
 
-#[cfg(feature = "implement-Truthy-for-str")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_str {
-    use super::Truthy;
+/// Indicates how a byte string is to be interpreted by
+/// [bytes_are_truthy_with_encoding()].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum ByteEncoding {
+    /// The bytes must be valid UTF-8, else they are not "truthy".
+    #[default]
+    Utf8,
+    /// The bytes must all be ASCII, else they are not "truthy".
+    Ascii,
+    /// Each byte is the ISO-8859-1 (Latin-1) character of the same value,
+    /// so any byte string may be classified.
+    Latin1,
+}
 
-    impl Truthy for &str {
-        fn is_truthy(&self) -> Option<bool> {
-            super::string_is_truthy(self)
+/// Indicates whether the given byte string, interpreted as strict UTF-8, is
+/// "truthy" and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy", including where it is
+///   not valid UTF-8;
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn bytes_are_truthy(bytes : &[u8]) -> Option<bool> {
+    bytes_are_truthy_with_encoding(bytes, ByteEncoding::Utf8)
+}
+
+/// Indicates whether the given byte string, interpreted according to the
+/// given encoding, is "truthy" and, if so, whether it is "truey" or
+/// "falsey".
+///
+/// ASCII byte strings are classified without conversion (or allocation) in
+/// all encodings.
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy", including where it is
+///   not valid in the given encoding;
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     bytes_are_truthy_with_encoding,
+///     ByteEncoding,
+/// };
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!(Some(true), bytes_are_truthy_with_encoding(b"Yes", ByteEncoding::Ascii));
+/// assert_eq!(None, bytes_are_truthy_with_encoding(b"Yes\xA0", ByteEncoding::Utf8));
+/// assert_eq!(Some(true), bytes_are_truthy_with_encoding(b"Yes\xA0", ByteEncoding::Latin1)); // NBSP is trimmed
+/// # }
+/// ```
+pub fn bytes_are_truthy_with_encoding(
+    bytes : &[u8],
+    encoding : ByteEncoding,
+) -> Option<bool> {
+    if bytes.is_ascii() {
+        return std::str::from_utf8(bytes).ok().and_then(string_is_truthy);
+    }
+
+    match encoding {
+        ByteEncoding::Utf8 => std::str::from_utf8(bytes).ok().and_then(string_is_truthy),
+        ByteEncoding::Ascii => None,
+        ByteEncoding::Latin1 => {
+            let s : String = bytes.iter().copied().map(char::from).collect();
+
+            string_is_truthy(&s)
+        },
+    }
+}
+
+/// Indicates whether the given (trimmed) duration string, such as might
+/// specify a timeout or a retry interval, is "truthy" and, if so, whether it
+/// is "truey" or "falsey", by the "zero is false" convention, i.e. a zero
+/// duration - `"0"`, `"0s"`, `"0.0ms"`, and so on - is "falsey" and a
+/// non-zero duration is "truey".
+///
+/// The string must be a non-negative decimal number optionally followed
+/// (with or without intervening whitespace) by one of the units `"ns"`,
+/// `"us"`, `"µs"`, `"ms"`, `"s"`, `"m"`, `"h"`, `"d"`.
+///
+/// # Returns:
+/// - `None` - string is not a duration;
+/// - `Some(false)` - string is a zero duration;
+/// - `Some(true)` - string is a non-zero duration;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::duration_string_is_truthy;
+///
+/// assert_eq!(Some(false), duration_string_is_truthy("0s"));
+/// assert_eq!(Some(true), duration_string_is_truthy(" 1.5 ms "));
+/// assert_eq!(None, duration_string_is_truthy("soon"));
+/// ```
+pub fn duration_string_is_truthy(s : &str) -> Option<bool> {
+    const UNITS : &[&str] = &["ns", "us", "\u{b5}s", "\u{3bc}s", "ms", "s", "m", "h", "d"];
+
+    let s = s.trim();
+    let number_len = s.find(|c : char| !c.is_ascii_digit() && '.' != c).unwrap_or(s.len());
+    let (number, unit) = s.split_at(number_len);
+    let unit = unit.trim_start();
+
+    if !unit.is_empty() && !UNITS.contains(&unit) {
+        return None;
+    }
+
+    let (integral, fractional) = number.split_once('.').unwrap_or((number, ""));
+
+    if integral.is_empty() && fractional.is_empty() {
+        return None;
+    }
+    if fractional.contains('.') {
+        return None;
+    }
+
+    let truey = integral.bytes().chain(fractional.bytes()).any(|b| b'0' != b);
+
+    trace_event_!(input = %TracedInput(s), truey, "input interpreted as duration");
+
+    Some(truey)
+}
+
+/// Indicates whether the given process exit code is "truey" by the shell
+/// convention, i.e. whether it is `0` (success).
+///
+/// # Note:
+/// This is the inverse of the interpretation of numbers by
+/// [string_is_truthy()], by which `"0"` is "falsey".
+pub const fn exit_code_is_truey(code : i32) -> bool {
+    0 == code
+}
+
+/// Indicates whether the given (trimmed) string, such as the expansion of
+/// `$?`, is a process exit code and, if so, whether it is "truey" or
+/// "falsey" by the shell convention (see [exit_code_is_truey()]).
+///
+/// # Returns:
+/// - `None` - string is not an (integral) exit code;
+/// - `Some(false)` - string is a non-zero exit code (failure);
+/// - `Some(true)` - string is a zero exit code (success);
+///
+/// # Example:
+///
+/// ```
+/// use to_be::exit_code_string_is_truthy;
+///
+/// assert_eq!(Some(true), exit_code_string_is_truthy("0"));
+/// assert_eq!(Some(false), exit_code_string_is_truthy(" 127\n"));
+/// assert_eq!(None, exit_code_string_is_truthy("true"));
+/// ```
+pub fn exit_code_string_is_truthy(s : &str) -> Option<bool> {
+    let s = s.trim();
+
+    match s.parse::<i32>() {
+        Ok(code) => {
+            trace_event_!(code, "input interpreted as exit code");
+
+            Some(exit_code_is_truey(code))
+        },
+        Err(_) => {
+            debug_event_!(input = %TracedInput(s), "input is not an exit code");
+
+            None
+        },
+    }
+}
+
+/// Indicates whether the given C string is "truthy" and, if so, whether it
+/// is "truey" or "falsey".
+///
+/// The bytes up to (but not including) the terminating NUL that are valid
+/// UTF-8 are classified as by [string_is_truthy()]; those that are not
+/// cannot match any term, and so are not "truthy". No (lossy) conversion,
+/// and so no allocation, is required.
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy", including where it is
+///   not valid UTF-8;
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn c_string_is_truthy(s : &CStr) -> Option<bool> {
+    s.to_str().ok().and_then(string_is_truthy)
+}
+
+/// Indicates whether the given OS string is "truthy" and, if so, whether it
+/// is "truey" or "falsey".
+///
+/// Content that is valid UTF-8 is classified as by [string_is_truthy()];
+/// content that is not cannot match any term, and so is not "truthy". No
+/// (lossy) conversion, and so no allocation, is required.
+///
+/// Where the content, when trimmed of ASCII whitespace, is ASCII - as is
+/// almost every flag value - only that is examined, and it is classified
+/// directly, without the whole string being converted.
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy", including where it is
+///   not valid UTF-8;
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn os_string_is_truthy(s : &OsStr) -> Option<bool> {
+    os_string_is_truthy_by_(s, string_is_truthy)
+}
+
+/// Indicates whether the given OS string is "truthy" and, if so, whether it
+/// is "truey" or "falsey", when evaluated against the given terms.
+///
+/// Content is examined as by [os_string_is_truthy()], and classified as by
+/// [string_is_truthy_with()].
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy", including where it is
+///   not valid UTF-8;
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn os_string_is_truthy_with(
+    s : &OsStr,
+    terms : Terms,
+) -> Option<bool> {
+    os_string_is_truthy_by_(s, |s| string_is_truthy_with(s, terms))
+}
+
+/// Classifies the given OS string by the given function, examining only
+/// the trimmed content where that is ASCII.
+fn os_string_is_truthy_by_<F>(
+    s : &OsStr,
+    f : F,
+) -> Option<bool>
+where
+    F : FnOnce(&str) -> Option<bool>,
+{
+    let trimmed = s.as_encoded_bytes().trim_ascii();
+
+    if trimmed.is_ascii() {
+        // ASCII is valid UTF-8, so the conversion cannot fail
+        return std::str::from_utf8(trimmed).ok().and_then(f);
+    }
+
+    s.to_str().and_then(f)
+}
+
+/// Indicates whether any of the given strings equals the given bytes,
+/// optionally ignoring ASCII case, in a form usable in constant evaluation.
+const fn any_string_equals_const_(
+    s : &[u8],
+    strings : &[&str],
+    ignore_ascii_case : bool,
+) -> bool {
+    let mut i = 0;
+
+    'strings: while i < strings.len() {
+        let candidate = strings[i].as_bytes();
+
+        i += 1;
+
+        if candidate.len() != s.len() {
+            continue;
+        }
+
+        let mut j = 0;
+
+        while j < s.len() {
+            let (l, r) = if ignore_ascii_case {
+                (s[j].to_ascii_lowercase(), candidate[j])
+            } else {
+                (s[j], candidate[j])
+            };
+
+            if l != r {
+                continue 'strings;
+            }
+
+            j += 1;
+        }
+
+        return true;
+    }
+
+    false
+}
+
+const fn string_is_truthy_against_const_(
+    s : &str,
+    precise_strings : &[&str],
+    lowercase_strings : &[&str],
+) -> bool {
+    let s = s.trim_ascii().as_bytes();
+
+    any_string_equals_const_(s, precise_strings, false) || any_string_equals_const_(s, lowercase_strings, true)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "falsey", in
+/// a form usable in constant evaluation.
+///
+/// # Note:
+/// Unlike [string_is_falsey], the string is trimmed of ASCII whitespace,
+/// and compared ignoring ASCII case, only.
+pub const fn string_is_falsey_const(s : &str) -> bool {
+    string_is_truthy_against_const_(s, defaults::FALSEY_PRECISE_STRINGS, defaults::FALSEY_LOWERCASE_STRINGS)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "truey", in
+/// a form usable in constant evaluation.
+///
+/// # Note:
+/// Unlike [string_is_truey], the string is trimmed of ASCII whitespace, and
+/// compared ignoring ASCII case, only.
+pub const fn string_is_truey_const(s : &str) -> bool {
+    string_is_truthy_against_const_(s, defaults::TRUEY_PRECISE_STRINGS, defaults::TRUEY_LOWERCASE_STRINGS)
+}
+
+/// Indicates whether the given string is "truthy" and, if so, whether it is
+/// "truey" or "falsey", in a form usable in constant evaluation.
+///
+/// # Note:
+/// Unlike [string_is_truthy], the string is trimmed of ASCII whitespace,
+/// and compared ignoring ASCII case, only.
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// const FEATURE_ENABLED : bool = match to_be::string_is_truthy_const(" On ") {
+///     Some(b) => b,
+///     None => false,
+/// };
+///
+/// assert!(FEATURE_ENABLED);
+/// # }
+/// ```
+pub const fn string_is_truthy_const(s : &str) -> Option<bool> {
+    let s = s.trim_ascii().as_bytes();
+
+    if any_string_equals_const_(s, defaults::FALSEY_PRECISE_STRINGS, false) {
+        return Some(false);
+    }
+    if any_string_equals_const_(s, defaults::TRUEY_PRECISE_STRINGS, false) {
+        return Some(true);
+    }
+    if any_string_equals_const_(s, defaults::FALSEY_LOWERCASE_STRINGS, true) {
+        return Some(false);
+    }
+    if any_string_equals_const_(s, defaults::TRUEY_LOWERCASE_STRINGS, true) {
+        return Some(true);
+    }
+
+    None
+}
+
+/// Classifies the given string literal at compile time, evaluating to
+/// `true` if it is deemed "truey" and `false` if it is deemed "falsey", and
+/// failing to compile if it is not "truthy".
+///
+/// # Note:
+/// The literal is trimmed of ASCII whitespace, and compared ignoring ASCII
+/// case only.
+///
+/// # Example:
+///
+/// ```
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// const VERBOSE_BY_DEFAULT : bool = to_be::truthy!("no");
+///
+/// assert!(!VERBOSE_BY_DEFAULT);
+/// assert!(to_be::truthy!(" Yes "));
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// let _ = to_be::truthy!("treu");
+/// ```
+#[macro_export]
+macro_rules! truthy {
+    ($s:literal) => {{
+        const TRUTHY : bool = match $crate::string_is_truthy_const($s) {
+            ::core::option::Option::Some(b) => b,
+            ::core::option::Option::None => {
+                ::core::panic!(::core::concat!("\"", $s, "\" is not a recognised truthy string"))
+            },
+        };
+
+        TRUTHY
+    }};
+}
+
+/// Trait that provides truthy attributes for an implementing type.
+pub trait Truthy {
+    /// Indicates whether the instance can be classed as "falsey".
+    fn is_falsey(&self) -> bool {
+        Some(false) == self.is_truthy()
+    }
+    /// Indicates whether the instance can be classed as "truey".
+    fn is_truey(&self) -> bool {
+        Some(true) == self.is_truthy()
+    }
+    /// Indicates whether the instance can be classed as "truthy", and, if
+    /// so, whether it is "truey" or "falsey".
+    fn is_truthy(&self) -> Option<bool>;
+}
+
+/// Implementations of `arbitrary::Arbitrary` for [Truthiness],
+/// [CompiledTerms] (i.e. owned terms), [ByteEncoding], and
+/// [Preset](preset::Preset), so that code that handles them may be fuzzed.
+///
+/// The term strings of arbitrary [CompiledTerms] are drawn mostly from the
+/// stock terms, so that the vocabularies are realistic (and overlap with
+/// realistic inputs), and otherwise are arbitrary strings.
+#[cfg(feature = "arbitrary")]
+#[allow(non_snake_case)]
+mod implement_Arbitrary {
+    use super::{
+        constants,
+        preset::Preset,
+        ByteEncoding,
+        CompiledTerms,
+        Terms,
+        Truthiness,
+    };
+
+    use arbitrary::{
+        Arbitrary,
+        Result,
+        Unstructured,
+    };
+
+
+    /// The maximum number of term strings in each list of an arbitrary
+    /// [CompiledTerms].
+    const MAX_TERMS : usize = 16;
+
+    /// Obtains an arbitrary term string, which is 3 times in 4 one of the
+    /// stock terms.
+    fn arbitrary_term_(u : &mut Unstructured<'_>) -> Result<String> {
+        if u.ratio(3, 4)? {
+            let stock_terms : [&[&str]; 4] = [
+                constants::FALSEY_PRECISE_STRINGS,
+                constants::FALSEY_LOWERCASE_STRINGS,
+                constants::TRUEY_PRECISE_STRINGS,
+                constants::TRUEY_LOWERCASE_STRINGS,
+            ];
+            let terms = *u.choose(&stock_terms)?;
+
+            Ok(u.choose(terms)?.to_string())
+        } else {
+            String::arbitrary(u)
+        }
+    }
+
+    fn arbitrary_terms_(u : &mut Unstructured<'_>) -> Result<Vec<String>> {
+        let n = u.int_in_range(0..=MAX_TERMS)?;
+
+        (0..n).map(|_| arbitrary_term_(u)).collect()
+    }
+
+    fn strs_(strings : &[String]) -> Vec<&str> {
+        strings.iter().map(String::as_str).collect()
+    }
+
+    impl<'a> Arbitrary<'a> for Truthiness {
+        fn arbitrary(u : &mut Unstructured<'a>) -> Result<Self> {
+            Ok(*u.choose(&[Self::False, Self::Unknown, Self::True])?)
+        }
+
+        fn size_hint(_depth : usize) -> (usize, Option<usize>) {
+            (1, Some(4))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for CompiledTerms {
+        fn arbitrary(u : &mut Unstructured<'a>) -> Result<Self> {
+            let falsey_precise_strings = arbitrary_terms_(u)?;
+            let falsey_lowercase_strings = arbitrary_terms_(u)?;
+            let truey_precise_strings = arbitrary_terms_(u)?;
+            let truey_lowercase_strings = arbitrary_terms_(u)?;
+
+            Ok(Self::new(Terms::Strings {
+                falsey_precise_strings :   &strs_(&falsey_precise_strings),
+                falsey_lowercase_strings : &strs_(&falsey_lowercase_strings),
+                truey_precise_strings :    &strs_(&truey_precise_strings),
+                truey_lowercase_strings :  &strs_(&truey_lowercase_strings),
+            }))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ByteEncoding {
+        fn arbitrary(u : &mut Unstructured<'a>) -> Result<Self> {
+            Ok(*u.choose(&[Self::Utf8, Self::Ascii, Self::Latin1])?)
+        }
+
+        fn size_hint(_depth : usize) -> (usize, Option<usize>) {
+            (1, Some(4))
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Preset {
+        fn arbitrary(u : &mut Unstructured<'a>) -> Result<Self> {
+            Ok(*u.choose(Preset::ALL)?)
+        }
+
+        fn size_hint(_depth : usize) -> (usize, Option<usize>) {
+            (1, Some(4))
+        }
+    }
+}
+
+/// Implementations of `quickcheck::Arbitrary` for [Truthiness],
+/// [CompiledTerms] (i.e. owned terms), [ByteEncoding], and
+/// [Preset](preset::Preset) (see also the [generators] module).
+///
+/// The term strings of arbitrary [CompiledTerms] are drawn mostly from the
+/// stock terms, so that the vocabularies are realistic.
+#[cfg(feature = "quickcheck")]
+#[allow(non_snake_case)]
+mod implement_quickcheck_Arbitrary {
+    use super::{
+        constants,
+        preset::Preset,
+        ByteEncoding,
+        CompiledTerms,
+        Terms,
+        Truthiness,
+    };
+
+    use quickcheck::{
+        Arbitrary,
+        Gen,
+    };
+
+
+    /// Obtains an arbitrary term string, which is 3 times in 4 one of the
+    /// stock terms.
+    fn arbitrary_term_(g : &mut Gen) -> String {
+        if 0 != u8::arbitrary(g) % 4 {
+            let stock_terms : [&[&str]; 4] = [
+                constants::FALSEY_PRECISE_STRINGS,
+                constants::FALSEY_LOWERCASE_STRINGS,
+                constants::TRUEY_PRECISE_STRINGS,
+                constants::TRUEY_LOWERCASE_STRINGS,
+            ];
+            let terms = g.choose(&stock_terms).copied().unwrap_or_default();
+
+            g.choose(terms).copied().unwrap_or_default().to_owned()
+        } else {
+            String::arbitrary(g)
+        }
+    }
+
+    fn arbitrary_terms_(g : &mut Gen) -> Vec<String> {
+        let n = usize::arbitrary(g) % (g.size() + 1);
+
+        (0..n).map(|_| arbitrary_term_(g)).collect()
+    }
+
+    fn strs_(strings : &[String]) -> Vec<&str> {
+        strings.iter().map(String::as_str).collect()
+    }
+
+    impl Arbitrary for Truthiness {
+        fn arbitrary(g : &mut Gen) -> Self {
+            *g.choose(&[Self::False, Self::Unknown, Self::True]).unwrap()
+        }
+    }
+
+    impl Arbitrary for CompiledTerms {
+        fn arbitrary(g : &mut Gen) -> Self {
+            let falsey_precise_strings = arbitrary_terms_(g);
+            let falsey_lowercase_strings = arbitrary_terms_(g);
+            let truey_precise_strings = arbitrary_terms_(g);
+            let truey_lowercase_strings = arbitrary_terms_(g);
+
+            Self::new(Terms::Strings {
+                falsey_precise_strings :   &strs_(&falsey_precise_strings),
+                falsey_lowercase_strings : &strs_(&falsey_lowercase_strings),
+                truey_precise_strings :    &strs_(&truey_precise_strings),
+                truey_lowercase_strings :  &strs_(&truey_lowercase_strings),
+            })
+        }
+    }
+
+    impl Arbitrary for ByteEncoding {
+        fn arbitrary(g : &mut Gen) -> Self {
+            *g.choose(&[Self::Utf8, Self::Ascii, Self::Latin1]).unwrap()
+        }
+    }
+
+    impl Arbitrary for Preset {
+        fn arbitrary(g : &mut Gen) -> Self {
+            *g.choose(Preset::ALL).unwrap()
+        }
+    }
+}
+
+/// Implementations of [Truthy] for the smart pointers `Box<T>`, `Rc<T>`, and
+/// `Arc<T>`, where `T` implements [Truthy], including `Box<dyn Truthy>`
+/// and so on.
+///
+/// # Note:
+/// These are not available with the `"implement-Truthy-for-AsStr"`
+/// feature, with whose blanket implementation they would conflict.
+#[cfg(not(feature = "implement-Truthy-for-AsStr"))]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_smart_pointers {
+    use super::Truthy;
+
+    use std::{
+        rc::Rc,
+        sync::Arc,
+    };
+
+    impl<T> Truthy for Box<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for Rc<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for Arc<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+}
+
+/// Implementation of [Truthy] for `Result<T, E>`, where `T` implements
+/// [Truthy], such that `Ok(v)` is evaluated as `v` and `Err(_)` is not
+/// "truthy". This allows, say, `std::env::var("X").is_truthy()`.
+///
+/// # Note:
+/// This is not available with the `"implement-Truthy-for-AsStr"` feature,
+/// with whose blanket implementation it would conflict.
+#[cfg(not(feature = "implement-Truthy-for-AsStr"))]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_Result {
+    use super::Truthy;
+
+    impl<T, E> Truthy for Result<T, E>
+    where
+        T : Truthy,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            match self {
+                Ok(v) => v.is_truthy(),
+                Err(_) => None,
+            }
+        }
+    }
+
+    impl<T, E> Truthy for &Result<T, E>
+    where
+        T : Truthy,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+}
+
+/// Specialisation of [Truthy] for type `T` for any type that implements
+/// [AsStr].
+#[cfg(feature = "implement-Truthy-for-AsStr")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_AsStr {
+    use super::Truthy;
+    use base_traits::AsStr;
+
+    impl<T> Truthy for T
+    where
+        T : AsStr,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self.as_str())
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-AtomicBool")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_AtomicBool {
+    use super::Truthy;
+
+    use std::sync::atomic::{
+        AtomicBool,
+        Ordering,
+    };
+
+    // NOTE: the value is loaded with `Ordering::Relaxed`, which suffices for
+    // flags that do not guard other memory; where a stronger ordering is
+    // required, load the value explicitly and evaluate the `bool`, as in
+    // `flag.load(Ordering::Acquire).is_truthy()`
+
+    impl Truthy for AtomicBool {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(self.load(Ordering::Relaxed))
+        }
+    }
+
+    impl Truthy for &AtomicBool {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(self.load(Ordering::Relaxed))
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-bool")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_bool {
+    use super::Truthy;
+
+    impl Truthy for bool {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(*self)
+        }
+    }
+
+    impl Truthy for &bool {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(**self)
+        }
+    }
+
+    impl Truthy for Option<bool> {
+        fn is_truthy(&self) -> Option<bool> {
+            *self
+        }
+    }
+
+    impl Truthy for &Option<bool> {
+        fn is_truthy(&self) -> Option<bool> {
+            **self
+        }
+    }
+
+    impl Truthy for Option<&bool> {
+        fn is_truthy(&self) -> Option<bool> {
+            self.copied()
+        }
+    }
+
+    impl Truthy for &Option<&bool> {
+        fn is_truthy(&self) -> Option<bool> {
+            self.copied()
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-bytes")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_bytes {
+    use super::Truthy;
+
+    impl Truthy for [u8] {
+        fn is_truthy(&self) -> Option<bool> {
+            super::bytes_are_truthy(self)
+        }
+    }
+
+    impl Truthy for &[u8] {
+        fn is_truthy(&self) -> Option<bool> {
+            super::bytes_are_truthy(self)
+        }
+    }
+
+    impl Truthy for Vec<u8> {
+        fn is_truthy(&self) -> Option<bool> {
+            super::bytes_are_truthy(self)
+        }
+    }
+
+    impl Truthy for &Vec<u8> {
+        fn is_truthy(&self) -> Option<bool> {
+            super::bytes_are_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-char")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_char {
+    use super::Truthy;
+
+    /// Classifies a single answer character, as in `[Y/n]` prompts and
+    /// single-character flags.
+    fn char_is_truthy_(c : char) -> Option<bool> {
+        match c {
+            '0' | 'f' | 'F' | 'n' | 'N' => Some(false),
+            '1' | 't' | 'T' | 'y' | 'Y' => Some(true),
+            _ => None,
+        }
+    }
+
+    impl Truthy for char {
+        fn is_truthy(&self) -> Option<bool> {
+            char_is_truthy_(*self)
+        }
+    }
+
+    impl Truthy for &char {
+        fn is_truthy(&self) -> Option<bool> {
+            char_is_truthy_(**self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-Duration")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_Duration {
+    use super::Truthy;
+
+    use std::time::Duration;
+
+    impl Truthy for Duration {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(!self.is_zero())
+        }
+    }
+
+    impl Truthy for &Duration {
+        fn is_truthy(&self) -> Option<bool> {
+            Some(!self.is_zero())
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-floats")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_floats {
+    use super::Truthy;
+
+    // NOTE: `-0.0 == 0.0`, so both zeroes are "falsey"; infinities are
+    // "truey"; NaN is not "truthy"
+
+    macro_rules! implement_Truthy_for_float {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl Truthy for $type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        if self.is_nan() {
+                            None
+                        } else {
+                            Some(0.0 != *self)
+                        }
+                    }
+                }
+
+                impl Truthy for &$type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        (**self).is_truthy()
+                    }
+                }
+            )+
+        };
+    }
+
+    implement_Truthy_for_float!(f32, f64);
+}
+
+#[cfg(feature = "implement-Truthy-for-integers")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_integers {
+    use super::Truthy;
+
+    macro_rules! implement_Truthy_for_integer {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl Truthy for $type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(0 != *self)
+                    }
+                }
+
+                impl Truthy for &$type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(0 != **self)
+                    }
+                }
+            )+
+        };
+    }
+
+    implement_Truthy_for_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+}
+
+#[cfg(feature = "implement-Truthy-for-interior-mutability")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_interior_mutability {
+    use super::Truthy;
+
+    use std::{
+        cell::{
+            Cell,
+            RefCell,
+        },
+        sync::{
+            Mutex,
+            PoisonError,
+            RwLock,
+        },
+    };
+
+    // NOTE: the current value is read as follows:
+    //
+    // - `Cell<T>` - by `get()` (and so `T` must be `Copy`);
+    // - `RefCell<T>` - by `try_borrow()`, such that a value that is
+    //   currently mutably borrowed is not "truthy" (rather than panicking);
+    // - `Mutex<T>` - by `lock()`, which blocks, and so deadlocks if the
+    //   calling thread already holds the lock;
+    // - `RwLock<T>` - by `read()`, which blocks, and so may deadlock if the
+    //   calling thread already holds the lock;
+    //
+    // Poisoning is disregarded, since a panic in another thread cannot leave
+    // a value that is merely read here in an inconsistent state.
+
+    impl<T> Truthy for Cell<T>
+    where
+        T : Truthy + Copy,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            self.get().is_truthy()
+        }
+    }
+
+    impl<T> Truthy for &Cell<T>
+    where
+        T : Truthy + Copy,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            self.get().is_truthy()
+        }
+    }
+
+    impl<T> Truthy for RefCell<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            self.try_borrow().ok()?.is_truthy()
+        }
+    }
+
+    impl<T> Truthy for &RefCell<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for Mutex<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            self.lock().unwrap_or_else(PoisonError::into_inner).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for &Mutex<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for RwLock<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            self.read().unwrap_or_else(PoisonError::into_inner).is_truthy()
+        }
+    }
+
+    impl<T> Truthy for &RwLock<T>
+    where
+        T : Truthy + ?Sized,
+    {
+        fn is_truthy(&self) -> Option<bool> {
+            (**self).is_truthy()
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-IpAddr")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_IpAddr {
+    use super::Truthy;
+
+    use std::net::{
+        IpAddr,
+        Ipv4Addr,
+        Ipv6Addr,
+    };
+
+    // NOTE: unspecified addresses (`0.0.0.0`, `::`) are "falsey"
+
+    macro_rules! implement_Truthy_for_IpAddr {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl Truthy for $type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(!self.is_unspecified())
+                    }
+                }
+
+                impl Truthy for &$type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(!self.is_unspecified())
+                    }
+                }
+            )+
+        };
+    }
+
+    implement_Truthy_for_IpAddr!(IpAddr, Ipv4Addr, Ipv6Addr);
+}
+
+#[cfg(feature = "implement-Truthy-for-NonZero")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_NonZero {
+    use super::Truthy;
+
+    use std::num::{
+        NonZeroI128,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI8,
+        NonZeroIsize,
+        NonZeroU128,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU8,
+        NonZeroUsize,
+    };
+
+    macro_rules! implement_Truthy_for_NonZero {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl Truthy for $type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(true)
+                    }
+                }
+
+                impl Truthy for &$type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(true)
+                    }
+                }
+
+                impl Truthy for Option<$type> {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(self.is_some())
+                    }
+                }
+
+                impl Truthy for &Option<$type> {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(self.is_some())
+                    }
+                }
+            )+
+        };
+    }
+
+    implement_Truthy_for_NonZero!(
+        NonZeroI8,
+        NonZeroI16,
+        NonZeroI32,
+        NonZeroI64,
+        NonZeroI128,
+        NonZeroIsize,
+        NonZeroU8,
+        NonZeroU16,
+        NonZeroU32,
+        NonZeroU64,
+        NonZeroU128,
+        NonZeroUsize,
+    );
+}
+
+#[cfg(feature = "implement-Truthy-for-Path")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_Path {
+    use super::{
+        fs::path_is_truthy,
+        Truthy,
+    };
+
+    use std::path::{
+        Path,
+        PathBuf,
+    };
+
+    // NOTE: by the flag file convention, so the filesystem is consulted
+
+    impl Truthy for Path {
+        fn is_truthy(&self) -> Option<bool> {
+            path_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &Path {
+        fn is_truthy(&self) -> Option<bool> {
+            path_is_truthy(self)
+        }
+    }
+
+    impl Truthy for PathBuf {
+        fn is_truthy(&self) -> Option<bool> {
+            path_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &PathBuf {
+        fn is_truthy(&self) -> Option<bool> {
+            path_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-SocketAddr")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_SocketAddr {
+    use super::Truthy;
+
+    use std::net::{
+        SocketAddr,
+        SocketAddrV4,
+        SocketAddrV6,
+    };
+
+    // NOTE: addresses whose IP address is unspecified (`0.0.0.0`, `::`) are
+    // "falsey", regardless of port
+
+    macro_rules! implement_Truthy_for_SocketAddr {
+        ($($type:ty),+ $(,)?) => {
+            $(
+                impl Truthy for $type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(!self.ip().is_unspecified())
+                    }
+                }
+
+                impl Truthy for &$type {
+                    fn is_truthy(&self) -> Option<bool> {
+                        Some(!self.ip().is_unspecified())
+                    }
+                }
+            )+
+        };
+    }
+
+    implement_Truthy_for_SocketAddr!(SocketAddr, SocketAddrV4, SocketAddrV6);
+}
+
+#[cfg(feature = "http")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_StatusCode {
+    use super::Truthy;
+
+    use ::http::StatusCode;
+
+    // NOTE: informational (1xx) and redirection (3xx) statuses are neither
+    // success nor failure, and so are not "truthy"
+
+    fn status_is_truthy_(status : &StatusCode) -> Option<bool> {
+        if status.is_success() {
+            Some(true)
+        } else if status.is_client_error() || status.is_server_error() {
+            Some(false)
+        } else {
+            None
+        }
+    }
+
+    impl Truthy for StatusCode {
+        fn is_truthy(&self) -> Option<bool> {
+            status_is_truthy_(self)
+        }
+    }
+
+    impl Truthy for &StatusCode {
+        fn is_truthy(&self) -> Option<bool> {
+            status_is_truthy_(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-str")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_str {
+    use super::Truthy;
+
+    impl Truthy for &str {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &&str {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(any(feature = "implement-Truthy-for-str", feature = "implement-Truthy-for-String"))]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_Cow {
+    use super::Truthy;
+
+    use std::borrow::Cow;
+
+    impl Truthy for Cow<'_, str> {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &Cow<'_, str> {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-CStr")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_CStr {
+    use super::Truthy;
+    use std::ffi::CStr;
+
+    impl Truthy for CStr {
+        fn is_truthy(&self) -> Option<bool> {
+            super::c_string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &CStr {
+        fn is_truthy(&self) -> Option<bool> {
+            super::c_string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-CString")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_CString {
+    use super::Truthy;
+    use std::ffi::CString;
+
+    impl Truthy for CString {
+        fn is_truthy(&self) -> Option<bool> {
+            super::c_string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &CString {
+        fn is_truthy(&self) -> Option<bool> {
+            super::c_string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-OsStr")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_OsStr {
+    use super::Truthy;
+    use std::ffi::OsStr;
+
+    impl Truthy for OsStr {
+        fn is_truthy(&self) -> Option<bool> {
+            super::os_string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &OsStr {
+        fn is_truthy(&self) -> Option<bool> {
+            super::os_string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-OsString")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_OsString {
+    use super::Truthy;
+    use std::ffi::OsString;
+
+    impl Truthy for OsString {
+        fn is_truthy(&self) -> Option<bool> {
+            super::os_string_is_truthy(self)
+        }
+    }
+
+    impl Truthy for &OsString {
+        fn is_truthy(&self) -> Option<bool> {
+            super::os_string_is_truthy(self)
+        }
+    }
+}
+
+#[cfg(feature = "implement-Truthy-for-String")]
+#[allow(non_snake_case)]
+mod implement_Truthy_for_String {
+    use super::Truthy;
+
+    impl Truthy for String {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self.as_str())
+        }
+    }
+
+    impl Truthy for &String {
+        fn is_truthy(&self) -> Option<bool> {
+            super::string_is_truthy(self.as_str())
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::bool_assert_comparison)]
+    #![allow(clippy::needless_borrow)]
+
+
+    mod test_API {
+        #![allow(non_snake_case)]
+
+        #[allow(unused_imports)]
+        use super::super::{
+            first_determinate,
+            first_determinate_with,
+            string_is_falsey,
+            string_is_falsey_const,
+            string_is_truey,
+            string_is_truey_const,
+            string_is_truthy,
+            string_is_truthy_const,
+            string_is_truthy_with,
+            Terms,
+        };
+
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_string_is_falsey_1() {
+            assert_eq!(false, string_is_falsey(""));
+
+            assert_eq!(true, string_is_falsey("0"));
+            assert_eq!(true, string_is_falsey("false"));
+            assert_eq!(true, string_is_falsey(" FALSE"));
+            assert_eq!(true, string_is_falsey("False"));
+            assert_eq!(true, string_is_falsey("FaLSe"));
+            assert_eq!(true, string_is_falsey("no"));
+            assert_eq!(true, string_is_falsey("No "));
+            assert_eq!(true, string_is_falsey("NO"));
+            assert_eq!(true, string_is_falsey(" Off "));
+            assert_eq!(true, string_is_falsey("off"));
+            assert_eq!(true, string_is_falsey("OFF"));
+
+            assert_eq!(false, string_is_falsey("1"));
+            assert_eq!(false, string_is_falsey("true"));
+            assert_eq!(false, string_is_falsey("TRUE"));
+            assert_eq!(false, string_is_falsey("True"));
+            assert_eq!(false, string_is_falsey("tRuE"));
+            assert_eq!(false, string_is_falsey("yes"));
+            assert_eq!(false, string_is_falsey(" YES"));
+            assert_eq!(false, string_is_falsey("Yes   "));
+            assert_eq!(false, string_is_falsey("yEs"));
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_string_is_truey_1() {
+            assert_eq!(false, string_is_truey(""));
+
+            assert_eq!(false, string_is_truey("0"));
+            assert_eq!(false, string_is_truey("false"));
+            assert_eq!(false, string_is_truey(" FALSE"));
+            assert_eq!(false, string_is_truey("False"));
+            assert_eq!(false, string_is_truey("FaLSe"));
+            assert_eq!(false, string_is_truey("no"));
+            assert_eq!(false, string_is_truey("No "));
+            assert_eq!(false, string_is_truey("NO"));
+            assert_eq!(false, string_is_truey(" Off "));
+            assert_eq!(false, string_is_truey("off"));
+            assert_eq!(false, string_is_truey("OFF"));
+
+            assert_eq!(true, string_is_truey("1"));
+            assert_eq!(true, string_is_truey("true"));
+            assert_eq!(true, string_is_truey("TRUE"));
+            assert_eq!(true, string_is_truey("True"));
+            assert_eq!(true, string_is_truey("tRuE"));
+            assert_eq!(true, string_is_truey("yes"));
+            assert_eq!(true, string_is_truey(" YES"));
+            assert_eq!(true, string_is_truey("Yes   "));
+            assert_eq!(true, string_is_truey("yEs"));
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_string_is_truthy_1() {
+            assert_eq!(None, string_is_truthy(""));
+
+            assert_eq!(None, string_is_truthy("Nyet"));
+            assert_eq!(None, string_is_truthy("NYET"));
+            assert_eq!(None, string_is_truthy("nyET"));
+            assert_eq!(None, string_is_truthy("nope"));
+            assert_eq!(None, string_is_truthy("Nope"));
+            assert_eq!(None, string_is_truthy("NOPE"));
+
+            assert_eq!(None, string_is_truthy("Da"));
+            assert_eq!(None, string_is_truthy("DA"));
+            assert_eq!(None, string_is_truthy("dA"));
+            assert_eq!(None, string_is_truthy("yup"));
+            assert_eq!(None, string_is_truthy("Yup"));
+            assert_eq!(None, string_is_truthy("yUp"));
+
+            assert_eq!(Some(false), string_is_truthy("0"));
+            assert_eq!(Some(false), string_is_truthy("false"));
+            assert_eq!(Some(false), string_is_truthy(" FALSE"));
+            assert_eq!(Some(false), string_is_truthy("False"));
+            assert_eq!(Some(false), string_is_truthy("FaLSe"));
+            assert_eq!(Some(false), string_is_truthy("no"));
+            assert_eq!(Some(false), string_is_truthy("No "));
+            assert_eq!(Some(false), string_is_truthy("NO"));
+            assert_eq!(Some(false), string_is_truthy(" Off "));
+            assert_eq!(Some(false), string_is_truthy("off"));
+            assert_eq!(Some(false), string_is_truthy("OFF"));
+
+            assert_eq!(Some(true), string_is_truthy("1"));
+            assert_eq!(Some(true), string_is_truthy("true"));
+            assert_eq!(Some(true), string_is_truthy("TRUE"));
+            assert_eq!(Some(true), string_is_truthy("True"));
+            assert_eq!(Some(true), string_is_truthy("tRuE"));
+            assert_eq!(Some(true), string_is_truthy("yes"));
+            assert_eq!(Some(true), string_is_truthy(" YES"));
+            assert_eq!(Some(true), string_is_truthy("Yes   "));
+            assert_eq!(Some(true), string_is_truthy("yEs"));
+        }
+
+        #[test]
+        fn TEST_string_is_truthy_with_1() {
+            #[rustfmt::skip]
+            const TRUEY_PRECISE_STRINGS : &[&str] = &[
+                "Da",
+                "YUP",
+                "Yup",
+            ];
+            #[rustfmt::skip]
+            const TRUEY_LOWERCASE_STRINGS : &[&str] = &[
+                "da",
+                "yup",
+            ];
+            #[rustfmt::skip]
+            const FALSEY_PRECISE_STRINGS : &[&str] = &[
+                "Nyet",
+                "Nope",
+            ];
+            #[rustfmt::skip]
+            const FALSEY_LOWERCASE_STRINGS : &[&str] = &[
+                "nyet",
+                "nope",
+            ];
+
+            let terms = Terms::Strings {
+                falsey_precise_strings :   FALSEY_PRECISE_STRINGS,
+                falsey_lowercase_strings : FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    TRUEY_PRECISE_STRINGS,
+                truey_lowercase_strings :  TRUEY_LOWERCASE_STRINGS,
+            };
+
+            assert_eq!(Some(false), string_is_truthy_with("Nyet", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("NYET", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("nyET", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("nope", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("Nope", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("NOPE", terms.clone()));
+
+            assert_eq!(Some(true), string_is_truthy_with("Da", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("DA", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("dA", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("yup", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("Yup", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("yUp", terms.clone()));
+
+            assert_eq!(None, string_is_truthy_with("", terms.clone()));
+
+            assert_eq!(None, string_is_truthy_with("0", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("false", terms.clone()));
+            assert_eq!(None, string_is_truthy_with(" FALSE", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("False", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("FaLSe", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("no", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("No ", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("NO", terms.clone()));
+            assert_eq!(None, string_is_truthy_with(" Off ", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("off", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("OFF", terms.clone()));
+
+            assert_eq!(None, string_is_truthy_with("1", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("true", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("TRUE", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("True", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("tRuE", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
+            assert_eq!(None, string_is_truthy_with(" YES", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("Yes   ", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("yEs", terms.clone()));
         }
-    }
 
-    impl Truthy for &&str {
-        fn is_truthy(&self) -> Option<bool> {
-            super::string_is_truthy(*self)
+        #[test]
+        fn TEST_string_is_truthy_with_SortedStrings() {
+            assert!(Terms::sorted_strings(&["b", "a"], &[], &[], &[]).is_none());
+            assert!(Terms::sorted_strings(&[], &[], &["a", "a"], &[]).is_none());
+
+            let terms = Terms::sorted_strings(
+                &["NYET", "Nope", "Nyet"],
+                &["nope", "nyet"],
+                &["DA", "Da", "YUP", "Yup"],
+                &["da", "yup"],
+            )
+            .unwrap();
+
+            for s in ["NYET", "Nope", " Nyet", "nOpE", "nyet"] {
+                assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+            }
+
+            for s in ["DA", "Da", "YUP ", "Yup", "dA", "yuP"] {
+                assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+            }
+
+            for s in ["", "no", "yes", "Daa"] {
+                assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+            }
         }
-    }
-}
 
-#[cfg(feature = "implement-Truthy-for-CStr")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_CStr {
-    use super::Truthy;
-    use std::ffi::CStr;
+        #[test]
+        fn TEST_first_determinate_with() {
+            let terms = Terms::Strings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : &["nein"],
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  &["ja"],
+            };
 
-    impl Truthy for CStr {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                Err(_) => None,
-                Ok(c) => {
-                    super::string_is_truthy(c)
-                }
+            assert_eq!(None, first_determinate_with(Vec::<&str>::new(), terms.clone()));
+            assert_eq!(None, first_determinate_with(["", "vielleicht"], terms.clone()));
+            assert_eq!(Some(true), first_determinate_with(["Ja", "nein"], terms.clone()));
+            assert_eq!(Some(false), first_determinate_with(["", "egal", " NEIN ", "ja"], terms.clone()));
+            assert_eq!(Some(true), first_determinate_with(vec!["?".to_owned(), "ja".to_owned()], terms.clone()));
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_first_determinate() {
+            assert_eq!(None, first_determinate(["maybe", ""]));
+            assert_eq!(Some(false), first_determinate(["maybe", "off", "on"]));
+            assert_eq!(Some(true), first_determinate([String::from("yes")]));
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_truthy_MACRO() {
+            const T1 : bool = crate::truthy!("yes");
+            const T2 : bool = crate::truthy!(" TRUE ");
+            const T3 : bool = crate::truthy!("oN");
+            const F1 : bool = crate::truthy!("0");
+            const F2 : bool = crate::truthy!("\tFaLsE\n");
+            const F3 : bool = crate::truthy!("Off");
+
+            assert_eq!(true, T1);
+            assert_eq!(true, T2);
+            assert_eq!(true, T3);
+            assert_eq!(false, F1);
+            assert_eq!(false, F2);
+            assert_eq!(false, F3);
+
+            assert_eq!(true, crate::truthy!("1"));
+            assert_eq!(false, crate::truthy!("NO"));
+        }
+
+        #[test]
+        fn TEST_const_VARIANTS_MATCH_NON_const_FUNCTIONS() {
+            for s in [
+                "", "0", "1", "no", "No", "NO", "nO", " yes ", "True", "tRuE", "OFF", "on", "maybe", "yess", "n",
+            ] {
+                assert_eq!(string_is_falsey(s), string_is_falsey_const(s), "'{s}'");
+                assert_eq!(string_is_truey(s), string_is_truey_const(s), "'{s}'");
+                assert_eq!(string_is_truthy(s), string_is_truthy_const(s), "'{s}'");
             }
         }
-    }
 
-    impl Truthy for &CStr {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                Err(_) => None,
-                Ok(c) => {
-                    super::string_is_truthy(c)
-                }
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_const_VARIANTS_IN_CONST_ITEMS() {
+            const F : bool = string_is_falsey_const("OFF");
+            const T : bool = string_is_truey_const(" yes");
+            const U : Option<bool> = string_is_truthy_const("unknown");
+
+            static DEFAULTS : [Option<bool>; 3] = [
+                string_is_truthy_const("true"),
+                string_is_truthy_const("0"),
+                string_is_truthy_const(""),
+            ];
+
+            assert_eq!(true, F);
+            assert_eq!(true, T);
+            assert_eq!(None, U);
+            assert_eq!([Some(true), Some(false), None], DEFAULTS);
+        }
+
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_bytes_are_truthy() {
+            use super::super::{
+                bytes_are_truthy,
+                bytes_are_truthy_with_encoding,
+                ByteEncoding,
+            };
+
+            assert_eq!(None, bytes_are_truthy(b""));
+            assert_eq!(Some(false), bytes_are_truthy(b" no "));
+            assert_eq!(Some(true), bytes_are_truthy(b"TRUE"));
+            assert_eq!(None, bytes_are_truthy(b"TRUE\xFF"));
+            assert_eq!(Some(true), bytes_are_truthy("yes\u{a0}".as_bytes()));
+
+            for encoding in [ByteEncoding::Utf8, ByteEncoding::Ascii, ByteEncoding::Latin1] {
+                assert_eq!(Some(true), bytes_are_truthy_with_encoding(b"YES", encoding), "{encoding:?}");
+                assert_eq!(Some(false), bytes_are_truthy_with_encoding(b"\toff", encoding), "{encoding:?}");
+                assert_eq!(None, bytes_are_truthy_with_encoding(b"maybe", encoding), "{encoding:?}");
             }
+
+            assert_eq!(Some(true), bytes_are_truthy_with_encoding("yes\u{a0}".as_bytes(), ByteEncoding::Utf8));
+            assert_eq!(None, bytes_are_truthy_with_encoding("yes\u{a0}".as_bytes(), ByteEncoding::Ascii));
+            assert_eq!(None, bytes_are_truthy_with_encoding(b"yes\xA0", ByteEncoding::Ascii));
+            assert_eq!(Some(true), bytes_are_truthy_with_encoding(b"yes\xA0", ByteEncoding::Latin1));
+            assert_eq!(None, bytes_are_truthy_with_encoding(b"y\xE9s", ByteEncoding::Latin1));
         }
-    }
-}
 
-#[cfg(feature = "implement-Truthy-for-CString")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_CString {
-    use super::Truthy;
-    use std::ffi::CString;
+        #[test]
+        fn TEST_exit_code_is_truey() {
+            use super::super::{
+                exit_code_is_truey,
+                exit_code_string_is_truthy,
+            };
 
-    impl Truthy for CString {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                Err(_) => None,
-                Ok(c) => {
-                    super::string_is_truthy(c)
-                }
+            const SUCCESS : bool = exit_code_is_truey(0);
+
+            assert_eq!(true, SUCCESS);
+            assert_eq!(false, exit_code_is_truey(1));
+            assert_eq!(false, exit_code_is_truey(-1));
+            assert_eq!(false, exit_code_is_truey(255));
+
+            assert_eq!(Some(true), exit_code_string_is_truthy("0"));
+            assert_eq!(Some(true), exit_code_string_is_truthy(" 0\n"));
+            assert_eq!(Some(true), exit_code_string_is_truthy("+0"));
+            assert_eq!(Some(false), exit_code_string_is_truthy("1"));
+            assert_eq!(Some(false), exit_code_string_is_truthy("130"));
+            assert_eq!(Some(false), exit_code_string_is_truthy("-2"));
+
+            for s in ["", "true", "yes", "0x0", "1.0", "99999999999"] {
+                assert_eq!(None, exit_code_string_is_truthy(s), "'{s}'");
             }
         }
-    }
 
-    impl Truthy for &CString {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                Err(_) => None,
-                Ok(c) => {
-                    super::string_is_truthy(c)
-                }
+        #[cfg(feature = "tracing")]
+        #[test]
+        fn TEST_TracedInput() {
+            use super::super::TracedInput;
+
+            assert_eq!("\"\"", TracedInput("").to_string());
+            assert_eq!("\"yes\"", TracedInput("yes").to_string());
+            assert_eq!("\"0123456789abcdef\"", TracedInput("0123456789abcdef").to_string());
+            assert_eq!("\"0123456789abcdef\"... (17 bytes)", TracedInput("0123456789abcdefg").to_string());
+            assert_eq!("\"ÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇ\"... (40 bytes)", TracedInput(&"Ç".repeat(20)).to_string());
+        }
+
+        #[test]
+        fn TEST_duration_string_is_truthy() {
+            use super::super::duration_string_is_truthy;
+
+            for s in ["0", "0s", "0ms", " 0.0 ms ", "00h", "0.", ".0", "0 d", "0\u{b5}s", "0ns"] {
+                assert_eq!(Some(false), duration_string_is_truthy(s), "'{s}'");
+            }
+
+            for s in ["1", "30s", "1.5m", "0.001s", ".5h", "100 us", "2d", "10\u{3bc}s"] {
+                assert_eq!(Some(true), duration_string_is_truthy(s), "'{s}'");
+            }
+
+            for s in ["", "s", ".", "..", "0.0.0", "-1s", "1x", "1 sec", "0s0", "soon", "1e3"] {
+                assert_eq!(None, duration_string_is_truthy(s), "'{s}'");
             }
         }
-    }
-}
 
-#[cfg(feature = "implement-Truthy-for-OsStr")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_OsStr {
-    use super::Truthy;
-    use std::ffi::OsStr;
+        #[cfg(to_be_stock_default)]
+        #[test]
+        fn TEST_c_string_is_truthy() {
+            use super::super::c_string_is_truthy;
 
-    impl Truthy for OsStr {
-        fn is_truthy(&self) -> Option<bool> {
-            super::os_string_is_truthy(self)
+            use std::ffi::CStr;
+
+            assert_eq!(None, c_string_is_truthy(c""));
+            assert_eq!(Some(false), c_string_is_truthy(c" no "));
+            assert_eq!(Some(true), c_string_is_truthy(c"TRUE"));
+            assert_eq!(Some(true), c_string_is_truthy(CStr::from_bytes_until_nul(b"on\0ignored").unwrap()));
+            assert_eq!(None, c_string_is_truthy(c"yes\xFF"));
         }
-    }
 
-    impl Truthy for &OsStr {
-        fn is_truthy(&self) -> Option<bool> {
-            super::os_string_is_truthy(*self)
+        #[cfg(all(to_be_stock_default, unix))]
+        #[test]
+        fn TEST_os_string_is_truthy() {
+            use super::super::os_string_is_truthy;
+
+            use std::{
+                ffi::OsStr,
+                os::unix::ffi::OsStrExt as _,
+            };
+
+            assert_eq!(None, os_string_is_truthy(OsStr::new("")));
+            assert_eq!(Some(false), os_string_is_truthy(OsStr::new(" no ")));
+            assert_eq!(Some(true), os_string_is_truthy(OsStr::new("TRUE")));
+            assert_eq!(None, os_string_is_truthy(OsStr::from_bytes(b"yes\xFF")));
+            assert_eq!(None, os_string_is_truthy(OsStr::from_bytes(b"\xC0\x80")));
+            assert_eq!(None, os_string_is_truthy(OsStr::from_bytes(b"\xFF yes")));
+
+            // the ASCII fast path, and the fall-back for non-ASCII content
+
+            assert_eq!(Some(true), os_string_is_truthy(OsStr::new("\t yes \r\n")));
+            assert_eq!(Some(true), os_string_is_truthy(OsStr::new("\u{a0}yes\u{a0}")));
+            assert_eq!(None, os_string_is_truthy(OsStr::new("yés")));
         }
-    }
-}
 
-#[cfg(feature = "implement-Truthy-for-OsString")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_OsString {
-    use super::Truthy;
-    use std::ffi::OsString;
+        #[cfg(unix)]
+        #[test]
+        fn TEST_os_string_is_truthy_with() {
+            use super::super::{
+                os_string_is_truthy_with,
+                Terms,
+            };
 
-    impl Truthy for OsString {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                None => None,
-                Some(c) => {
-                    super::string_is_truthy(c)
-                }
+            use std::{
+                ffi::OsStr,
+                os::unix::ffi::OsStrExt as _,
+            };
+
+            let terms = Terms::Strings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : &["nein"],
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  &["ja", "jä"],
+            };
+
+            assert_eq!(Some(true), os_string_is_truthy_with(OsStr::new(" JA\n"), terms.clone()));
+            assert_eq!(Some(true), os_string_is_truthy_with(OsStr::new("jä"), terms.clone()));
+            assert_eq!(Some(false), os_string_is_truthy_with(OsStr::new("Nein"), terms.clone()));
+            assert_eq!(None, os_string_is_truthy_with(OsStr::new("yes"), terms.clone()));
+            assert_eq!(None, os_string_is_truthy_with(OsStr::from_bytes(b"j\xE4"), terms));
+        }
+
+        #[test]
+        fn TEST_FoldedString_MATCHES_fold_() {
+            use super::super::{
+                fold_,
+                FoldedString,
+                FOLD_BUFFER_SIZE,
+            };
+
+            for s in [
+                "",
+                "TRUE",
+                "yEs",
+                "0123456789abcdefghijklmnopqrstuv",
+                "0123456789ABCDEFGHIJKLMNOPQRSTUVW",
+                "Ça Va",
+                "ＹＥＳ",
+                "ÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇ",
+                "ÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇÇ",
+            ] {
+                let l = FoldedString::new(s);
+
+                assert_eq!(fold_(s), l.as_str(), "'{s}'");
+                assert_eq!(
+                    fold_(s).len() <= FOLD_BUFFER_SIZE,
+                    matches!(l, FoldedString::Inline { .. }),
+                    "'{s}'"
+                );
             }
         }
-    }
 
-    impl Truthy for &OsString {
-        fn is_truthy(&self) -> Option<bool> {
-            match self.to_str() {
-                None => None,
-                Some(c) => {
-                    super::string_is_truthy(c)
+        #[cfg(all(feature = "phf", to_be_stock_default))]
+        #[test]
+        fn TEST_STOCK_TERMS_BY_FOLDED_FORM_MATCHES_STOCK_TABLES() {
+            use super::super::{
+                constants,
+                fold_,
+            };
+
+            for (truey, strings) in [
+                (false, &constants::FALSEY_PRECISE_STRINGS[..]),
+                (false, &constants::FALSEY_LOWERCASE_STRINGS[..]),
+                (true, &constants::TRUEY_PRECISE_STRINGS[..]),
+                (true, &constants::TRUEY_LOWERCASE_STRINGS[..]),
+            ] {
+                for s in strings {
+                    assert_eq!(Some(&truey), constants::STOCK_TERMS_BY_FOLDED_FORM.get(fold_(s).as_str()), "'{s}'");
                 }
             }
+
+            for key in constants::STOCK_TERMS_BY_FOLDED_FORM.keys() {
+                assert!(key.len() <= constants::STOCK_TERM_MAX_LEN, "'{key}'");
+            }
         }
-    }
-}
 
-#[cfg(feature = "implement-Truthy-for-String")]
-#[allow(non_snake_case)]
-mod implement_Truthy_for_String {
-    use super::Truthy;
+        #[cfg(all(feature = "phf", to_be_stock_default))]
+        #[test]
+        fn TEST_string_is_truthy_PHF_LONG_AND_NON_ASCII_STRINGS() {
+            assert_eq!(None, string_is_truthy("falsey"));
+            assert_eq!(None, string_is_truthy("trueee"));
+            assert_eq!(None, string_is_truthy("tru\u{e9}"));
+            assert_eq!(Some(false), string_is_truthy(" \tFALSE\n"));
+            assert_eq!(true, string_is_truey(" YeS "));
+            assert_eq!(false, string_is_falsey(" YeS "));
+        }
 
-    impl Truthy for String {
-        fn is_truthy(&self) -> Option<bool> {
-            super::string_is_truthy(self.as_str())
+        #[cfg(feature = "default-locale-fr")]
+        #[test]
+        fn TEST_string_is_truthy_DEFAULT_LOCALE_fr() {
+            assert_eq!(Some(false), string_is_truthy("non"));
+            assert_eq!(Some(false), string_is_truthy(" Faux"));
+            assert_eq!(Some(true), string_is_truthy("OUI"));
+            assert_eq!(Some(true), string_is_truthy("vrai"));
+            assert_eq!(None, string_is_truthy("yes"));
+
+            assert_eq!(true, string_is_falsey("NON"));
+            assert_eq!(true, string_is_truey("Oui"));
+
+            assert_eq!(Some(true), string_is_truthy_with("oui", Terms::Default));
         }
-    }
 
-    impl Truthy for &String {
-        fn is_truthy(&self) -> Option<bool> {
-            super::string_is_truthy(self.as_str())
+        #[cfg(feature = "default-preset-yaml11")]
+        #[test]
+        fn TEST_string_is_truthy_DEFAULT_PRESET_yaml11() {
+            assert_eq!(Some(false), string_is_truthy("n"));
+            assert_eq!(Some(false), string_is_truthy("Off"));
+            assert_eq!(Some(true), string_is_truthy(" Y "));
+            assert_eq!(Some(true), string_is_truthy("YES"));
+            assert_eq!(None, string_is_truthy("0"));
+            assert_eq!(None, string_is_truthy("yEs"));
+
+            assert_eq!(true, string_is_falsey("NO"));
+            assert_eq!(true, string_is_truey("y"));
+
+            assert_eq!(Some(true), string_is_truthy_with("on", Terms::Default));
         }
     }
-}
 
+    #[cfg(feature = "arbitrary")]
+    mod test_Arbitrary {
+        #![allow(non_snake_case)]
 
-#[cfg(test)]
-mod tests {
-    #![allow(non_snake_case)]
+        use super::super::{
+            preset::Preset,
+            ByteEncoding,
+            CompiledTerms,
+            Truthiness,
+        };
+
+        use arbitrary::{
+            Arbitrary as _,
+            Unstructured,
+        };
 
 
-    mod test_API {
+        #[test]
+        fn TEST_Arbitrary() {
+            let data : Vec<u8> = (0..4096u32).map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8).collect();
+            let mut u = Unstructured::new(&data);
+
+            let mut truthinesses = Vec::new();
+
+            for _ in 0..16 {
+                truthinesses.push(Truthiness::arbitrary(&mut u).unwrap());
+
+                let _ = ByteEncoding::arbitrary(&mut u).unwrap();
+                let _ = Preset::arbitrary(&mut u).unwrap();
+            }
+
+            assert!(truthinesses.contains(&Truthiness::True) || truthinesses.contains(&Truthiness::False));
+
+            let terms = CompiledTerms::arbitrary(&mut u).unwrap();
+
+            // whatever the terms, classification is total and consistent
+
+            for s in ["", "yes", "NO", "maybe", " true "] {
+                let r = terms.string_is_truthy(s);
+
+                assert_eq!(Some(false) == r, terms.string_is_falsey(s));
+                assert_eq!(Some(true) == r, terms.string_is_truey(s));
+            }
+
+            assert!(CompiledTerms::arbitrary(&mut Unstructured::new(&[])).is_ok());
+        }
+    }
+
+    #[cfg(feature = "quickcheck")]
+    mod test_quickcheck {
         #![allow(non_snake_case)]
 
         use super::super::{
-            string_is_falsey,
-            string_is_truey,
-            string_is_truthy,
-            string_is_truthy_with,
-            Terms,
+            ByteEncoding,
+            CompiledTerms,
+            Truthiness,
         };
 
+        use quickcheck::quickcheck;
 
-        #[test]
-        fn TEST_string_is_falsey_1() {
-            assert_eq!(false, string_is_falsey(""));
 
-            assert_eq!(true, string_is_falsey("0"));
-            assert_eq!(true, string_is_falsey("false"));
-            assert_eq!(true, string_is_falsey(" FALSE"));
-            assert_eq!(true, string_is_falsey("False"));
-            assert_eq!(true, string_is_falsey("FaLSe"));
-            assert_eq!(true, string_is_falsey("no"));
-            assert_eq!(true, string_is_falsey("No "));
-            assert_eq!(true, string_is_falsey("NO"));
-            assert_eq!(true, string_is_falsey(" Off "));
-            assert_eq!(true, string_is_falsey("off"));
-            assert_eq!(true, string_is_falsey("OFF"));
+        #[test]
+        fn TEST_CompiledTerms_CLASSIFICATION_IS_CONSISTENT() {
+            fn prop(
+                terms : CompiledTerms,
+                s : String,
+            ) -> bool {
+                let r = terms.string_is_truthy(&s);
+
+                (Some(false) == r) == terms.string_is_falsey(&s) && (Some(true) == r) == terms.string_is_truey(&s)
+            }
 
-            assert_eq!(false, string_is_falsey("1"));
-            assert_eq!(false, string_is_falsey("true"));
-            assert_eq!(false, string_is_falsey("TRUE"));
-            assert_eq!(false, string_is_falsey("True"));
-            assert_eq!(false, string_is_falsey("tRuE"));
-            assert_eq!(false, string_is_falsey("yes"));
-            assert_eq!(false, string_is_falsey(" YES"));
-            assert_eq!(false, string_is_falsey("Yes   "));
-            assert_eq!(false, string_is_falsey("yEs"));
+            quickcheck(prop as fn(CompiledTerms, String) -> bool);
         }
 
         #[test]
-        fn TEST_string_is_truey_1() {
-            assert_eq!(false, string_is_truey(""));
+        fn TEST_Arbitrary_ENUMS() {
+            fn prop(
+                _truthiness : Truthiness,
+                _encoding : ByteEncoding,
+            ) -> bool {
+                true
+            }
 
-            assert_eq!(false, string_is_truey("0"));
-            assert_eq!(false, string_is_truey("false"));
-            assert_eq!(false, string_is_truey(" FALSE"));
-            assert_eq!(false, string_is_truey("False"));
-            assert_eq!(false, string_is_truey("FaLSe"));
-            assert_eq!(false, string_is_truey("no"));
-            assert_eq!(false, string_is_truey("No "));
-            assert_eq!(false, string_is_truey("NO"));
-            assert_eq!(false, string_is_truey(" Off "));
-            assert_eq!(false, string_is_truey("off"));
-            assert_eq!(false, string_is_truey("OFF"));
+            quickcheck(prop as fn(Truthiness, ByteEncoding) -> bool);
+        }
+    }
 
-            assert_eq!(true, string_is_truey("1"));
-            assert_eq!(true, string_is_truey("true"));
-            assert_eq!(true, string_is_truey("TRUE"));
-            assert_eq!(true, string_is_truey("True"));
-            assert_eq!(true, string_is_truey("tRuE"));
-            assert_eq!(true, string_is_truey("yes"));
-            assert_eq!(true, string_is_truey(" YES"));
-            assert_eq!(true, string_is_truey("Yes   "));
-            assert_eq!(true, string_is_truey("yEs"));
+    #[cfg(feature = "derive")]
+    mod test_derive {
+        #![allow(non_snake_case)]
+        #![allow(dead_code)]
+
+        use super::super::Truthy;
+
+
+        #[derive(Truthy)]
+        enum Status {
+            #[truthy(true)]
+            Enabled,
+            #[truthy(false)]
+            Disabled,
+            #[truthy(unknown)]
+            Unknown,
         }
 
-        #[test]
-        fn TEST_string_is_truthy_1() {
-            assert_eq!(None, string_is_truthy(""));
+        #[derive(Truthy)]
+        enum Answer<T> {
+            #[truthy(true)]
+            Yes(T),
+            #[truthy(false)]
+            No {
+                reason : T,
+            },
+            #[truthy(unknown)]
+            Maybe,
+        }
 
-            assert_eq!(None, string_is_truthy("Nyet"));
-            assert_eq!(None, string_is_truthy("NYET"));
-            assert_eq!(None, string_is_truthy("nyET"));
-            assert_eq!(None, string_is_truthy("nope"));
-            assert_eq!(None, string_is_truthy("Nope"));
-            assert_eq!(None, string_is_truthy("NOPE"));
+        #[derive(Truthy)]
+        enum Never {}
 
-            assert_eq!(None, string_is_truthy("Da"));
-            assert_eq!(None, string_is_truthy("DA"));
-            assert_eq!(None, string_is_truthy("dA"));
-            assert_eq!(None, string_is_truthy("yup"));
-            assert_eq!(None, string_is_truthy("Yup"));
-            assert_eq!(None, string_is_truthy("yUp"));
+        #[derive(Truthy)]
+        struct Setting {
+            name :      &'static str,
+            #[truthy]
+            raw_value : String,
+        }
 
-            assert_eq!(Some(false), string_is_truthy("0"));
-            assert_eq!(Some(false), string_is_truthy("false"));
-            assert_eq!(Some(false), string_is_truthy(" FALSE"));
-            assert_eq!(Some(false), string_is_truthy("False"));
-            assert_eq!(Some(false), string_is_truthy("FaLSe"));
-            assert_eq!(Some(false), string_is_truthy("no"));
-            assert_eq!(Some(false), string_is_truthy("No "));
-            assert_eq!(Some(false), string_is_truthy("NO"));
-            assert_eq!(Some(false), string_is_truthy(" Off "));
-            assert_eq!(Some(false), string_is_truthy("off"));
-            assert_eq!(Some(false), string_is_truthy("OFF"));
+        #[derive(Truthy)]
+        struct Wrapper<'a>(u32, #[truthy] &'a str);
 
-            assert_eq!(Some(true), string_is_truthy("1"));
-            assert_eq!(Some(true), string_is_truthy("true"));
-            assert_eq!(Some(true), string_is_truthy("TRUE"));
-            assert_eq!(Some(true), string_is_truthy("True"));
-            assert_eq!(Some(true), string_is_truthy("tRuE"));
-            assert_eq!(Some(true), string_is_truthy("yes"));
-            assert_eq!(Some(true), string_is_truthy(" YES"));
-            assert_eq!(Some(true), string_is_truthy("Yes   "));
-            assert_eq!(Some(true), string_is_truthy("yEs"));
+        #[derive(Truthy)]
+        struct Nested {
+            #[truthy]
+            status : Status,
         }
 
-        #[test]
-        fn TEST_string_is_truthy_with_1() {
-            #[rustfmt::skip]
-            const TRUEY_PRECISE_STRINGS : &[&str] = &[
-                "Da",
-                "YUP",
-                "Yup",
-            ];
-            #[rustfmt::skip]
-            const TRUEY_LOWERCASE_STRINGS : &[&str] = &[
-                "da",
-                "yup",
-            ];
-            #[rustfmt::skip]
-            const FALSEY_PRECISE_STRINGS : &[&str] = &[
-                "Nyet",
-                "Nope",
-            ];
-            #[rustfmt::skip]
-            const FALSEY_LOWERCASE_STRINGS : &[&str] = &[
-                "nyet",
-                "nope",
-            ];
 
-            let terms = Terms::Strings {
-                falsey_precise_strings :   FALSEY_PRECISE_STRINGS,
-                falsey_lowercase_strings : FALSEY_LOWERCASE_STRINGS,
-                truey_precise_strings :    TRUEY_PRECISE_STRINGS,
-                truey_lowercase_strings :  TRUEY_LOWERCASE_STRINGS,
-            };
+        #[test]
+        fn TEST_derive_Truthy_UNIT_VARIANTS() {
+            assert_eq!(Some(true), Status::Enabled.is_truthy());
+            assert_eq!(Some(false), Status::Disabled.is_truthy());
+            assert_eq!(None, Status::Unknown.is_truthy());
+
+            assert!(Status::Enabled.is_truey());
+            assert!(Status::Disabled.is_falsey());
+            assert!(!Status::Unknown.is_truey());
+            assert!(!Status::Unknown.is_falsey());
+        }
 
-            assert_eq!(Some(false), string_is_truthy_with("Nyet", terms.clone()));
-            assert_eq!(Some(false), string_is_truthy_with("NYET", terms.clone()));
-            assert_eq!(Some(false), string_is_truthy_with("nyET", terms.clone()));
-            assert_eq!(Some(false), string_is_truthy_with("nope", terms.clone()));
-            assert_eq!(Some(false), string_is_truthy_with("Nope", terms.clone()));
-            assert_eq!(Some(false), string_is_truthy_with("NOPE", terms.clone()));
+        #[test]
+        fn TEST_derive_Truthy_GENERIC_VARIANTS_WITH_FIELDS() {
+            assert_eq!(Some(true), Answer::Yes(1).is_truthy());
+            assert_eq!(Some(false), Answer::No { reason : "because" }.is_truthy());
+            assert_eq!(None, Answer::<()>::Maybe.is_truthy());
+        }
 
-            assert_eq!(Some(true), string_is_truthy_with("Da", terms.clone()));
-            assert_eq!(Some(true), string_is_truthy_with("DA", terms.clone()));
-            assert_eq!(Some(true), string_is_truthy_with("dA", terms.clone()));
-            assert_eq!(Some(true), string_is_truthy_with("yup", terms.clone()));
-            assert_eq!(Some(true), string_is_truthy_with("Yup", terms.clone()));
-            assert_eq!(Some(true), string_is_truthy_with("yUp", terms.clone()));
+        #[cfg(all(
+            to_be_stock_default,
+            feature = "implement-Truthy-for-str",
+            feature = "implement-Truthy-for-String",
+        ))]
+        #[test]
+        fn TEST_derive_Truthy_STRUCT_FIELD_DELEGATION() {
+            let setting = |raw_value : &str| Setting {
+                name :      "verbose",
+                raw_value : raw_value.into(),
+            };
 
-            assert_eq!(None, string_is_truthy_with("", terms.clone()));
+            assert_eq!(Some(true), setting("yes").is_truthy());
+            assert_eq!(Some(false), setting(" Off ").is_truthy());
+            assert_eq!(None, setting("maybe").is_truthy());
 
-            assert_eq!(None, string_is_truthy_with("0", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("false", terms.clone()));
-            assert_eq!(None, string_is_truthy_with(" FALSE", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("False", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("FaLSe", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("no", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("No ", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("NO", terms.clone()));
-            assert_eq!(None, string_is_truthy_with(" Off ", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("off", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("OFF", terms.clone()));
+            assert_eq!(Some(true), Wrapper(0, "TRUE").is_truthy());
+            assert_eq!(Some(false), Wrapper(1, "0").is_truthy());
+            assert_eq!(None, Wrapper(1, "").is_truthy());
+        }
 
-            assert_eq!(None, string_is_truthy_with("1", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("true", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("TRUE", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("True", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("tRuE", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
-            assert_eq!(None, string_is_truthy_with(" YES", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("Yes   ", terms.clone()));
-            assert_eq!(None, string_is_truthy_with("yEs", terms.clone()));
+        #[test]
+        fn TEST_derive_Truthy_STRUCT_NESTED_DELEGATION() {
+            assert_eq!(Some(true), Nested { status : Status::Enabled }.is_truthy());
+            assert_eq!(None, Nested { status : Status::Unknown }.is_truthy());
         }
     }
 
@@ -620,9 +3145,21 @@ mod tests {
         #![allow(non_snake_case)]
 
         #[cfg(any(
+            feature = "http",
             feature = "implement-Truthy-for-AsStr",
+            feature = "implement-Truthy-for-AtomicBool",
             feature = "implement-Truthy-for-String",
             feature = "implement-Truthy-for-bool",
+            feature = "implement-Truthy-for-bytes",
+            feature = "implement-Truthy-for-char",
+            feature = "implement-Truthy-for-Duration",
+            feature = "implement-Truthy-for-floats",
+            feature = "implement-Truthy-for-integers",
+            feature = "implement-Truthy-for-interior-mutability",
+            feature = "implement-Truthy-for-IpAddr",
+            feature = "implement-Truthy-for-NonZero",
+            feature = "implement-Truthy-for-Path",
+            feature = "implement-Truthy-for-SocketAddr",
             feature = "implement-Truthy-for-str",
         ))]
         use super::super::Truthy as _;
@@ -632,7 +3169,42 @@ mod tests {
         use std::ffi::OsString;
 
 
-        #[cfg(feature = "implement-Truthy-for-CString")]
+        #[cfg(all(
+            to_be_stock_default,
+            any(feature = "implement-Truthy-for-str", feature = "implement-Truthy-for-String"),
+        ))]
+        #[test]
+        fn TEST_Cow_Truthy() {
+            use std::borrow::Cow;
+
+            let borrowed : Cow<str> = Cow::Borrowed(" Yes ");
+            let owned : Cow<str> = Cow::Owned(String::from("OFF"));
+            let other : Cow<str> = Cow::Borrowed("maybe");
+
+            assert_eq!(Some(true), borrowed.is_truthy());
+            assert_eq!(Some(true), (&borrowed).is_truthy());
+            assert_eq!(true, borrowed.is_truey());
+
+            assert_eq!(Some(false), owned.is_truthy());
+            assert_eq!(Some(false), (&owned).is_truthy());
+            assert_eq!(true, owned.is_falsey());
+
+            assert_eq!(None, other.is_truthy());
+            assert_eq!(None, (&other).is_truthy());
+        }
+
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-CStr"))]
+        #[test]
+        fn TEST_CStr_Truthy() {
+            assert_eq!(None, c"".is_truthy());
+            assert_eq!(None, c"maybe".is_truthy());
+            assert_eq!(Some(false), c" Off ".is_truthy());
+            assert_eq!(Some(true), c"yEs".is_truthy());
+            assert_eq!(true, (&c"0").is_falsey());
+            assert_eq!(true, (&c"TRUE").is_truey());
+        }
+
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-CString"))]
         #[test]
         fn TEST_CString_Truthy() {
             // is_falsey
@@ -720,7 +3292,20 @@ mod tests {
             }
         }
 
-        #[cfg(feature = "implement-Truthy-for-OsString")]
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-OsStr"))]
+        #[test]
+        fn TEST_OsStr_Truthy() {
+            use std::ffi::OsStr;
+
+            assert_eq!(None, OsStr::new("").is_truthy());
+            assert_eq!(None, OsStr::new("maybe").is_truthy());
+            assert_eq!(Some(false), OsStr::new(" Off ").is_truthy());
+            assert_eq!(Some(true), OsStr::new("yEs").is_truthy());
+            assert_eq!(true, (&OsStr::new("0")).is_falsey());
+            assert_eq!(true, (&OsStr::new("TRUE")).is_truey());
+        }
+
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-OsString"))]
         #[test]
         fn TEST_OsString_Truthy() {
             // is_falsey
@@ -805,7 +3390,7 @@ mod tests {
             }
         }
 
-        #[cfg(feature = "implement-Truthy-for-String")]
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-String"))]
         #[test]
         fn TEST_String_Truthy() {
             // is_falsey
@@ -890,6 +3475,77 @@ mod tests {
             }
         }
 
+        #[cfg(feature = "implement-Truthy-for-AtomicBool")]
+        #[test]
+        fn TEST_AtomicBool_Truthy() {
+            use std::sync::atomic::{
+                AtomicBool,
+                Ordering,
+            };
+
+            let flag = AtomicBool::new(false);
+
+            assert_eq!(true, flag.is_falsey());
+            assert_eq!(false, flag.is_truey());
+            assert_eq!(Some(false), flag.is_truthy());
+            assert_eq!(Some(false), (&flag).is_truthy());
+
+            flag.store(true, Ordering::Relaxed);
+
+            assert_eq!(false, flag.is_falsey());
+            assert_eq!(true, flag.is_truey());
+            assert_eq!(Some(true), flag.is_truthy());
+            assert_eq!(Some(true), (&flag).is_truthy());
+        }
+
+        #[cfg(all(
+            to_be_stock_default,
+            feature = "implement-Truthy-for-bool",
+            feature = "implement-Truthy-for-String",
+        ))]
+        #[test]
+        fn TEST_smart_pointers_Truthy() {
+            use super::super::Truthy;
+
+            use std::{
+                rc::Rc,
+                sync::Arc,
+            };
+
+            assert_eq!(Some(true), Box::new(true).is_truthy());
+            assert_eq!(Some(false), Rc::new(String::from("no")).is_truthy());
+            assert_eq!(Some(true), Arc::new(String::from("YES")).is_truthy());
+            assert_eq!(None, Arc::new(Box::new(String::from("maybe"))).is_truthy());
+
+            let values : Vec<Box<dyn Truthy>> = vec![Box::new(false), Box::new(String::from("on")), Box::new(None::<bool>)];
+
+            assert_eq!(vec![Some(false), Some(true), None], values.iter().map(|v| v.is_truthy()).collect::<Vec<_>>());
+
+            let shared : Arc<dyn Truthy + Send + Sync> = Arc::new(String::from(" off "));
+
+            assert_eq!(true, shared.is_falsey());
+            assert_eq!(true, Rc::new(Box::new(true)).is_truey());
+        }
+
+        #[cfg(all(
+            to_be_stock_default,
+            feature = "implement-Truthy-for-bool",
+            feature = "implement-Truthy-for-String",
+        ))]
+        #[test]
+        fn TEST_Result_Truthy() {
+            use std::env;
+
+            assert_eq!(Some(true), Ok::<_, ()>(true).is_truthy());
+            assert_eq!(Some(false), Ok::<_, ()>(String::from("off")).is_truthy());
+            assert_eq!(None, Ok::<_, ()>(String::from("maybe")).is_truthy());
+            assert_eq!(None, Err::<bool, _>("failed").is_truthy());
+            assert_eq!(true, (&Ok::<_, ()>(String::from("YES"))).is_truey());
+            assert_eq!(false, (&Err::<String, _>(())).is_falsey());
+
+            assert_eq!(None, env::var("TO_BE_TEST_RESULT_TRUTHY_NOT_SET").is_truthy());
+        }
+
         #[cfg(feature = "implement-Truthy-for-bool")]
         #[test]
         fn TEST_bool_Truthy() {
@@ -960,9 +3616,372 @@ mod tests {
             }
         }
 
-        #[cfg(any(
-            feature = "implement-Truthy-for-AsStr",
-            feature = "implement-Truthy-for-str",
+        #[cfg(all(to_be_stock_default, feature = "implement-Truthy-for-bytes"))]
+        #[test]
+        fn TEST_bytes_Truthy() {
+            // [u8] / &[u8]
+            {
+                assert_eq!(Some(false), b"no"[..].is_truthy());
+                assert_eq!(Some(true), (&b" TRUE "[..]).is_truthy());
+                assert_eq!(true, (&b"0"[..]).is_falsey());
+                assert_eq!(None, (&b"maybe"[..]).is_truthy());
+                assert_eq!(None, (&b"yes\xFF"[..]).is_truthy());
+            }
+
+            // Vec<u8> / &Vec<u8>
+            {
+                assert_eq!(Some(true), b"on".to_vec().is_truthy());
+                assert_eq!(Some(false), (&b"Off".to_vec()).is_truthy());
+                assert_eq!(true, b"yes".to_vec().is_truey());
+                assert_eq!(None, Vec::<u8>::new().is_truthy());
+            }
+        }
+
+        #[cfg(feature = "implement-Truthy-for-char")]
+        #[test]
+        fn TEST_char_Truthy() {
+            // char
+            {
+                for c in ['0', 'f', 'F', 'n', 'N'] {
+                    assert_eq!(true, c.is_falsey(), "'{c}'");
+                    assert_eq!(false, c.is_truey(), "'{c}'");
+                    assert_eq!(Some(false), c.is_truthy(), "'{c}'");
+                }
+
+                for c in ['1', 't', 'T', 'y', 'Y'] {
+                    assert_eq!(false, c.is_falsey(), "'{c}'");
+                    assert_eq!(true, c.is_truey(), "'{c}'");
+                    assert_eq!(Some(true), c.is_truthy(), "'{c}'");
+                }
+
+                for c in ['2', 'x', 'o', 'O', ' ', '\0', 'ｙ'] {
+                    assert_eq!(false, c.is_falsey(), "'{c}'");
+                    assert_eq!(false, c.is_truey(), "'{c}'");
+                    assert_eq!(None, c.is_truthy(), "'{c}'");
+                }
+            }
+
+            // &char
+            {
+                assert_eq!(Some(false), (&'n').is_truthy());
+                assert_eq!(Some(true), (&'Y').is_truthy());
+                assert_eq!(None, (&'?').is_truthy());
+            }
+        }
+
+        #[cfg(feature = "implement-Truthy-for-Duration")]
+        #[test]
+        fn TEST_Duration_Truthy() {
+            use std::time::Duration;
+
+            assert_eq!(true, Duration::ZERO.is_falsey());
+            assert_eq!(Some(false), (&Duration::from_secs(0)).is_truthy());
+            assert_eq!(true, Duration::from_nanos(1).is_truey());
+            assert_eq!(Some(true), (&Duration::MAX).is_truthy());
+        }
+
+        #[cfg(feature = "implement-Truthy-for-floats")]
+        #[test]
+        fn TEST_floats_Truthy() {
+            macro_rules! check {
+                ($($type:ty),+) => {
+                    $(
+                        for v in [0.0 as $type, -0.0] {
+                            assert_eq!(true, v.is_falsey(), "{v} : {}", stringify!($type));
+                            assert_eq!(false, v.is_truey(), "{v} : {}", stringify!($type));
+                            assert_eq!(Some(false), v.is_truthy(), "{v} : {}", stringify!($type));
+                            assert_eq!(Some(false), (&v).is_truthy(), "{v} : {}", stringify!($type));
+                        }
+
+                        for v in [1.0 as $type, -1.0, 0.5, <$type>::MIN_POSITIVE, <$type>::EPSILON, <$type>::MAX, <$type>::MIN, <$type>::INFINITY, <$type>::NEG_INFINITY] {
+                            assert_eq!(false, v.is_falsey(), "{v} : {}", stringify!($type));
+                            assert_eq!(true, v.is_truey(), "{v} : {}", stringify!($type));
+                            assert_eq!(Some(true), v.is_truthy(), "{v} : {}", stringify!($type));
+                            assert_eq!(Some(true), (&v).is_truthy(), "{v} : {}", stringify!($type));
+                        }
+
+                        for v in [<$type>::NAN, -<$type>::NAN] {
+                            assert_eq!(false, v.is_falsey(), "{v} : {}", stringify!($type));
+                            assert_eq!(false, v.is_truey(), "{v} : {}", stringify!($type));
+                            assert_eq!(None, v.is_truthy(), "{v} : {}", stringify!($type));
+                            assert_eq!(None, (&v).is_truthy(), "{v} : {}", stringify!($type));
+                        }
+                    )+
+                };
+            }
+
+            check!(f32, f64);
+        }
+
+        #[cfg(feature = "implement-Truthy-for-integers")]
+        #[test]
+        fn TEST_integers_Truthy() {
+            macro_rules! check {
+                ($($type:ty),+) => {
+                    $(
+                        assert_eq!(true, (0 as $type).is_falsey(), stringify!($type));
+                        assert_eq!(false, (0 as $type).is_truey(), stringify!($type));
+                        assert_eq!(Some(false), (0 as $type).is_truthy(), stringify!($type));
+                        assert_eq!(Some(false), (&(0 as $type)).is_truthy(), stringify!($type));
+
+                        assert_eq!(false, (1 as $type).is_falsey(), stringify!($type));
+                        assert_eq!(true, (1 as $type).is_truey(), stringify!($type));
+                        assert_eq!(Some(true), (1 as $type).is_truthy(), stringify!($type));
+                        assert_eq!(Some(true), (&(1 as $type)).is_truthy(), stringify!($type));
+
+                        assert_eq!(Some(true), <$type>::MIN.max(1).is_truthy(), stringify!($type));
+                        assert_eq!(Some(true), <$type>::MAX.is_truthy(), stringify!($type));
+                    )+
+                };
+            }
+
+            check!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+            assert_eq!(Some(true), (-1i32).is_truthy());
+            assert_eq!(Some(true), i64::MIN.is_truthy());
+        }
+
+        #[cfg(all(feature = "implement-Truthy-for-bool", feature = "implement-Truthy-for-interior-mutability"))]
+        #[test]
+        fn TEST_interior_mutability_Truthy() {
+            use std::{
+                cell::{
+                    Cell,
+                    RefCell,
+                },
+                sync::{
+                    Arc,
+                    Mutex,
+                    RwLock,
+                },
+                thread,
+            };
+
+            // Cell<bool>
+            {
+                let flag = Cell::new(false);
+
+                assert_eq!(Some(false), flag.is_truthy());
+
+                flag.set(true);
+
+                assert_eq!(Some(true), (&flag).is_truthy());
+            }
+
+            // RefCell<bool>
+            {
+                let flag = RefCell::new(true);
+
+                assert_eq!(Some(true), flag.is_truthy());
+
+                {
+                    let _b = flag.borrow();
+
+                    assert_eq!(Some(true), (&flag).is_truthy());
+                }
+
+                {
+                    let mut b = flag.borrow_mut();
+
+                    *b = false;
+
+                    assert_eq!(None, flag.is_truthy());
+                }
+
+                assert_eq!(Some(false), flag.is_truthy());
+            }
+
+            // Mutex<bool>
+            {
+                let flag = Mutex::new(false);
+
+                assert_eq!(true, flag.is_falsey());
+
+                *flag.lock().unwrap() = true;
+
+                assert_eq!(true, (&flag).is_truey());
+            }
+
+            // RwLock<bool>
+            {
+                let flag = RwLock::new(true);
+
+                assert_eq!(Some(true), flag.is_truthy());
+
+                *flag.write().unwrap() = false;
+
+                assert_eq!(Some(false), (&flag).is_truthy());
+            }
+
+            // poisoning
+            {
+                let flag = Arc::new(Mutex::new(true));
+                let flag2 = Arc::clone(&flag);
+
+                let _ = thread::spawn(move || {
+                    let _guard = flag2.lock().unwrap();
+
+                    panic!("poisoning");
+                })
+                .join();
+
+                assert!(flag.is_poisoned());
+                assert_eq!(Some(true), flag.is_truthy());
+            }
+        }
+
+        #[cfg(feature = "implement-Truthy-for-IpAddr")]
+        #[test]
+        fn TEST_IpAddr_Truthy() {
+            use std::net::{
+                IpAddr,
+                Ipv4Addr,
+                Ipv6Addr,
+            };
+
+            assert_eq!(true, Ipv4Addr::UNSPECIFIED.is_falsey());
+            assert_eq!(true, Ipv6Addr::UNSPECIFIED.is_falsey());
+            assert_eq!(Some(false), (&IpAddr::from([0, 0, 0, 0])).is_truthy());
+            assert_eq!(Some(false), "::".parse::<IpAddr>().unwrap().is_truthy());
+
+            assert_eq!(true, Ipv4Addr::LOCALHOST.is_truey());
+            assert_eq!(true, Ipv6Addr::LOCALHOST.is_truey());
+            assert_eq!(Some(true), (&IpAddr::from([192, 168, 0, 1])).is_truthy());
+            assert_eq!(Some(true), "fe80::1".parse::<IpAddr>().unwrap().is_truthy());
+        }
+
+        #[cfg(feature = "implement-Truthy-for-NonZero")]
+        #[test]
+        fn TEST_NonZero_Truthy() {
+            use std::num::{
+                NonZeroI128,
+                NonZeroI16,
+                NonZeroI32,
+                NonZeroI64,
+                NonZeroI8,
+                NonZeroIsize,
+                NonZeroU128,
+                NonZeroU16,
+                NonZeroU32,
+                NonZeroU64,
+                NonZeroU8,
+                NonZeroUsize,
+            };
+
+            macro_rules! check {
+                ($($type:ty),+) => {
+                    $(
+                        let v = <$type>::MIN;
+
+                        // $type
+                        {
+                            assert_eq!(false, v.is_falsey(), stringify!($type));
+                            assert_eq!(true, v.is_truey(), stringify!($type));
+                            assert_eq!(Some(true), v.is_truthy(), stringify!($type));
+                            assert_eq!(Some(true), (&v).is_truthy(), stringify!($type));
+                        }
+
+                        // Option<$type>
+                        {
+                            assert_eq!(false, Some(v).is_falsey(), stringify!($type));
+                            assert_eq!(true, Some(v).is_truey(), stringify!($type));
+                            assert_eq!(Some(true), Some(v).is_truthy(), stringify!($type));
+                            assert_eq!(Some(true), (&Some(v)).is_truthy(), stringify!($type));
+
+                            assert_eq!(true, None::<$type>.is_falsey(), stringify!($type));
+                            assert_eq!(false, None::<$type>.is_truey(), stringify!($type));
+                            assert_eq!(Some(false), None::<$type>.is_truthy(), stringify!($type));
+                            assert_eq!(Some(false), (&None::<$type>).is_truthy(), stringify!($type));
+                        }
+                    )+
+                };
+            }
+
+            check!(
+                NonZeroI8,
+                NonZeroI16,
+                NonZeroI32,
+                NonZeroI64,
+                NonZeroI128,
+                NonZeroIsize,
+                NonZeroU8,
+                NonZeroU16,
+                NonZeroU32,
+                NonZeroU64,
+                NonZeroU128,
+                NonZeroUsize
+            );
+
+            assert_eq!(Some(false), NonZeroU32::new(0).is_truthy());
+            assert_eq!(Some(true), NonZeroU32::new(7).is_truthy());
+        }
+
+        #[cfg(feature = "implement-Truthy-for-Path")]
+        #[test]
+        fn TEST_Path_Truthy() {
+            use std::{
+                fs,
+                path::{
+                    Path,
+                    PathBuf,
+                },
+            };
+
+            let path = std::env::temp_dir().join(format!("to-be-Path-Truthy-{}", std::process::id()));
+
+            assert_eq!(true, path.is_falsey());
+
+            fs::write(&path, "1").unwrap();
+
+            assert_eq!(true, path.is_truey());
+            assert_eq!(Some(true), path.as_path().is_truthy());
+            assert_eq!(Some(true), (&path).is_truthy());
+
+            let _ = fs::remove_file(&path);
+
+            assert_eq!(None, Path::new(".").is_truthy());
+            assert_eq!(Some(false), PathBuf::new().is_truthy());
+        }
+
+        #[cfg(feature = "implement-Truthy-for-SocketAddr")]
+        #[test]
+        fn TEST_SocketAddr_Truthy() {
+            use std::net::{
+                Ipv4Addr,
+                Ipv6Addr,
+                SocketAddr,
+                SocketAddrV4,
+                SocketAddrV6,
+            };
+
+            assert_eq!(true, SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, 8080).is_falsey());
+            assert_eq!(true, SocketAddrV6::new(Ipv6Addr::UNSPECIFIED, 0, 0, 0).is_falsey());
+            assert_eq!(Some(false), "0.0.0.0:80".parse::<SocketAddr>().unwrap().is_truthy());
+            assert_eq!(Some(false), (&"[::]:443".parse::<SocketAddr>().unwrap()).is_truthy());
+
+            assert_eq!(true, SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0).is_truey());
+            assert_eq!(true, SocketAddrV6::new(Ipv6Addr::LOCALHOST, 8080, 0, 0).is_truey());
+            assert_eq!(Some(true), "10.0.0.1:80".parse::<SocketAddr>().unwrap().is_truthy());
+            assert_eq!(Some(true), (&"[::1]:443".parse::<SocketAddr>().unwrap()).is_truthy());
+        }
+
+        #[cfg(feature = "http")]
+        #[test]
+        fn TEST_StatusCode_Truthy() {
+            use ::http::StatusCode;
+
+            assert_eq!(true, StatusCode::OK.is_truey());
+            assert_eq!(Some(true), (&StatusCode::NO_CONTENT).is_truthy());
+            assert_eq!(true, StatusCode::NOT_FOUND.is_falsey());
+            assert_eq!(Some(false), (&StatusCode::SERVICE_UNAVAILABLE).is_truthy());
+            assert_eq!(None, StatusCode::CONTINUE.is_truthy());
+            assert_eq!(None, StatusCode::MOVED_PERMANENTLY.is_truthy());
+            assert_eq!(None, StatusCode::from_u16(600).unwrap().is_truthy());
+        }
+
+        #[cfg(all(
+            to_be_stock_default,
+            any(feature = "implement-Truthy-for-AsStr", feature = "implement-Truthy-for-str",),
         ))]
         #[test]
         fn TEST_str_Truthy() {
@@ -0,0 +1,951 @@
+// locale.rs - locale-specific term packs
+//
+// When the `"cldr"` feature is enabled, the registry additionally contains
+// the "yes"/"no" keywords of the ~40 locales in `data/cldr-yesno.tsv`,
+// which are generated into static tables by the build script. This adds
+// approximately 6KB to the binary. Hand-curated packs (from the
+// `"locale-*"` features) take precedence over the CLDR data.
+
+
+use super::{
+    fmt::BoolStyle,
+    Terms,
+};
+
+
+#[rustfmt::skip]
+pub(crate) mod constants {
+    #![allow(clippy::redundant_static_lifetimes)]
+
+    // NOTE: the first string in each table is the canonical form of the
+    // term in that language; the others in most-likely order. All strings
+    // must be lowercase and in Unicode Normalization Form C.
+
+    #[cfg(feature = "locale-cs")]
+    pub(crate) const CS_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "ne",
+        "nepravda",
+    ];
+
+    #[cfg(feature = "locale-cs")]
+    pub(crate) const CS_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "ano",
+        "pravda",
+    ];
+
+    #[cfg(feature = "locale-de")]
+    pub(crate) const DE_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "nein",
+        "falsch",
+        "aus",
+    ];
+
+    #[cfg(feature = "locale-de")]
+    pub(crate) const DE_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "ja",
+        "wahr",
+        "an",
+        "ein",
+    ];
+
+    #[cfg(feature = "locale-es")]
+    pub(crate) const ES_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "no",
+        "falso",
+    ];
+
+    #[cfg(feature = "locale-es")]
+    pub(crate) const ES_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "sí",
+        "si",
+        "verdadero",
+    ];
+
+    #[cfg(feature = "locale-fr")]
+    pub(crate) const FR_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "non",
+        "faux",
+    ];
+
+    #[cfg(feature = "locale-fr")]
+    pub(crate) const FR_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "oui",
+        "vrai",
+    ];
+
+    #[cfg(feature = "locale-it")]
+    pub(crate) const IT_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "no",
+        "falso",
+    ];
+
+    #[cfg(feature = "locale-it")]
+    pub(crate) const IT_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "sì",
+        "si",
+        "vero",
+    ];
+
+    #[cfg(feature = "locale-ja")]
+    pub(crate) const JA_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "いいえ",
+        "無効",
+    ];
+
+    #[cfg(feature = "locale-ja")]
+    pub(crate) const JA_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "はい",
+        "有効",
+    ];
+
+    #[cfg(feature = "locale-ko")]
+    pub(crate) const KO_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "아니오",
+        "아니요",
+    ];
+
+    #[cfg(feature = "locale-ko")]
+    pub(crate) const KO_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "예",
+        "네",
+    ];
+
+    #[cfg(feature = "locale-pl")]
+    pub(crate) const PL_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "nie",
+        "fałsz",
+        "falsz",
+    ];
+
+    #[cfg(feature = "locale-pl")]
+    pub(crate) const PL_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "tak",
+        "prawda",
+    ];
+
+    #[cfg(feature = "locale-pt")]
+    pub(crate) const PT_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "não",
+        "nao",
+        "falso",
+    ];
+
+    #[cfg(feature = "locale-pt")]
+    pub(crate) const PT_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "sim",
+        "verdadeiro",
+    ];
+
+    #[cfg(feature = "locale-ru")]
+    pub(crate) const RU_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "нет",
+        "ложь",
+        "nyet",
+        "net",
+    ];
+
+    #[cfg(feature = "locale-ru")]
+    pub(crate) const RU_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "да",
+        "истина",
+        "da",
+    ];
+
+    #[cfg(feature = "locale-uk")]
+    pub(crate) const UK_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "ні",
+        "ni",
+    ];
+
+    #[cfg(feature = "locale-uk")]
+    pub(crate) const UK_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "так",
+        "tak",
+    ];
+
+    #[cfg(feature = "locale-zh")]
+    pub(crate) const ZH_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "否",
+        "假",
+        "关",
+        "關",
+    ];
+
+    #[cfg(feature = "locale-zh")]
+    pub(crate) const ZH_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "是",
+        "真",
+        "开",
+        "開",
+    ];
+}
+
+
+/// An entry in the locale registry.
+struct LocaleEntry {
+    tag :                      &'static str,
+    falsey_lowercase_strings : &'static [&'static str],
+    truey_lowercase_strings :  &'static [&'static str],
+}
+
+#[rustfmt::skip]
+const LOCALES : &[LocaleEntry] = &[
+    #[cfg(feature = "locale-cs")]
+    LocaleEntry {
+        tag :                      "cs",
+        falsey_lowercase_strings : constants::CS_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::CS_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-de")]
+    LocaleEntry {
+        tag :                      "de",
+        falsey_lowercase_strings : constants::DE_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::DE_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-es")]
+    LocaleEntry {
+        tag :                      "es",
+        falsey_lowercase_strings : constants::ES_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::ES_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-fr")]
+    LocaleEntry {
+        tag :                      "fr",
+        falsey_lowercase_strings : constants::FR_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::FR_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-it")]
+    LocaleEntry {
+        tag :                      "it",
+        falsey_lowercase_strings : constants::IT_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::IT_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-ja")]
+    LocaleEntry {
+        tag :                      "ja",
+        falsey_lowercase_strings : constants::JA_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::JA_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-ko")]
+    LocaleEntry {
+        tag :                      "ko",
+        falsey_lowercase_strings : constants::KO_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::KO_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-pl")]
+    LocaleEntry {
+        tag :                      "pl",
+        falsey_lowercase_strings : constants::PL_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::PL_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-pt")]
+    LocaleEntry {
+        tag :                      "pt",
+        falsey_lowercase_strings : constants::PT_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::PT_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-ru")]
+    LocaleEntry {
+        tag :                      "ru",
+        falsey_lowercase_strings : constants::RU_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::RU_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-uk")]
+    LocaleEntry {
+        tag :                      "uk",
+        falsey_lowercase_strings : constants::UK_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::UK_TRUEY_LOWERCASE_STRINGS,
+    },
+    #[cfg(feature = "locale-zh")]
+    LocaleEntry {
+        tag :                      "zh",
+        falsey_lowercase_strings : constants::ZH_FALSEY_LOWERCASE_STRINGS,
+        truey_lowercase_strings :  constants::ZH_TRUEY_LOWERCASE_STRINGS,
+    },
+];
+
+#[cfg(feature = "cldr")]
+#[rustfmt::skip]
+include!(concat!(env!("OUT_DIR"), "/cldr_tables.rs"));
+
+#[cfg(not(feature = "cldr"))]
+const CLDR_LOCALES : &[LocaleEntry] = &[];
+
+impl LocaleEntry {
+    fn terms(&self) -> Terms<'static> {
+        Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : self.falsey_lowercase_strings,
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  self.truey_lowercase_strings,
+        }
+    }
+
+    fn bool_style(&self) -> Option<BoolStyle<'static>> {
+        Some(BoolStyle::new(self.truey_lowercase_strings.first()?, self.falsey_lowercase_strings.first()?))
+    }
+}
+
+fn tags_match_(
+    lhs : &str,
+    rhs : &str,
+) -> bool {
+    fn normalise_(b : u8) -> u8 {
+        match b {
+            b'_' => b'-',
+            _ => b.to_ascii_lowercase(),
+        }
+    }
+
+    lhs.len() == rhs.len() && lhs.bytes().zip(rhs.bytes()).all(|(l, r)| normalise_(l) == normalise_(r))
+}
+
+/// Obtains the tags of all locales available in the registry, which is
+/// always `"en"` (the stock terms) plus those whose `"locale-*"` features
+/// are enabled, plus those from the CLDR data if the `"cldr"` feature is
+/// enabled.
+pub fn available_locales() -> impl Iterator<Item = &'static str> {
+    let cldr_tags = CLDR_LOCALES
+        .iter()
+        .map(|entry| entry.tag)
+        .filter(|&tag| !tags_match_(tag, "en") && !LOCALES.iter().any(|entry| tags_match_(tag, entry.tag)));
+
+    std::iter::once("en").chain(LOCALES.iter().map(|entry| entry.tag)).chain(cldr_tags)
+}
+
+/// Obtains the terms for the given locale, if registered.
+///
+/// The tag is matched case-insensitively, and `'_'` is accepted in place of
+/// `'-'`.
+///
+/// # Returns:
+/// - `None` - no terms are registered for the given locale;
+/// - `Some(terms)` - the terms for the given locale;
+///
+/// # Note:
+/// Only an exact match is made, so, e.g., `"fr-CA"` is not matched by the
+/// terms registered for `"fr"`. For fallback from a specific tag to a more
+/// general one, use [resolve_locale()].
+pub fn terms_for_locale(tag : &str) -> Option<Terms<'static>> {
+    find_locale_(tag).map(|(_, terms)| terms)
+}
+
+/// Obtains the (registered) tag and terms for the given locale, if
+/// registered.
+fn find_locale_(tag : &str) -> Option<(&'static str, Terms<'static>)> {
+    if tags_match_(tag, "en") {
+        return Some(("en", super::stock_term_strings()));
+    }
+
+    LOCALES
+        .iter()
+        .chain(CLDR_LOCALES.iter())
+        .find(|entry| tags_match_(tag, entry.tag))
+        .map(|entry| (entry.tag, entry.terms()))
+}
+
+
+/// The level of the fallback chain at which the terms for a locale were
+/// found by [resolve_locale()].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum LocaleFallback {
+    /// The tag itself is registered.
+    Exact,
+    /// The language and script subtags (e.g. `"zh-Hant"` for
+    /// `"zh-Hant-TW"`) are registered.
+    Script,
+    /// The language subtag (e.g. `"de"` for `"de-AT"`) is registered.
+    Language,
+    /// Nothing is registered for the locale, so the default vocabulary is
+    /// used.
+    Default,
+}
+
+/// The outcome of [resolve_locale()].
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct ResolvedLocale {
+    /// The terms.
+    pub terms : Terms<'static>,
+    /// The registered tag whose terms were found, or `None` if the default
+    /// vocabulary is used.
+    pub tag :   Option<&'static str>,
+    /// The level of the fallback chain at which the terms were found.
+    pub level : LocaleFallback,
+}
+
+/// Obtains the tags, in order, of the fallback chain of the given tag,
+/// along with the level of each.
+fn fallback_chain_(tag : &str) -> Vec<(String, LocaleFallback)> {
+    let subtags : Vec<&str> = tag.split(['-', '_']).collect();
+    let language = subtags[0];
+    let script = subtags.get(1).filter(|subtag| 4 == subtag.len() && subtag.chars().all(|c| c.is_ascii_alphabetic()));
+
+    let mut chain = vec![(tag.to_owned(), LocaleFallback::Exact)];
+
+    if let Some(script) = script {
+        if subtags.len() > 2 {
+            chain.push((format!("{language}-{script}"), LocaleFallback::Script));
+        }
+    }
+    if subtags.len() > 1 {
+        chain.push((language.to_owned(), LocaleFallback::Language));
+    }
+
+    chain
+}
+
+/// Obtains the terms for the given locale, falling back - according to
+/// the BCP 47 subtags of the tag - from the whole tag to its language and
+/// script (if it has a script subtag) to its language and, finally, to the
+/// default vocabulary (i.e. [Terms::Default]).
+///
+/// Each tag in the chain is matched as by [terms_for_locale()].
+///
+/// # Example:
+///
+/// ```
+/// use to_be::locale::{
+///     resolve_locale,
+///     LocaleFallback,
+/// };
+///
+/// let resolved = resolve_locale("en-AU");
+///
+/// assert_eq!(Some("en"), resolved.tag);
+/// assert_eq!(LocaleFallback::Language, resolved.level);
+///
+/// let resolved = resolve_locale("tlh");
+///
+/// assert_eq!(None, resolved.tag);
+/// assert_eq!(LocaleFallback::Default, resolved.level);
+/// ```
+pub fn resolve_locale(tag : &str) -> ResolvedLocale {
+    for (candidate, level) in fallback_chain_(tag) {
+        if let Some((tag, terms)) = find_locale_(&candidate) {
+            return ResolvedLocale {
+                terms,
+                tag : Some(tag),
+                level,
+            };
+        }
+    }
+
+    ResolvedLocale {
+        terms : Terms::Default,
+        tag :   None,
+        level : LocaleFallback::Default,
+    }
+}
+
+/// Obtains the style in which `bool` values are rendered for the given
+/// locale, if registered, which comprises the canonical "truey" and
+/// "falsey" terms of the locale (e.g. `"oui"` / `"non"` for `"fr"`).
+///
+/// The tag is matched as by [terms_for_locale()].
+///
+/// # Returns:
+/// - `None` - no terms are registered for the given locale;
+/// - `Some(style)` - the style for the given locale;
+pub fn bool_style_for_locale(tag : &str) -> Option<BoolStyle<'static>> {
+    if tags_match_(tag, "en") {
+        return Some(BoolStyle::YES_NO);
+    }
+
+    LOCALES
+        .iter()
+        .chain(CLDR_LOCALES.iter())
+        .find(|entry| tags_match_(tag, entry.tag))
+        .and_then(LocaleEntry::bool_style)
+}
+
+
+/// Obtains the locale tag corresponding to the given value of a POSIX
+/// locale variable (e.g. `"fr_CA.UTF-8@euro"`), which is stripped of its
+/// codeset and modifier, or `None` if it denotes the `C` (or `POSIX`)
+/// locale.
+fn tag_from_posix_locale_(value : &str) -> Option<String> {
+    let tag = value.split(['.', '@']).next().unwrap_or_default();
+
+    match tag {
+        "" | "C" | "POSIX" => None,
+        _ => Some(tag.replace('_', "-")),
+    }
+}
+
+/// Obtains the tag of the locale in effect for messages according to the
+/// POSIX locale variables obtained from the given lookup, in which the
+/// first of `LC_ALL`, `LC_MESSAGES`, and `LANG` that is set (to a
+/// non-empty value) prevails.
+///
+/// # Returns:
+/// - `None` - none of the variables is set;
+/// - `Some(None)` - the prevailing variable denotes the `C` locale;
+/// - `Some(Some(tag))` - the tag;
+fn env_locale_tag_<F>(lookup : F) -> Option<Option<String>>
+where
+    F : FnMut(&str) -> Option<String>,
+{
+    ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .into_iter()
+        .filter_map(lookup)
+        .find(|value| !value.is_empty())
+        .map(|value| tag_from_posix_locale_(&value))
+}
+
+/// Obtains the tag of the default locale of the current user.
+#[cfg(all(windows, feature = "windows-locale"))]
+fn windows_user_locale_tag_() -> Option<String> {
+    #[link(name = "kernel32")]
+    extern "system" {
+        fn GetUserDefaultLocaleName(
+            lpLocaleName : *mut u16,
+            cchLocaleName : i32,
+        ) -> i32;
+    }
+
+    const LOCALE_NAME_MAX_LENGTH : usize = 85;
+
+    let mut buffer = [0u16; LOCALE_NAME_MAX_LENGTH];
+
+    // SAFETY: the buffer is writable, and its length is as given
+    let len = unsafe { GetUserDefaultLocaleName(buffer.as_mut_ptr(), LOCALE_NAME_MAX_LENGTH as i32) };
+
+    // the length includes the terminating NUL, and is 0 on failure
+    match len {
+        len if len > 1 => String::from_utf16(&buffer[..len as usize - 1]).ok(),
+        _ => None,
+    }
+}
+
+/// Obtains the tag of the locale of the system (for the current process).
+///
+/// The tag is obtained from the first of the environment variables
+/// `LC_ALL`, `LC_MESSAGES`, and `LANG` that is set (to a non-empty
+/// value), as would the C library, stripped of any codeset and modifier
+/// (e.g. `"fr-CA"` for `"fr_CA.UTF-8"`). If none is set and the
+/// `"windows-locale"` feature is enabled, then, on Windows, it is the
+/// default locale of the user (e.g. `"de-AT"`).
+///
+/// # Returns:
+/// - `None` - the locale cannot be determined, or is the `C` (or `POSIX`)
+///   locale;
+/// - `Some(tag)` - the tag;
+pub fn system_locale_tag() -> Option<String> {
+    match env_locale_tag_(|name| std::env::var(name).ok()) {
+        Some(tag) => tag,
+        #[cfg(all(windows, feature = "windows-locale"))]
+        None => windows_user_locale_tag_(),
+        #[cfg(not(all(windows, feature = "windows-locale")))]
+        None => None,
+    }
+}
+
+/// Obtains the terms for the locale of the system, as determined by
+/// [system_locale_tag()], with fallback as by [resolve_locale()], so that,
+/// say, localised prompts and parsers use the vocabulary of the user
+/// without configuration.
+///
+/// If the locale cannot be determined, or is the `C` locale, the default
+/// vocabulary (i.e. [Terms::Default]) is used.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     locale::detect_system_locale_terms,
+///     string_is_truthy_with,
+/// };
+///
+/// let resolved = detect_system_locale_terms();
+///
+/// let answer = "yes"; // as read from, say, the terminal
+///
+/// let confirmed = string_is_truthy_with(answer, resolved.terms).unwrap_or(false);
+/// ```
+pub fn detect_system_locale_terms() -> ResolvedLocale {
+    match system_locale_tag() {
+        Some(tag) => resolve_locale(&tag),
+        None => ResolvedLocale {
+            terms : Terms::Default,
+            tag :   None,
+            level : LocaleFallback::Default,
+        },
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::string_is_truthy_with;
+
+
+    #[test]
+    fn TEST_available_locales_1() {
+        let tags : Vec<_> = available_locales().collect();
+
+        assert_eq!(Some(&"en"), tags.first());
+
+        for tag in &tags {
+            assert!(terms_for_locale(tag).is_some());
+        }
+
+        for (index, tag) in tags.iter().enumerate() {
+            assert!(!tags[..index].contains(tag), "duplicate tag '{tag}'");
+        }
+    }
+
+    #[test]
+    fn TEST_bool_style_for_locale() {
+        assert_eq!(Some(BoolStyle::YES_NO), bool_style_for_locale("en"));
+        assert_eq!(None, bool_style_for_locale("xx"));
+
+        for tag in available_locales() {
+            let style = bool_style_for_locale(tag).unwrap();
+            let terms = terms_for_locale(tag).unwrap();
+
+            assert_eq!(Some(true), string_is_truthy_with(style.truey, terms.clone()), "{tag}");
+            assert_eq!(Some(false), string_is_truthy_with(style.falsey, terms.clone()), "{tag}");
+        }
+    }
+
+    #[test]
+    fn TEST_fallback_chain_() {
+        assert_eq!(vec![("de".to_owned(), LocaleFallback::Exact)], fallback_chain_("de"));
+        assert_eq!(
+            vec![("de-AT".to_owned(), LocaleFallback::Exact), ("de".to_owned(), LocaleFallback::Language)],
+            fallback_chain_("de-AT")
+        );
+        assert_eq!(
+            vec![
+                ("zh_Hant_TW".to_owned(), LocaleFallback::Exact),
+                ("zh-Hant".to_owned(), LocaleFallback::Script),
+                ("zh".to_owned(), LocaleFallback::Language),
+            ],
+            fallback_chain_("zh_Hant_TW")
+        );
+        assert_eq!(
+            vec![("sr-Latn".to_owned(), LocaleFallback::Exact), ("sr".to_owned(), LocaleFallback::Language)],
+            fallback_chain_("sr-Latn")
+        );
+        assert_eq!(
+            vec![("es-419-x".to_owned(), LocaleFallback::Exact), ("es".to_owned(), LocaleFallback::Language)],
+            fallback_chain_("es-419-x")
+        );
+        assert_eq!(vec![("".to_owned(), LocaleFallback::Exact)], fallback_chain_(""));
+    }
+
+    #[test]
+    fn TEST_resolve_locale() {
+        let resolved = resolve_locale("EN");
+
+        assert_eq!(Some("en"), resolved.tag);
+        assert_eq!(LocaleFallback::Exact, resolved.level);
+
+        let resolved = resolve_locale("en_GB");
+
+        assert_eq!(Some("en"), resolved.tag);
+        assert_eq!(LocaleFallback::Language, resolved.level);
+        assert_eq!(Some(true), string_is_truthy_with("Yes", resolved.terms));
+
+        let resolved = resolve_locale("xx-Latn-XX");
+
+        assert_eq!(None, resolved.tag);
+        assert_eq!(LocaleFallback::Default, resolved.level);
+        assert!(matches!(resolved.terms, Terms::Default));
+    }
+
+    #[cfg(feature = "locale-de")]
+    #[test]
+    fn TEST_resolve_locale_de() {
+        let resolved = resolve_locale("de-AT");
+
+        assert_eq!(Some("de"), resolved.tag);
+        assert_eq!(LocaleFallback::Language, resolved.level);
+        assert_eq!(Some(true), string_is_truthy_with("Ja", resolved.terms));
+    }
+
+    #[test]
+    fn TEST_terms_for_locale_UNKNOWN() {
+        assert!(terms_for_locale("").is_none());
+        assert!(terms_for_locale("xx").is_none());
+        assert!(terms_for_locale("english").is_none());
+    }
+
+    #[test]
+    fn TEST_terms_for_locale_en() {
+        let terms = terms_for_locale("EN").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("No", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Yes", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("oui", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-cs")]
+    #[test]
+    fn TEST_terms_for_locale_cs() {
+        let terms = terms_for_locale("cs").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("ne", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Nepravda", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("ANO", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("pravda", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("tak", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-de")]
+    #[test]
+    fn TEST_terms_for_locale_de() {
+        let terms = terms_for_locale("de").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("nein", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Falsch", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("AUS", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Ja", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("wahr", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("An", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("EIN", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-es")]
+    #[test]
+    fn TEST_terms_for_locale_es() {
+        let terms = terms_for_locale("es").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("no", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Falso", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("sí", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with(" SÍ ", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Si", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("VERDADERO", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn TEST_terms_for_locale_fr() {
+        let terms = terms_for_locale("fr").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("non", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("NON", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Faux", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("oui", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with(" Oui", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("VRAI", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("no", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-it")]
+    #[test]
+    fn TEST_terms_for_locale_it() {
+        let terms = terms_for_locale("it").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("No", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("falso", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("sì", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("SÌ", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Vero", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("sí", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-ja")]
+    #[test]
+    fn TEST_terms_for_locale_ja() {
+        let terms = terms_for_locale("ja").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("いいえ", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("無効", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("はい", terms.clone()));
+        // surrounded by ideographic spaces
+        assert_eq!(Some(true), string_is_truthy_with("\u{3000}有効\u{3000}", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("是", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-ko")]
+    #[test]
+    fn TEST_terms_for_locale_ko() {
+        let terms = terms_for_locale("ko").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("아니오", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("아니요", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("예", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("네", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("はい", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-pl")]
+    #[test]
+    fn TEST_terms_for_locale_pl() {
+        let terms = terms_for_locale("pl").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("Nie", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("FAŁSZ", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("falsz", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("tak", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Prawda", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("ano", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-pt")]
+    #[test]
+    fn TEST_terms_for_locale_pt() {
+        let terms = terms_for_locale("pt").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("não", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("NÃO", terms.clone()));
+        // decomposed form, i.e. "a" followed by U+0303 COMBINING TILDE
+        assert_eq!(Some(false), string_is_truthy_with("Na\u{0303}o", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("nao", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Sim", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("verdadeiro", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("si", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-ru")]
+    #[test]
+    fn TEST_terms_for_locale_ru() {
+        let terms = terms_for_locale("ru").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("нет", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("НЕТ", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Ложь", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("Nyet", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("да", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with(" Да ", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("ИСТИНА", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("DA", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("так", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-uk")]
+    #[test]
+    fn TEST_terms_for_locale_uk() {
+        let terms = terms_for_locale("uk").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("ні", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("НІ", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("ni", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("Так", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("tak", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("да", terms.clone()));
+    }
+
+    #[cfg(feature = "locale-zh")]
+    #[test]
+    fn TEST_terms_for_locale_zh() {
+        let terms = terms_for_locale("zh").unwrap();
+
+        assert_eq!(Some(false), string_is_truthy_with("否", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("假", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("关", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("關", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("是", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("真", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("开", terms.clone()));
+        assert_eq!(Some(true), string_is_truthy_with("開", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("はい", terms.clone()));
+    }
+
+    #[cfg(all(
+        to_be_stock_default,
+        any(feature = "locale-ja", feature = "locale-ko", feature = "locale-zh",),
+    ))]
+    #[test]
+    fn TEST_terms_for_locale_FULLWIDTH_FORMS() {
+        use crate::string_is_truthy;
+
+        assert_eq!(Some(false), string_is_truthy("ＮＯ"));
+        assert_eq!(Some(false), string_is_truthy("０"));
+        assert_eq!(Some(true), string_is_truthy("ｙｅｓ"));
+        assert_eq!(Some(true), string_is_truthy("\u{3000}Ｔｒｕｅ\u{3000}"));
+        assert_eq!(None, string_is_truthy("ｙ ｅｓ"));
+    }
+
+    #[cfg(feature = "cldr")]
+    #[test]
+    fn TEST_terms_for_locale_CLDR() {
+        {
+            let terms = terms_for_locale("nl").unwrap();
+
+            assert_eq!(Some(false), string_is_truthy_with("Nee", terms.clone()));
+            assert_eq!(Some(false), string_is_truthy_with("n", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("JA", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("j", terms.clone()));
+            assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
+        }
+
+        {
+            let terms = terms_for_locale("el").unwrap();
+
+            assert_eq!(Some(false), string_is_truthy_with("ΌΧΙ", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("Ναι", terms.clone()));
+        }
+
+        {
+            let terms = terms_for_locale("tr").unwrap();
+
+            assert_eq!(Some(false), string_is_truthy_with("hayır", terms.clone()));
+            assert_eq!(Some(true), string_is_truthy_with("Evet", terms.clone()));
+        }
+
+        // "en" is always the stock terms
+        {
+            let terms = terms_for_locale("en").unwrap();
+
+            assert_eq!(None, string_is_truthy_with("y", terms.clone()));
+        }
+    }
+
+    #[cfg(feature = "locale-fr")]
+    #[test]
+    fn TEST_terms_for_locale_TAG_FORMS() {
+        assert!(terms_for_locale("fr").is_some());
+        assert!(terms_for_locale("FR").is_some());
+        assert!(terms_for_locale("fr-FR").is_none());
+    }
+
+    #[test]
+    fn TEST_tag_from_posix_locale_() {
+        assert_eq!(Some("fr-CA".to_owned()), tag_from_posix_locale_("fr_CA.UTF-8"));
+        assert_eq!(Some("de-DE".to_owned()), tag_from_posix_locale_("de_DE@euro"));
+        assert_eq!(Some("sr-RS".to_owned()), tag_from_posix_locale_("sr_RS.UTF-8@latin"));
+        assert_eq!(Some("ja".to_owned()), tag_from_posix_locale_("ja"));
+        assert_eq!(None, tag_from_posix_locale_("C"));
+        assert_eq!(None, tag_from_posix_locale_("C.UTF-8"));
+        assert_eq!(None, tag_from_posix_locale_("POSIX"));
+        assert_eq!(None, tag_from_posix_locale_(".UTF-8"));
+    }
+
+    #[test]
+    fn TEST_env_locale_tag_() {
+        fn lookup_<'a>(vars : &'a [(&'a str, &'a str)]) -> impl FnMut(&str) -> Option<String> + 'a {
+            |name| vars.iter().find(|(n, _)| *n == name).map(|(_, value)| (*value).to_owned())
+        }
+
+        assert_eq!(None, env_locale_tag_(lookup_(&[])));
+        assert_eq!(None, env_locale_tag_(lookup_(&[("LC_ALL", ""), ("LC_CTYPE", "de_DE")])));
+        assert_eq!(Some(Some("pt-BR".to_owned())), env_locale_tag_(lookup_(&[("LANG", "pt_BR.UTF-8")])));
+        assert_eq!(
+            Some(Some("it-IT".to_owned())),
+            env_locale_tag_(lookup_(&[("LANG", "pt_BR.UTF-8"), ("LC_MESSAGES", "it_IT")]))
+        );
+        assert_eq!(
+            Some(Some("es".to_owned())),
+            env_locale_tag_(lookup_(&[("LANG", "pt_BR"), ("LC_MESSAGES", "it_IT"), ("LC_ALL", "es")]))
+        );
+        assert_eq!(
+            Some(None),
+            env_locale_tag_(lookup_(&[("LANG", "pt_BR"), ("LC_ALL", "C.UTF-8")]))
+        );
+    }
+}
@@ -0,0 +1,230 @@
+// metrics.rs - counters of classifications
+
+//! Process-wide counters of the classifications made by each evaluator, so
+//! that long-running programs can detect, say, an unexpected increase in
+//! unrecognised inputs.
+//!
+//! Counted are the classifications made by [string_is_truthy()],
+//! [string_is_truthy_with()], [CompiledTerms::string_is_truthy()] (and so
+//! its `string_is_falsey()` and `string_is_truey()`), and
+//! [BatchClassifier::classify()] (and so its `classify_into()`). (The free
+//! functions [string_is_falsey()] and [string_is_truey()] do not determine
+//! whether their input is recognised, and so are not counted.)
+//!
+//! The counters are updated with relaxed ordering, so a snapshot taken
+//! while classifications are under way is not necessarily consistent
+//! across counters.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::metrics::{
+//!     self,
+//!     Evaluator,
+//! };
+//!
+//! let before = metrics::snapshot().get(Evaluator::Default);
+//!
+//! to_be::string_is_truthy("maybe");
+//!
+//! let after = metrics::snapshot().get(Evaluator::Default);
+//!
+//! assert!(after.unknown > before.unknown);
+//! ```
+//!
+//! [BatchClassifier::classify()]: crate::batch::BatchClassifier::classify()
+//! [CompiledTerms::string_is_truthy()]: crate::CompiledTerms::string_is_truthy()
+//! [string_is_falsey()]: crate::string_is_falsey()
+//! [string_is_truey()]: crate::string_is_truey()
+//! [string_is_truthy()]: crate::string_is_truthy()
+//! [string_is_truthy_with()]: crate::string_is_truthy_with()
+
+use std::sync::atomic::{
+    AtomicU64,
+    Ordering,
+};
+
+
+/// The evaluator by which classifications are made.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum Evaluator {
+    /// `string_is_truthy()`, `string_is_truthy_with_strictness()`, and
+    /// `string_is_truthy_with()` and
+    /// `string_is_truthy_with_terms_and_strictness()` with `Terms::Default`.
+    Default,
+    /// `string_is_truthy_with()` and
+    /// `string_is_truthy_with_terms_and_strictness()` with custom terms.
+    Custom,
+    /// `CompiledTerms`.
+    Compiled,
+    /// `BatchClassifier`.
+    Batch,
+}
+
+impl Evaluator {
+    const ALL : [Self; 4] = [Self::Default, Self::Custom, Self::Compiled, Self::Batch];
+}
+
+/// The numbers of classifications of each kind.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct Counts {
+    pub truey :   u64,
+    pub falsey :  u64,
+    pub unknown : u64,
+}
+
+impl Counts {
+    /// The total number of classifications.
+    pub fn total(&self) -> u64 {
+        self.truey + self.falsey + self.unknown
+    }
+}
+
+/// The counts of all evaluators at a point in time, as obtained by
+/// [snapshot()].
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct Snapshot {
+    counts : [Counts; 4],
+}
+
+impl Snapshot {
+    /// Obtains the counts of the given evaluator.
+    pub fn get(
+        &self,
+        evaluator : Evaluator,
+    ) -> Counts {
+        self.counts[evaluator as usize]
+    }
+
+    /// Obtains the sums of the counts of all evaluators.
+    pub fn total(&self) -> Counts {
+        self.counts.iter().fold(Counts::default(), |acc, counts| Counts {
+            truey :   acc.truey + counts.truey,
+            falsey :  acc.falsey + counts.falsey,
+            unknown : acc.unknown + counts.unknown,
+        })
+    }
+}
+
+
+/// The counters, indexed by evaluator and then by classification (falsey,
+/// truey, unknown).
+static COUNTERS : [[AtomicU64; 3]; 4] = [const { [const { AtomicU64::new(0) }; 3] }; 4];
+
+const FALSEY_INDEX : usize = 0;
+const TRUEY_INDEX : usize = 1;
+const UNKNOWN_INDEX : usize = 2;
+
+/// Records the given classification by the given evaluator.
+pub(crate) fn record_(
+    evaluator : Evaluator,
+    classification : Option<bool>,
+) {
+    let index = match classification {
+        Some(false) => FALSEY_INDEX,
+        Some(true) => TRUEY_INDEX,
+        None => UNKNOWN_INDEX,
+    };
+
+    COUNTERS[evaluator as usize][index].fetch_add(1, Ordering::Relaxed);
+}
+
+/// Obtains the current counts of all evaluators.
+pub fn snapshot() -> Snapshot {
+    let mut snapshot = Snapshot::default();
+
+    for evaluator in Evaluator::ALL {
+        let counters = &COUNTERS[evaluator as usize];
+
+        snapshot.counts[evaluator as usize] = Counts {
+            truey :   counters[TRUEY_INDEX].load(Ordering::Relaxed),
+            falsey :  counters[FALSEY_INDEX].load(Ordering::Relaxed),
+            unknown : counters[UNKNOWN_INDEX].load(Ordering::Relaxed),
+        };
+    }
+
+    snapshot
+}
+
+/// Resets the counts of all evaluators to zero.
+pub fn reset() {
+    for counter in COUNTERS.iter().flatten() {
+        counter.store(0, Ordering::Relaxed);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::{
+        batch::BatchClassifier,
+        string_is_truthy_with,
+        CompiledTerms,
+        Terms,
+    };
+
+
+    // NOTE: as the counters are process-wide, and tests run concurrently,
+    // these tests verify only increases in the counts
+
+    #[test]
+    fn TEST_Counts_total() {
+        let counts = Counts {
+            truey :   1,
+            falsey :  2,
+            unknown : 3,
+        };
+
+        assert_eq!(6, counts.total());
+    }
+
+    #[test]
+    fn TEST_snapshot_Custom_AND_Compiled_AND_Batch() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["non"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["oui"],
+        };
+        let compiled = CompiledTerms::new(terms.clone());
+        let batch = BatchClassifier::new(terms.clone());
+
+        let before = snapshot();
+
+        assert_eq!(Some(true), string_is_truthy_with("oui", terms.clone()));
+        assert_eq!(Some(false), string_is_truthy_with("non", terms.clone()));
+        assert_eq!(None, string_is_truthy_with("yes", terms.clone()));
+        assert_eq!(None, compiled.string_is_truthy("peut-être"));
+        assert_eq!(Some(true), batch.classify("OUI"));
+        assert_eq!(None, batch.classify("a-string-too-long-to-be-packed"));
+
+        let after = snapshot();
+
+        assert!(after.get(Evaluator::Custom).truey > before.get(Evaluator::Custom).truey);
+        assert!(after.get(Evaluator::Custom).falsey > before.get(Evaluator::Custom).falsey);
+        assert!(after.get(Evaluator::Custom).unknown > before.get(Evaluator::Custom).unknown);
+        assert!(after.get(Evaluator::Compiled).unknown > before.get(Evaluator::Compiled).unknown);
+        assert!(after.get(Evaluator::Batch).truey > before.get(Evaluator::Batch).truey);
+        assert!(after.get(Evaluator::Batch).unknown > before.get(Evaluator::Batch).unknown);
+        assert!(after.total().total() >= before.total().total() + 6);
+    }
+}
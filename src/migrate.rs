@@ -0,0 +1,552 @@
+// migrate.rs - rewriting of configuration values in a canonical style
+
+//! Rewriting of the boolean values of configuration entries in a canonical
+//! style, reporting those that are not recognised, as the core of a
+//! configuration-migration tool (without the file I/O).
+//!
+//! Entries may be given as key/value pairs - e.g. from a `HashMap` or
+//! `BTreeMap` - to [migrate()], or as lines of the form `key = value` to
+//! [migrate_lines()]; and a `.env` file may be streamed through
+//! [normalize_env_file()].
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::{
+//!     fmt::BoolStyle,
+//!     migrate::migrate_lines,
+//! };
+//!
+//! let text = "\
+//! name = frobnicator
+//! verbose = Yes
+//! daemon=off
+//! debug = treu
+//! ";
+//!
+//! let migration = migrate_lines(text.lines(), &["verbose", "daemon", "debug"], &BoolStyle::TRUE_FALSE);
+//!
+//! # #[cfg(to_be_stock_default)]
+//! # {
+//! assert_eq!(
+//!     vec!["name = frobnicator", "verbose = true", "daemon=false", "debug = treu"],
+//!     migration.output
+//! );
+//! assert_eq!(2, migration.changed);
+//! assert_eq!(1, migration.unrecognised.len());
+//! assert_eq!("debug", migration.unrecognised[0].key);
+//! assert_eq!(3, migration.unrecognised[0].index);
+//! # }
+//! ```
+
+use super::{
+    diagnostics::{
+        classify_spanned,
+        Span,
+        UnrecognisedValue,
+    },
+    fmt::BoolStyle,
+};
+
+use std::{
+    io::{
+        self,
+        BufRead,
+        Write,
+    },
+    ops::Range,
+};
+
+
+/// An entry whose value is not recognised, and so is left unchanged.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct UnrecognisedEntry {
+    /// The (0-based) index of the entry (or line) in the input.
+    pub index : usize,
+    /// The key of the entry.
+    pub key :   String,
+    /// The error, whose span is that of the (trimmed) value within the
+    /// value (or, for [migrate_lines()], within the line).
+    pub error : UnrecognisedValue,
+}
+
+/// The outcome of a migration.
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct Migration<T> {
+    /// The entries (or lines), in input order, in which the values of the
+    /// boolean keys are rewritten.
+    pub output :       Vec<T>,
+    /// The entries whose values are not recognised.
+    pub unrecognised : Vec<UnrecognisedEntry>,
+    /// The number of values that were changed by rewriting.
+    pub changed :      usize,
+}
+
+impl<T> Migration<T> {
+    /// Indicates whether all values of the boolean keys were recognised.
+    pub fn is_clean(&self) -> bool {
+        self.unrecognised.is_empty()
+    }
+}
+
+/// Rewrites the given value, at the given offset, or reports it as
+/// unrecognised.
+fn rewrite_(
+    value : &str,
+    offset : usize,
+    style : &BoolStyle,
+) -> Result<String, UnrecognisedValue> {
+    classify_spanned(value, Span::new(offset, value.len())).map(|b| style.render(b).into_owned())
+}
+
+/// Rewrites, in the given style, the values of those of the given entries
+/// whose keys are among the given boolean keys (which are matched
+/// exactly), leaving all other values unchanged.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     fmt::BoolStyle,
+///     migrate::migrate,
+/// };
+///
+/// use std::collections::BTreeMap;
+///
+/// let config = BTreeMap::from([("name", "frobnicator"), ("verbose", " YES ")]);
+///
+/// let migration = migrate(config, &["verbose"], &BoolStyle::ON_OFF);
+///
+/// # #[cfg(to_be_stock_default)]
+/// assert_eq!(vec![("name", "frobnicator".to_owned()), ("verbose", "on".to_owned())], migration.output);
+/// ```
+pub fn migrate<I, K, V>(
+    entries : I,
+    boolean_keys : &[&str],
+    style : &BoolStyle,
+) -> Migration<(K, String)>
+where
+    I : IntoIterator<Item = (K, V)>,
+    K : AsRef<str>,
+    V : AsRef<str>,
+{
+    let mut migration = Migration {
+        output :       Vec::new(),
+        unrecognised : Vec::new(),
+        changed :      0,
+    };
+
+    for (index, (key, value)) in entries.into_iter().enumerate() {
+        let value = value.as_ref();
+
+        if !boolean_keys.contains(&key.as_ref()) {
+            migration.output.push((key, value.to_owned()));
+
+            continue;
+        }
+
+        match rewrite_(value, 0, style) {
+            Ok(rewritten) => {
+                if rewritten != value {
+                    migration.changed += 1;
+                }
+
+                migration.output.push((key, rewritten));
+            },
+            Err(error) => {
+                migration.unrecognised.push(UnrecognisedEntry {
+                    index,
+                    key : key.as_ref().to_owned(),
+                    error,
+                });
+
+                migration.output.push((key, value.to_owned()));
+            },
+        };
+    }
+
+    migration
+}
+
+/// Rewrites, in the given style, the values of those of the given lines
+/// of the form `key = value` whose (trimmed) keys are among the given
+/// boolean keys (which are matched exactly), leaving all other lines -
+/// including blank lines and comments - unchanged.
+///
+/// Only the value is rewritten, so the layout of the line - indentation,
+/// whitespace around the `'='`, and trailing whitespace - is preserved.
+pub fn migrate_lines<'a, I>(
+    lines : I,
+    boolean_keys : &[&str],
+    style : &BoolStyle,
+) -> Migration<String>
+where
+    I : IntoIterator<Item = &'a str>,
+{
+    let mut migration = Migration {
+        output :       Vec::new(),
+        unrecognised : Vec::new(),
+        changed :      0,
+    };
+
+    for (index, line) in lines.into_iter().enumerate() {
+        let Some((key, value)) = line.split_once('=') else {
+            migration.output.push(line.to_owned());
+
+            continue;
+        };
+
+        let key = key.trim();
+
+        if !boolean_keys.contains(&key) {
+            migration.output.push(line.to_owned());
+
+            continue;
+        }
+
+        let value_offset = line.len() - value.len();
+
+        match rewrite_(value, value_offset, style) {
+            Ok(rewritten) => {
+                let leading = value.len() - value.trim_start().len();
+                let trailing = value.len() - value.trim_end().len();
+                let value_range = value_offset + leading..line.len() - trailing;
+                let rewritten_line = format!("{}{rewritten}{}", &line[..value_range.start], &line[value_range.end..]);
+
+                if rewritten_line != line {
+                    migration.changed += 1;
+                }
+
+                migration.output.push(rewritten_line);
+            },
+            Err(error) => {
+                migration.unrecognised.push(UnrecognisedEntry {
+                    index,
+                    key : key.to_owned(),
+                    error,
+                });
+
+                migration.output.push(line.to_owned());
+            },
+        };
+    }
+
+    migration
+}
+
+
+/// The outcome of [normalize_env_file()].
+#[derive(Clone)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct EnvNormalization {
+    /// The entries whose values are not recognised, where the index of
+    /// each is that of its line.
+    pub unrecognised : Vec<UnrecognisedEntry>,
+    /// The number of values that were changed by rewriting.
+    pub changed :      usize,
+}
+
+/// Obtains the key of the given `.env` line, and the range of its value
+/// (within quotes, if quoted), or `None` if the line is not an assignment.
+fn parse_env_line_(line : &str) -> Option<(&str, Range<usize>)> {
+    let body = line.trim_start();
+
+    if body.starts_with('#') {
+        return None;
+    }
+
+    let body = match body.strip_prefix("export") {
+        Some(rest) if rest.starts_with([' ', '\t']) => rest,
+        _ => body,
+    };
+
+    let (key, _) = body.split_once('=')?;
+    let key = key.trim();
+
+    let value_start = line.len() - body.len() + body.find('=')? + 1;
+    let value = &line[value_start..];
+    let leading = value.len() - value.trim_start().len();
+    let value = value.trim_start();
+    let value_start = value_start + leading;
+
+    match value.chars().next() {
+        Some(quote @ ('"' | '\'')) => {
+            let len = value[1..].find(quote)?;
+
+            Some((key, value_start + 1..value_start + 1 + len))
+        },
+        _ => {
+            // an unquoted value ends at a comment, i.e. at a '#' that
+            // follows whitespace
+
+            let end = value
+                .as_bytes()
+                .windows(2)
+                .position(|pair| matches!(pair, [b' ' | b'\t', b'#']))
+                .unwrap_or(value.len());
+
+            Some((key, value_start..value_start + value[..end].trim_end().len()))
+        },
+    }
+}
+
+/// Streams the `.env` file from the given reader to the given writer,
+/// rewriting, in the given style, the values of those assignments whose
+/// keys are among the given boolean keys (which are matched exactly).
+///
+/// Lines of the form `KEY=value`, optionally prefixed by `export`, are
+/// recognised as assignments. A value may be quoted - with `'"'` or
+/// `'\''` - in which case the quotes are preserved; an unquoted value ends
+/// at any comment (i.e. a `'#'` that follows whitespace), which is also
+/// preserved. All other lines - comments, blank lines, assignments of
+/// other keys, and assignments whose values are not recognised (or that
+/// are not valid UTF-8) - are written byte-for-byte, as are all line
+/// terminators.
+///
+/// # Returns:
+/// - `Err(e)` - reading or writing failed;
+/// - `Ok(normalization)` - the number of values changed and the
+///   assignments whose values are not recognised;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     fmt::BoolStyle,
+///     migrate::normalize_env_file,
+/// };
+///
+/// let input = "export VERBOSE=Yes # noisy\r\nNAME=\"yes\"\nDEBUG='off'\n";
+/// let mut output = Vec::new();
+///
+/// let normalization =
+///     normalize_env_file(input.as_bytes(), &mut output, &BoolStyle::TRUE_FALSE, &["VERBOSE", "DEBUG"]).unwrap();
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!("export VERBOSE=true # noisy\r\nNAME=\"yes\"\nDEBUG='false'\n", String::from_utf8(output).unwrap());
+/// assert_eq!(2, normalization.changed);
+/// # }
+/// ```
+pub fn normalize_env_file<R, W>(
+    mut reader : R,
+    mut writer : W,
+    style : &BoolStyle,
+    boolean_keys : &[&str],
+) -> io::Result<EnvNormalization>
+where
+    R : BufRead,
+    W : Write,
+{
+    let mut normalization = EnvNormalization::default();
+    let mut buf = Vec::new();
+
+    for index in 0.. {
+        buf.clear();
+
+        if 0 == reader.read_until(b'\n', &mut buf)? {
+            break;
+        }
+
+        let assignment = std::str::from_utf8(&buf)
+            .ok()
+            .and_then(|line| parse_env_line_(line).map(|(key, range)| (line, key, range)))
+            .filter(|(_, key, _)| boolean_keys.contains(key));
+
+        let Some((line, key, range)) = assignment else {
+            writer.write_all(&buf)?;
+
+            continue;
+        };
+
+        match rewrite_(&line[range.clone()], range.start, style) {
+            Ok(rewritten) => {
+                if rewritten != line[range.clone()] {
+                    normalization.changed += 1;
+                }
+
+                writer.write_all(&buf[..range.start])?;
+                writer.write_all(rewritten.as_bytes())?;
+                writer.write_all(&buf[range.end..])?;
+            },
+            Err(error) => {
+                normalization.unrecognised.push(UnrecognisedEntry {
+                    index,
+                    key : key.to_owned(),
+                    error,
+                });
+
+                writer.write_all(&buf)?;
+            },
+        };
+    }
+
+    writer.flush()?;
+
+    Ok(normalization)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::fmt::Capitalization;
+
+    use std::collections::HashMap;
+
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_migrate() {
+        let config = vec![("a", "yes"), ("b", "true"), ("c", "maybe"), ("d", "maybe"), ("e", "0")];
+
+        let migration = migrate(config, &["a", "b", "d", "e"], &BoolStyle::TRUE_FALSE);
+
+        assert_eq!(
+            vec![
+                ("a", "true".to_owned()),
+                ("b", "true".to_owned()),
+                ("c", "maybe".to_owned()),
+                ("d", "maybe".to_owned()),
+                ("e", "false".to_owned()),
+            ],
+            migration.output
+        );
+        assert_eq!(2, migration.changed);
+        assert!(!migration.is_clean());
+        assert_eq!(1, migration.unrecognised.len());
+        assert_eq!(3, migration.unrecognised[0].index);
+        assert_eq!("d", migration.unrecognised[0].key);
+        assert_eq!("maybe", migration.unrecognised[0].error.value);
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_migrate_HashMap() {
+        let config : HashMap<String, String> =
+            [("verbose".to_owned(), "On".to_owned()), ("name".to_owned(), "on".to_owned())].into();
+
+        let style = BoolStyle::YES_NO.with_capitalization(Capitalization::Uppercase);
+
+        let migration = migrate(config, &["verbose"], &style);
+        let output : HashMap<String, String> = migration.output.into_iter().collect();
+
+        assert_eq!("YES", output["verbose"]);
+        assert_eq!("on", output["name"]);
+        assert_eq!(1, migration.changed);
+        assert!(migration.unrecognised.is_empty());
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_migrate_lines() {
+        let lines = ["  verbose =  Yes  ", "verbose=true", "# verbose = yes", "", "quiet = nope", "name = yes"];
+
+        let migration = migrate_lines(lines, &["verbose", "quiet"], &BoolStyle::ON_OFF);
+
+        assert_eq!(
+            vec!["  verbose =  on  ", "verbose=on", "# verbose = yes", "", "quiet = nope", "name = yes"],
+            migration.output
+        );
+        assert_eq!(2, migration.changed);
+        assert_eq!(1, migration.unrecognised.len());
+
+        let entry = &migration.unrecognised[0];
+
+        assert_eq!(4, entry.index);
+        assert_eq!("quiet", entry.key);
+        assert_eq!(Span::new(8, 4), entry.error.span);
+        assert_eq!("nope", &lines[4][entry.error.span.range()]);
+    }
+
+    #[test]
+    fn TEST_parse_env_line_() {
+        assert_eq!(Some(("A", 2..5)), parse_env_line_("A=yes\n"));
+        assert_eq!(Some(("A", 11..14)), parse_env_line_("export A = yes # comment"));
+        assert_eq!(Some(("A", 3..6)), parse_env_line_("A=\"yes\"  \r\n"));
+        assert_eq!(Some(("A", 3..6)), parse_env_line_("A='a#b'"));
+        assert_eq!(Some(("A", 2..5)), parse_env_line_("A=a#b"));
+        assert_eq!(Some(("A", 2..5)), parse_env_line_("A=yes\t# a # b"));
+        assert_eq!(Some(("A", 2..5)), parse_env_line_("A=yes # a\t# b"));
+        assert_eq!(Some(("exported", 9..10)), parse_env_line_("exported=1"));
+        assert_eq!(Some(("A", 2..2)), parse_env_line_("A="));
+        assert_eq!(None, parse_env_line_("A=\"yes"));
+        assert_eq!(None, parse_env_line_("# A=yes"));
+        assert_eq!(None, parse_env_line_(""));
+        assert_eq!(None, parse_env_line_("A"));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_normalize_env_file() {
+        let input = b"\
+# features\r
+VERBOSE=Yes\r
+export  DAEMON = 'ON' # as a service\r
+DEBUG=\"maybe\"\r
+NAME=yes\r
+\r
+BINARY=\xff\r
+QUIET=0";
+        let mut output = Vec::new();
+
+        let keys = ["VERBOSE", "DAEMON", "DEBUG", "QUIET", "BINARY"];
+
+        let normalization = normalize_env_file(&input[..], &mut output, &BoolStyle::YES_NO, &keys).unwrap();
+
+        assert_eq!(
+            &b"\
+# features\r
+VERBOSE=yes\r
+export  DAEMON = 'yes' # as a service\r
+DEBUG=\"maybe\"\r
+NAME=yes\r
+\r
+BINARY=\xff\r
+QUIET=no"[..],
+            &output[..]
+        );
+        assert_eq!(3, normalization.changed);
+        assert_eq!(1, normalization.unrecognised.len());
+
+        let entry = &normalization.unrecognised[0];
+
+        assert_eq!(3, entry.index);
+        assert_eq!("DEBUG", entry.key);
+        assert_eq!(Span::new(7, 5), entry.error.span);
+    }
+
+    #[test]
+    fn TEST_normalize_env_file_UNCHANGED() {
+        let input = "# comment\n\nA=1\nB = 'x'\n";
+        let mut output = Vec::new();
+
+        let normalization = normalize_env_file(input.as_bytes(), &mut output, &BoolStyle::ONE_ZERO, &["C"]).unwrap();
+
+        assert_eq!(input.as_bytes(), output);
+        assert_eq!(EnvNormalization::default(), normalization);
+    }
+
+    #[test]
+    fn TEST_migrate_lines_NO_BOOLEAN_KEYS() {
+        let lines = ["a = yes", "b = no"];
+
+        let migration = migrate_lines(lines, &[], &BoolStyle::ON_OFF);
+
+        assert_eq!(lines.to_vec(), migration.output);
+        assert_eq!(0, migration.changed);
+        assert!(migration.is_clean());
+    }
+}
@@ -0,0 +1,234 @@
+// mobile.rs - uniffi (Kotlin and Swift) bindings
+
+//! Bindings, exported via **uniffi** (by proc-macro, so no UDL file is
+//! required), for use from Kotlin and Swift, so that mobile apps classify
+//! strings identically to Rust code.
+//!
+//! The foreign-language sources are generated from the built library by
+//! `uniffi-bindgen`, e.g.:
+//!
+//! ```text
+//! cargo build --release --features uniffi
+//! uniffi-bindgen generate --library target/release/libto_be.so --language kotlin --out-dir out
+//! ```
+
+use super::{
+    locale,
+    preset::Preset,
+    CompiledTerms,
+    Terms,
+};
+
+use std::{
+    fmt,
+    sync::Arc,
+};
+
+
+/// Indicates whether the given string, when trimmed, is "truthy" according
+/// to the default vocabulary and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` (`null` / `nil`) - string is not classified as "truthy";
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+#[uniffi::export]
+pub fn string_is_truthy(s : &str) -> Option<bool> {
+    super::string_is_truthy(s)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "falsey"
+/// according to the default vocabulary.
+#[uniffi::export]
+pub fn string_is_falsey(s : &str) -> bool {
+    super::string_is_falsey(s)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "truey"
+/// according to the default vocabulary.
+#[uniffi::export]
+pub fn string_is_truey(s : &str) -> bool {
+    super::string_is_truey(s)
+}
+
+/// Obtains the tags of all locales available in the registry (see
+/// [available_locales()](crate::locale::available_locales())).
+#[uniffi::export]
+pub fn available_locales() -> Vec<String> {
+    locale::available_locales().map(str::to_owned).collect()
+}
+
+
+/// The error reported when creating a [MobileTerms] instance fails.
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(uniffi::Error)]
+pub enum MobileTermsError {
+    /// No terms are registered for the given locale.
+    UnknownLocale { tag : String },
+    /// There is no preset of the given name.
+    UnknownPreset { name : String },
+}
+
+impl fmt::Display for MobileTermsError {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::UnknownLocale {
+                tag,
+            } => write!(f, "no terms are registered for locale '{tag}'"),
+            Self::UnknownPreset {
+                name,
+            } => write!(f, "unrecognised preset '{name}'"),
+        }
+    }
+}
+
+impl std::error::Error for MobileTermsError {}
+
+
+/// Custom terms against which strings may be classified.
+#[derive(Debug)]
+#[derive(uniffi::Object)]
+pub struct MobileTerms {
+    terms : CompiledTerms,
+}
+
+#[uniffi::export]
+impl MobileTerms {
+    /// Creates an instance from the given term strings.
+    #[uniffi::constructor]
+    pub fn new(
+        falsey_precise_strings : Vec<String>,
+        falsey_lowercase_strings : Vec<String>,
+        truey_precise_strings : Vec<String>,
+        truey_lowercase_strings : Vec<String>,
+    ) -> Arc<Self> {
+        let falsey_precise_strings : Vec<&str> = falsey_precise_strings.iter().map(String::as_str).collect();
+        let falsey_lowercase_strings : Vec<&str> = falsey_lowercase_strings.iter().map(String::as_str).collect();
+        let truey_precise_strings : Vec<&str> = truey_precise_strings.iter().map(String::as_str).collect();
+        let truey_lowercase_strings : Vec<&str> = truey_lowercase_strings.iter().map(String::as_str).collect();
+
+        Arc::new(Self {
+            terms : CompiledTerms::new(Terms::Strings {
+                falsey_precise_strings :   &falsey_precise_strings,
+                falsey_lowercase_strings : &falsey_lowercase_strings,
+                truey_precise_strings :    &truey_precise_strings,
+                truey_lowercase_strings :  &truey_lowercase_strings,
+            }),
+        })
+    }
+
+    /// Creates an instance from the terms registered for the given locale.
+    #[uniffi::constructor]
+    pub fn for_locale(tag : String) -> Result<Arc<Self>, MobileTermsError> {
+        match locale::terms_for_locale(&tag) {
+            Some(terms) => Ok(Arc::new(Self {
+                terms : CompiledTerms::new(terms),
+            })),
+            None => Err(MobileTermsError::UnknownLocale {
+                tag,
+            }),
+        }
+    }
+
+    /// Creates an instance from the terms of the named preset (see
+    /// [Preset::from_name()]).
+    #[uniffi::constructor]
+    pub fn for_preset(name : String) -> Result<Arc<Self>, MobileTermsError> {
+        match Preset::from_name(&name) {
+            Some(preset) => Ok(Arc::new(Self {
+                terms : CompiledTerms::new(preset.terms()),
+            })),
+            None => Err(MobileTermsError::UnknownPreset {
+                name,
+            }),
+        }
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy"
+    /// according to the terms and, if so, whether it is "truey" or
+    /// "falsey".
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        self.terms.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as
+    /// "falsey" according to the terms.
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.terms.string_is_falsey(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey"
+    /// according to the terms.
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.terms.string_is_truey(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_string_is_truthy() {
+        assert_eq!(Some(true), string_is_truthy(" Yes "));
+        assert_eq!(Some(false), string_is_truthy("off"));
+        assert_eq!(None, string_is_truthy("maybe"));
+
+        assert!(string_is_truey("TRUE"));
+        assert!(string_is_falsey("false"));
+        assert!(available_locales().iter().any(|tag| "en" == tag));
+    }
+
+    #[test]
+    fn TEST_MobileTerms() {
+        let terms = MobileTerms::new(vec!["NYET".into()], vec!["non".into()], vec![], vec!["Oui".into()]);
+
+        assert_eq!(Some(false), terms.string_is_truthy("NYET"));
+        assert_eq!(None, terms.string_is_truthy("nyet"));
+        assert_eq!(Some(false), terms.string_is_truthy(" NON "));
+        assert!(terms.string_is_truey("OUI"));
+        assert!(terms.string_is_falsey("non"));
+        assert_eq!(None, terms.string_is_truthy("yes"));
+    }
+
+    #[test]
+    fn TEST_MobileTerms_for_locale_AND_for_preset() {
+        assert_eq!(Some(true), MobileTerms::for_locale("en".into()).unwrap().string_is_truthy("yes"));
+        assert_eq!(
+            MobileTermsError::UnknownLocale {
+                tag : "xx-unknown".into(),
+            },
+            MobileTerms::for_locale("xx-unknown".into()).unwrap_err()
+        );
+
+        let yaml11 = MobileTerms::for_preset("yaml11".into()).unwrap();
+
+        assert_eq!(Some(true), yaml11.string_is_truthy("Yes"));
+        assert_eq!(None, yaml11.string_is_truthy("yEs"));
+        assert_eq!(
+            "unrecognised preset 'unknown'",
+            MobileTerms::for_preset("unknown".into()).unwrap_err().to_string()
+        );
+    }
+}
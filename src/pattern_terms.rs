@@ -0,0 +1,157 @@
+// pattern_terms.rs - terms expressed as regular expressions
+
+use regex::Regex;
+
+
+/// Terms expressed as (compiled) regular expressions, rather than as
+/// literal strings, so that complex - e.g. legacy - value formats may be
+/// classified without enumerating every literal form.
+///
+/// The input is trimmed before matching, and each pattern is matched as by
+/// [Regex::is_match()], so a pattern that must match the whole of the input
+/// must be anchored (e.g. `^y(es)?$`), and one that is to be matched
+/// case-insensitively must say so (e.g. `(?i)^enab`). The "falsey"
+/// patterns take precedence over the "truey" patterns.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::PatternTerms;
+///
+/// let terms = PatternTerms::from_patterns(&[r"(?i)^(n(o)?|disab)"], &[r"(?i)^y(es)?$", r"(?i)^enab"]).unwrap();
+///
+/// assert_eq!(Some(true), terms.string_is_truthy("Y"));
+/// assert_eq!(Some(true), terms.string_is_truthy(" ENABLED "));
+/// assert_eq!(Some(false), terms.string_is_truthy("disable-all"));
+/// assert_eq!(None, terms.string_is_truthy("yesterday"));
+/// ```
+#[derive(Clone)]
+#[derive(Debug)]
+pub struct PatternTerms {
+    falsey_patterns : Vec<Regex>,
+    truey_patterns :  Vec<Regex>,
+}
+
+impl PatternTerms {
+    /// Creates an instance from the given compiled "falsey" and "truey"
+    /// patterns.
+    pub fn new(
+        falsey_patterns : Vec<Regex>,
+        truey_patterns : Vec<Regex>,
+    ) -> Self {
+        Self {
+            falsey_patterns,
+            truey_patterns,
+        }
+    }
+
+    /// Creates an instance by compiling the given "falsey" and "truey"
+    /// patterns.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - a pattern is not a valid regular expression;
+    /// - `Ok(terms)` - the terms;
+    pub fn from_patterns(
+        falsey_patterns : &[&str],
+        truey_patterns : &[&str],
+    ) -> Result<Self, regex::Error> {
+        let compile =
+            |patterns : &[&str]| patterns.iter().map(|&pattern| Regex::new(pattern)).collect::<Result<Vec<_>, _>>();
+
+        Ok(Self::new(compile(falsey_patterns)?, compile(truey_patterns)?))
+    }
+
+    /// The "falsey" patterns.
+    pub fn falsey_patterns(&self) -> &[Regex] {
+        &self.falsey_patterns
+    }
+
+    /// The "truey" patterns.
+    pub fn truey_patterns(&self) -> &[Regex] {
+        &self.truey_patterns
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey".
+    ///
+    /// # Returns:
+    /// - `None` - string matches none of the patterns;
+    /// - `Some(false)` - string matches a "falsey" pattern;
+    /// - `Some(true)` - string matches a "truey" pattern (and no "falsey"
+    ///   pattern);
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        let s = s.trim();
+
+        if self.falsey_patterns.iter().any(|pattern| pattern.is_match(s)) {
+            return Some(false);
+        }
+        if self.truey_patterns.iter().any(|pattern| pattern.is_match(s)) {
+            return Some(true);
+        }
+
+        debug_event_!(input = %super::TracedInput(s), "input is not recognised");
+
+        None
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "falsey".
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(false) == self.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey".
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(true) == self.string_is_truthy(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_PatternTerms_string_is_truthy() {
+        let terms = PatternTerms::from_patterns(&[r"^[Nn]", r"^0+$"], &[r"^[Yy]", r"^\d+$"]).unwrap();
+
+        assert_eq!(Some(false), terms.string_is_truthy("no"));
+        assert_eq!(Some(false), terms.string_is_truthy(" Nope\n"));
+        assert_eq!(Some(false), terms.string_is_truthy("000"));
+        assert_eq!(Some(true), terms.string_is_truthy("yup"));
+        assert_eq!(Some(true), terms.string_is_truthy("007"));
+        assert_eq!(None, terms.string_is_truthy("maybe"));
+        assert_eq!(None, terms.string_is_truthy(""));
+
+        assert!(terms.string_is_falsey("N"));
+        assert!(terms.string_is_truey("Y"));
+        assert!(!terms.string_is_truey("N"));
+    }
+
+    #[test]
+    fn TEST_PatternTerms_new() {
+        let terms = PatternTerms::new(vec![], vec![Regex::new("(?i)^on$").unwrap()]);
+
+        assert_eq!(0, terms.falsey_patterns().len());
+        assert_eq!(1, terms.truey_patterns().len());
+        assert_eq!(Some(true), terms.string_is_truthy("ON"));
+        assert_eq!(None, terms.string_is_truthy("online"));
+    }
+
+    #[test]
+    fn TEST_PatternTerms_from_patterns_INVALID() {
+        assert!(PatternTerms::from_patterns(&["("], &[]).is_err());
+        assert!(PatternTerms::from_patterns(&[], &["[a-"]).is_err());
+    }
+}
@@ -0,0 +1,229 @@
+// predicate_terms.rs - terms supplemented by a user-supplied predicate
+
+use super::{
+    string_is_truthy_with,
+    Terms,
+};
+
+use std::fmt;
+
+
+/// Indicates when the predicate of a [PredicateTerms] is consulted,
+/// relative to the terms.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum PredicateOrder {
+    /// The predicate is consulted first, and the terms only if it does not
+    /// classify the input, so that it may override the terms.
+    BeforeTerms,
+    /// The terms are consulted first, and the predicate only if they do not
+    /// classify the input, so that it may extend the terms.
+    #[default]
+    AfterTerms,
+}
+
+/// Terms supplemented by a predicate, i.e. a closure of type
+/// `Fn(&str) -> Option<bool>`, so that arbitrary custom rules (such as
+/// check digits, prefixes, or database lookups) may participate in
+/// classification.
+///
+/// The predicate is passed the trimmed input, and returns `None` if it
+/// does not classify it.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     PredicateOrder,
+///     PredicateTerms,
+///     Terms,
+/// };
+///
+/// // feature flags of the form "FEAT-<n>" are enabled for even <n>
+/// let terms = PredicateTerms::new(Terms::Default, |s : &str| {
+///     s.strip_prefix("FEAT-").and_then(|n| n.parse::<u32>().ok()).map(|n| 0 == n % 2)
+/// });
+///
+/// # #[cfg(to_be_stock_default)]
+/// assert_eq!(Some(true), terms.string_is_truthy("yes"));
+/// assert_eq!(Some(true), terms.string_is_truthy(" FEAT-42 "));
+/// assert_eq!(Some(false), terms.string_is_truthy("FEAT-7"));
+/// assert_eq!(None, terms.string_is_truthy("FEAT-x"));
+///
+/// // the predicate may instead take precedence over the terms
+/// let terms = PredicateTerms::new(Terms::Default, |s : &str| ("1" == s).then_some(false))
+///     .with_order(PredicateOrder::BeforeTerms);
+///
+/// assert_eq!(Some(false), terms.string_is_truthy("1"));
+/// ```
+#[derive(Clone)]
+pub struct PredicateTerms<'a, F> {
+    terms :     Terms<'a>,
+    predicate : F,
+    order :     PredicateOrder,
+}
+
+impl<'a, F> PredicateTerms<'a, F>
+where
+    F : Fn(&str) -> Option<bool>,
+{
+    /// Creates an instance from the given terms and predicate, where the
+    /// predicate is consulted after the terms.
+    pub fn new(
+        terms : Terms<'a>,
+        predicate : F,
+    ) -> Self {
+        Self {
+            terms,
+            predicate,
+            order : PredicateOrder::default(),
+        }
+    }
+
+    /// Changes when the predicate is consulted, relative to the terms.
+    pub fn with_order(
+        self,
+        order : PredicateOrder,
+    ) -> Self {
+        Self {
+            order,
+            ..self
+        }
+    }
+
+    /// When the predicate is consulted, relative to the terms.
+    pub fn order(&self) -> PredicateOrder {
+        self.order
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy" and,
+    /// if so, whether it is "truey" or "falsey", according to the terms
+    /// and the predicate, in the given order.
+    ///
+    /// # Returns:
+    /// - `None` - string is classified as "truthy" by neither the terms nor
+    ///   the predicate;
+    /// - `Some(false)` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - string (is classified as "truthy" and) is deemed
+    ///   "truey";
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        let s = s.trim();
+
+        match self.order {
+            PredicateOrder::BeforeTerms => (self.predicate)(s).or_else(|| string_is_truthy_with(s, self.terms.clone())),
+            PredicateOrder::AfterTerms => string_is_truthy_with(s, self.terms.clone()).or_else(|| (self.predicate)(s)),
+        }
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "falsey".
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(false) == self.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey".
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        Some(true) == self.string_is_truthy(s)
+    }
+}
+
+impl<F> fmt::Debug for PredicateTerms<'_, F> {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("PredicateTerms")
+            .field("terms", &self.terms)
+            .field("order", &self.order)
+            .finish_non_exhaustive()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    // `is_multiple_of()` is not available in all supported versions of Rust
+    #![allow(clippy::manual_is_multiple_of)]
+
+    use super::*;
+
+
+    /// Indicates whether the given string is a number whose last digit is a
+    /// valid Luhn check digit.
+    fn luhn_(s : &str) -> Option<bool> {
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        let sum : u32 = s
+            .bytes()
+            .rev()
+            .map(|b| u32::from(b - b'0'))
+            .enumerate()
+            .map(|(index, d)| if 1 == index % 2 { (d * 2) / 10 + (d * 2) % 10 } else { d })
+            .sum();
+
+        Some(0 == sum % 10)
+    }
+
+    #[test]
+    fn TEST_PredicateTerms_AfterTerms() {
+        let terms = PredicateTerms::new(
+            Terms::Strings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : &["0"],
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  &[],
+            },
+            luhn_,
+        );
+
+        assert_eq!(PredicateOrder::AfterTerms, terms.order());
+        assert_eq!(Some(false), terms.string_is_truthy("0"));
+        assert_eq!(Some(true), terms.string_is_truthy(" 79927398713 "));
+        assert_eq!(Some(false), terms.string_is_truthy("79927398710"));
+        assert_eq!(None, terms.string_is_truthy("yes"));
+        assert!(terms.string_is_truey("18"));
+        assert!(terms.string_is_falsey("19"));
+    }
+
+    #[test]
+    fn TEST_PredicateTerms_BeforeTerms() {
+        let terms = PredicateTerms::new(
+            Terms::Strings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : &["0"],
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  &["yes"],
+            },
+            luhn_,
+        )
+        .with_order(PredicateOrder::BeforeTerms);
+
+        assert_eq!(Some(true), terms.string_is_truthy("0"));
+        assert_eq!(Some(true), terms.string_is_truthy("YES"));
+        assert_eq!(None, terms.string_is_truthy("no"));
+    }
+
+    #[test]
+    fn TEST_PredicateTerms_Debug() {
+        let terms = PredicateTerms::new(Terms::Default, |_ : &str| None);
+
+        assert_eq!("PredicateTerms { terms: Default, order: AfterTerms, .. }", format!("{terms:?}"));
+    }
+}
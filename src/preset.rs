@@ -0,0 +1,1173 @@
+// preset.rs - built-in vocabularies for particular formats and conventions
+
+//! Built-in vocabularies for particular file formats and conventions.
+//!
+//! The presets are:
+//! - [Preset::Fortran] - `.TRUE.` / `.FALSE.`, and their abbreviations;
+//! - [Preset::Hdl] - the logic values `1` / `H` and `0` / `L`;
+//! - [Preset::Spreadsheet] - `TRUE` / `FALSE`, and `TRUE()` / `FALSE()`;
+//! - [Preset::Sql] - `TRUE` / `FALSE`, and `t` / `f`;
+//! - [Preset::Statistical] - the logical values of R and pandas;
+//! - [Preset::ToBeOrNotToBe] - `"to be"` / `"not to be"`;
+//! - [Preset::XsBoolean] - the lexical space of XML Schema `xs:boolean`;
+//! - [Preset::Yaml11] - the YAML 1.1 boolean type;
+//!
+//! The terms of a preset are obtained, as [Terms], by [Preset::terms()],
+//! and so may be passed to any function that takes terms, such as
+//! [string_is_truthy_with()](crate::string_is_truthy_with()). A preset may
+//! also be selected by name, by [Preset::from_name()], or - along with the
+//! locales - by [terms_for_identifier()].
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::{
+//!     preset::Preset,
+//!     string_is_truthy_with,
+//! };
+//!
+//! let terms = Preset::Yaml11.terms();
+//!
+//! assert_eq!(Some(true), string_is_truthy_with("On", terms.clone()));
+//! assert_eq!(Some(false), string_is_truthy_with("N", terms));
+//!
+//! let terms = Preset::from_name("hamlet").unwrap().terms();
+//!
+//! assert_eq!(Some(false), string_is_truthy_with("Not To Be", terms));
+//! ```
+
+
+use super::{
+    locale::{
+        self,
+        resolve_locale,
+        LocaleFallback,
+    },
+    ChainedTerms,
+    Terms,
+    Truthiness,
+};
+
+
+#[rustfmt::skip]
+pub(crate) mod constants {
+    #![allow(clippy::redundant_static_lifetimes)]
+
+    // NOTE: all `*_PRECISE_STRINGS` must be in sorted order, so that they
+    // may be used as the default vocabulary (which is consumed in binary
+    // search).
+
+    pub(crate) const FORTRAN_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        ".f",
+        ".f.",
+        ".false",
+        ".false.",
+        "f",
+        "false",
+    ];
+
+    pub(crate) const FORTRAN_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        ".t",
+        ".t.",
+        ".true",
+        ".true.",
+        "t",
+        "true",
+    ];
+
+    pub(crate) const HDL_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "0",
+        "l",
+    ];
+
+    pub(crate) const HDL_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "1",
+        "h",
+    ];
+
+    pub(crate) const SPREADSHEET_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "false",
+        "false()",
+    ];
+
+    pub(crate) const SPREADSHEET_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "true",
+        "true()",
+    ];
+
+    pub(crate) const SQL_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "f",
+        "false",
+    ];
+
+    pub(crate) const SQL_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "t",
+        "true",
+    ];
+
+    pub(crate) const STATISTICAL_FALSEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "F",
+        "FALSE",
+        "False",
+        "false",
+    ];
+
+    pub(crate) const STATISTICAL_MISSING_STRINGS : &'static [&'static str] = &[
+        "",
+        "#N/A",
+        "#N/A N/A",
+        "#NA",
+        "-1.#IND",
+        "-1.#QNAN",
+        "-NaN",
+        "-nan",
+        "1.#IND",
+        "1.#QNAN",
+        "<NA>",
+        "N/A",
+        "NA",
+        "NULL",
+        "NaN",
+        "None",
+        "n/a",
+        "nan",
+        "null",
+    ];
+
+    pub(crate) const STATISTICAL_TRUEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "T",
+        "TRUE",
+        "True",
+        "true",
+    ];
+
+    pub(crate) const TO_BE_OR_NOT_TO_BE_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "not to be",
+    ];
+
+    pub(crate) const TO_BE_OR_NOT_TO_BE_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "to be",
+    ];
+
+    pub(crate) const XS_BOOLEAN_FALSEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "0",
+        "false",
+    ];
+
+    pub(crate) const XS_BOOLEAN_TRUEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "1",
+        "true",
+    ];
+
+    pub(crate) const YAML11_FALSEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "FALSE",
+        "False",
+        "N",
+        "NO",
+        "No",
+        "OFF",
+        "Off",
+        "false",
+        "n",
+        "no",
+        "off",
+    ];
+
+    pub(crate) const YAML11_TRUEY_PRECISE_STRINGS : &'static [&'static str] = &[
+        "ON",
+        "On",
+        "TRUE",
+        "True",
+        "Y",
+        "YES",
+        "Yes",
+        "on",
+        "true",
+        "y",
+        "yes",
+    ];
+}
+
+
+/// The localised names of the spreadsheet boolean values (and
+/// functions), by (primary) language. Uppercase forms of non-ASCII
+/// names are given as precise strings, so that they are matched even
+/// without Unicode lowercasing.
+const SPREADSHEET_LOCALES : &[(&str, Terms<'static>)] = &[
+    ("cs", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["nepravda", "nepravda()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["pravda", "pravda()"],
+    }),
+    ("da", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falsk", "falsk()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["sand", "sand()"],
+    }),
+    ("de", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falsch", "falsch()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["wahr", "wahr()"],
+    }),
+    ("es", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falso", "falso()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["verdadero", "verdadero()"],
+    }),
+    ("fi", Terms::Strings {
+        falsey_precise_strings :   &["EPÄTOSI", "EPÄTOSI()"],
+        falsey_lowercase_strings : &["epätosi", "epätosi()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["tosi", "tosi()"],
+    }),
+    ("fr", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["faux", "faux()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["vrai", "vrai()"],
+    }),
+    ("hu", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["hamis", "hamis()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["igaz", "igaz()"],
+    }),
+    ("it", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falso", "falso()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["vero", "vero()"],
+    }),
+    ("nb", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["usann", "usann()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["sann", "sann()"],
+    }),
+    ("nl", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["onwaar", "onwaar()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["waar", "waar()"],
+    }),
+    ("pl", Terms::Strings {
+        falsey_precise_strings :   &["FAŁSZ", "FAŁSZ()"],
+        falsey_lowercase_strings : &["fałsz", "fałsz()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["prawda", "prawda()"],
+    }),
+    ("pt", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falso", "falso()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["verdadeiro", "verdadeiro()"],
+    }),
+    ("ru", Terms::Strings {
+        falsey_precise_strings :   &["ЛОЖЬ", "ЛОЖЬ()"],
+        falsey_lowercase_strings : &["ложь", "ложь()"],
+        truey_precise_strings :    &["ИСТИНА", "ИСТИНА()"],
+        truey_lowercase_strings :  &["истина", "истина()"],
+    }),
+    ("sv", Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["falskt", "falskt()"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["sant", "sant()"],
+    }),
+    ("tr", Terms::Strings {
+        falsey_precise_strings :   &["YANLIŞ", "YANLIŞ()"],
+        falsey_lowercase_strings : &["yanlış", "yanlış()"],
+        truey_precise_strings :    &["DOĞRU", "DOĞRU()"],
+        truey_lowercase_strings :  &["doğru", "doğru()"],
+    }),
+];
+
+/// A built-in vocabulary for a particular file format or convention.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum Preset {
+    /// The logical constants of Fortran, as emitted in namelist files -
+    /// `.TRUE.` and `.FALSE.` - along with the forms in which they are
+    /// commonly abbreviated - `.T.`, `.F.`, `T`, `F`, and so on - which are
+    /// matched case-insensitively.
+    ///
+    /// # Note:
+    /// Fortran input accepts any characters following the `T` or `F`
+    /// (e.g. `.Tomato`), which terms cannot express; see
+    /// [parse_fortran_logical()] for parsing according to the rules of
+    /// list-directed input.
+    Fortran,
+    /// The logic values of hardware description languages (such as VHDL's
+    /// `std_logic` and Verilog), in which `1` and `H` (weak high) are
+    /// "truey" and `0` and `L` (weak low) are "falsey", which are matched
+    /// case-insensitively.
+    ///
+    /// # Note:
+    /// As terms are two-valued, the indeterminate values (e.g. `X`, `Z`)
+    /// are not "truthy"; see [parse_hdl_logic()] for four-state parsing.
+    Hdl,
+    /// The boolean values of spreadsheets (such as Excel), as exported to
+    /// CSV - `TRUE` and `FALSE` - along with their formula forms `TRUE()`
+    /// and `FALSE()`, which are matched case-insensitively.
+    ///
+    /// # Note:
+    /// For the localised names (e.g. `WAHR` / `FALSCH`) of spreadsheets
+    /// in other languages, see [spreadsheet_terms_for_locale()].
+    Spreadsheet,
+    /// The SQL boolean literals `TRUE` and `FALSE`, along with the `t` and
+    /// `f` with which some databases (e.g. PostgreSQL) render booleans,
+    /// which are matched case-insensitively.
+    ///
+    /// # Note:
+    /// As terms are two-valued, the literal `UNKNOWN` is not "truthy"; see
+    /// [parse_sql_boolean()] for three-valued parsing.
+    Sql,
+    /// The logical values of statistical data, as exported by R (`TRUE`,
+    /// `FALSE`, `T`, `F`) and pandas (`True`, `False`), along with the
+    /// lowercase `true` and `false`, which are matched case-sensitively.
+    ///
+    /// # Note:
+    /// As terms are two-valued, the missing-value markers (e.g. `NA`,
+    /// `NaN`) are not "truthy"; see [parse_statistical_boolean()] for
+    /// parsing that distinguishes missing values from malformed ones.
+    Statistical,
+    /// The question posed by Hamlet, in which `"to be"` is "truey" and
+    /// `"not to be"` is "falsey", which are matched case-insensitively.
+    ///
+    /// # Note:
+    /// As with all terms, the phrases are matched in their entirety, with
+    /// exactly one space between words.
+    ToBeOrNotToBe,
+    /// The lexical space of the XML Schema `xs:boolean` type (`true|false|
+    /// 1|0`), which is matched case-sensitively.
+    ///
+    /// # Note:
+    /// As with all terms, the string is trimmed of all (Unicode) whitespace
+    /// before matching, rather than only of XML whitespace, as would a
+    /// schema processor; see [parse_xs_boolean()] for exact validation.
+    XsBoolean,
+    /// The YAML 1.1 boolean type (`y|Y|yes|Yes|YES|n|N|no|No|NO|true|True|
+    /// TRUE|false|False|FALSE|on|On|ON|off|Off|OFF`), which is matched
+    /// case-sensitively.
+    Yaml11,
+}
+
+impl Preset {
+    /// All presets.
+    pub const ALL : &'static [Self] = &[
+        Self::Fortran,
+        Self::Hdl,
+        Self::Spreadsheet,
+        Self::Sql,
+        Self::Statistical,
+        Self::ToBeOrNotToBe,
+        Self::XsBoolean,
+        Self::Yaml11,
+    ];
+
+    /// The aliases by which presets may also be named, in sorted order.
+    pub const ALIASES : &'static [(&'static str, Self)] = &[
+        ("excel", Self::Spreadsheet),
+        ("f90", Self::Fortran),
+        ("hamlet", Self::ToBeOrNotToBe),
+        ("pandas", Self::Statistical),
+        ("r", Self::Statistical),
+        ("verilog", Self::Hdl),
+        ("vhdl", Self::Hdl),
+        ("xsd", Self::XsBoolean),
+        ("yaml-1.1", Self::Yaml11),
+    ];
+
+    /// Obtains the preset of the given name, which is matched
+    /// case-insensitively.
+    ///
+    /// The names are those returned by [Preset::name()], along with those
+    /// in [Preset::ALIASES] (e.g. `"excel"` for [Preset::Spreadsheet]).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::preset::Preset;
+    ///
+    /// assert_eq!(Some(Preset::Yaml11), Preset::from_name("YAML11"));
+    /// assert_eq!(Some(Preset::ToBeOrNotToBe), Preset::from_name("hamlet"));
+    /// assert_eq!(None, Preset::from_name("toml"));
+    /// ```
+    pub fn from_name(name : &str) -> Option<Self> {
+        Self::ALL
+            .iter()
+            .map(|&preset| (preset.name(), preset))
+            .chain(Self::ALIASES.iter().copied())
+            .find(|(candidate, _)| name.eq_ignore_ascii_case(candidate))
+            .map(|(_, preset)| preset)
+    }
+
+    /// Obtains all names by which presets may be obtained from
+    /// [Preset::from_name()]: the name of each preset, in the order of
+    /// [Preset::ALL], followed by the aliases.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::preset::Preset;
+    ///
+    /// let names : Vec<&str> = Preset::names().collect();
+    ///
+    /// assert!(names.contains(&"yaml11"));
+    /// assert!(names.contains(&"excel"));
+    /// ```
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        Self::ALL
+            .iter()
+            .map(Self::name)
+            .chain(Self::ALIASES.iter().map(|&(alias, _)| alias))
+    }
+
+    /// The name of the preset.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Fortran => "fortran",
+            Self::Hdl => "hdl",
+            Self::Spreadsheet => "spreadsheet",
+            Self::Sql => "sql",
+            Self::Statistical => "statistical",
+            Self::ToBeOrNotToBe => "to-be-or-not-to-be",
+            Self::XsBoolean => "xs-boolean",
+            Self::Yaml11 => "yaml11",
+        }
+    }
+
+    /// Obtains the terms of the preset.
+    pub fn terms(&self) -> Terms<'static> {
+        match self {
+            Self::Fortran => Terms::SortedStrings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : constants::FORTRAN_FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  constants::FORTRAN_TRUEY_LOWERCASE_STRINGS,
+            },
+            Self::Hdl => Terms::SortedStrings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : constants::HDL_FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  constants::HDL_TRUEY_LOWERCASE_STRINGS,
+            },
+            Self::Spreadsheet => Terms::SortedStrings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : constants::SPREADSHEET_FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  constants::SPREADSHEET_TRUEY_LOWERCASE_STRINGS,
+            },
+            Self::Sql => Terms::SortedStrings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : constants::SQL_FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  constants::SQL_TRUEY_LOWERCASE_STRINGS,
+            },
+            Self::Statistical => Terms::SortedStrings {
+                falsey_precise_strings :   constants::STATISTICAL_FALSEY_PRECISE_STRINGS,
+                falsey_lowercase_strings : &[],
+                truey_precise_strings :    constants::STATISTICAL_TRUEY_PRECISE_STRINGS,
+                truey_lowercase_strings :  &[],
+            },
+            Self::ToBeOrNotToBe => Terms::SortedStrings {
+                falsey_precise_strings :   &[],
+                falsey_lowercase_strings : constants::TO_BE_OR_NOT_TO_BE_FALSEY_LOWERCASE_STRINGS,
+                truey_precise_strings :    &[],
+                truey_lowercase_strings :  constants::TO_BE_OR_NOT_TO_BE_TRUEY_LOWERCASE_STRINGS,
+            },
+            Self::XsBoolean => Terms::SortedStrings {
+                falsey_precise_strings :   constants::XS_BOOLEAN_FALSEY_PRECISE_STRINGS,
+                falsey_lowercase_strings : &[],
+                truey_precise_strings :    constants::XS_BOOLEAN_TRUEY_PRECISE_STRINGS,
+                truey_lowercase_strings :  &[],
+            },
+            Self::Yaml11 => Terms::SortedStrings {
+                falsey_precise_strings :   constants::YAML11_FALSEY_PRECISE_STRINGS,
+                falsey_lowercase_strings : &[],
+                truey_precise_strings :    constants::YAML11_TRUEY_PRECISE_STRINGS,
+                truey_lowercase_strings :  &[],
+            },
+        }
+    }
+}
+
+/// Obtains the terms identified by the given identifier, which is either
+/// the name of a preset (as matched by [Preset::from_name()]) or the tag of
+/// a locale (as resolved by [resolve_locale()], with fallback from, say,
+/// `"fr-CA"` to `"fr"`), so that the vocabulary may be chosen by a single
+/// setting in, say, a configuration file.
+///
+/// Preset names are matched first, so, e.g., `"r"` identifies
+/// [Preset::Statistical] rather than any locale.
+///
+/// # Returns:
+/// - `None` - identifier is neither a preset name nor the tag of a locale
+///   for which terms (or those of a more general locale) are registered;
+/// - `Some(terms)` - the terms;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     preset::terms_for_identifier,
+///     string_is_truthy_with,
+/// };
+///
+/// let terms = terms_for_identifier("yaml11").unwrap();
+///
+/// assert_eq!(Some(true), string_is_truthy_with("On", terms));
+///
+/// let terms = terms_for_identifier("en-GB").unwrap();
+///
+/// assert_eq!(Some(false), string_is_truthy_with("no", terms));
+///
+/// assert!(terms_for_identifier("toml").is_none());
+/// ```
+pub fn terms_for_identifier(identifier : &str) -> Option<Terms<'static>> {
+    if let Some(preset) = Preset::from_name(identifier) {
+        return Some(preset.terms());
+    }
+
+    let resolved = resolve_locale(identifier);
+
+    match resolved.level {
+        LocaleFallback::Default => None,
+        _ => Some(resolved.terms),
+    }
+}
+
+/// Obtains all identifiers understood by [terms_for_identifier()]: the
+/// names of the presets (as by [Preset::names()]), followed by the tags of
+/// the available locales (as by
+/// [available_locales()](crate::locale::available_locales())).
+///
+/// # Note:
+/// Tags of more specific locales (e.g. `"fr-CA"`) that fall back to one
+/// of the available locales are also understood, but are not listed.
+pub fn available_identifiers() -> impl Iterator<Item = &'static str> {
+    Preset::names().chain(locale::available_locales())
+}
+
+/// Parses the given string as a Fortran logical value, according to the
+/// rules of list-directed (and namelist) input: once stripped of leading
+/// and trailing blanks, the string comprises an optional period, followed
+/// by `T` or `F` (in either case), followed by any characters other than
+/// the value separators (blank, comma, slash, and equals sign).
+///
+/// # Returns:
+/// - `None` - string is not a Fortran logical value;
+/// - `Some(false)` - string is false, e.g. `".FALSE."`, `"F"`;
+/// - `Some(true)` - string is true, e.g. `".TRUE."`, `".t"`;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::preset::parse_fortran_logical;
+///
+/// assert_eq!(Some(true), parse_fortran_logical(".TRUE."));
+/// assert_eq!(Some(false), parse_fortran_logical(" .F. "));
+/// assert_eq!(Some(true), parse_fortran_logical("Tomato"));
+/// assert_eq!(None, parse_fortran_logical(".yes."));
+/// assert_eq!(None, parse_fortran_logical(".T.,"));
+/// ```
+pub fn parse_fortran_logical(s : &str) -> Option<bool> {
+    let s = s.trim();
+    let s = s.strip_prefix('.').unwrap_or(s);
+
+    let mut chars = s.chars();
+
+    let b = match chars.next()? {
+        'F' | 'f' => false,
+        'T' | 't' => true,
+        _ => return None,
+    };
+
+    if chars.any(|c| c.is_whitespace() || [',', '/', '='].contains(&c)) {
+        None
+    } else {
+        Some(b)
+    }
+}
+
+/// Parses the given string, when trimmed, as a (four-state) HDL logic
+/// value: a single character - optionally quoted as a character literal,
+/// e.g. `'1'` - of the nine values of VHDL's `std_logic` (a superset of
+/// those of Verilog), in either case.
+///
+/// # Returns:
+/// - `None` - string is not an HDL logic value;
+/// - `Some(Truthiness::False)` - string is `0` or `L` (weak low);
+/// - `Some(Truthiness::Unknown)` - string is indeterminate: `X` (unknown),
+///   `Z` (high impedance), `U` (uninitialised), `W` (weak unknown), or `-`
+///   (don't care);
+/// - `Some(Truthiness::True)` - string is `1` or `H` (weak high);
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     preset::parse_hdl_logic,
+///     Truthiness,
+/// };
+///
+/// assert_eq!(Some(Truthiness::True), parse_hdl_logic("H"));
+/// assert_eq!(Some(Truthiness::False), parse_hdl_logic("'0'"));
+/// assert_eq!(Some(Truthiness::Unknown), parse_hdl_logic("x"));
+/// assert_eq!(None, parse_hdl_logic("2"));
+/// ```
+pub fn parse_hdl_logic(s : &str) -> Option<Truthiness> {
+    let s = s.trim();
+    let s = s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')).unwrap_or(s);
+
+    match s.as_bytes() {
+        [b'0' | b'L' | b'l'] => Some(Truthiness::False),
+        [b'1' | b'H' | b'h'] => Some(Truthiness::True),
+        [b'X' | b'x' | b'Z' | b'z' | b'U' | b'u' | b'W' | b'w' | b'-'] => Some(Truthiness::Unknown),
+        _ => None,
+    }
+}
+
+/// Obtains the spreadsheet terms for the given locale: the localised
+/// names of the boolean values (and functions) of spreadsheets in the
+/// locale's language - e.g. `WAHR` / `FALSCH` (and `WAHR()` / `FALSCH()`)
+/// for German - followed by those of [Preset::Spreadsheet], which are
+/// used in exports regardless of locale.
+///
+/// The tag is matched by its (primary) language subtag, case-insensitively,
+/// so, e.g., `"de-AT"` and `"de_CH"` obtain the terms for `"de"`. The
+/// languages are Czech, Danish, Dutch, English, Finnish, French, German,
+/// Hungarian, Italian, Norwegian (Bokmål), Polish, Portuguese, Russian,
+/// Spanish, Swedish, and Turkish.
+///
+/// # Returns:
+/// - `None` - the language of the given locale is not known;
+/// - `Some(terms)` - the terms for the given locale;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::preset::spreadsheet_terms_for_locale;
+///
+/// let terms = spreadsheet_terms_for_locale("de-DE").unwrap();
+///
+/// assert_eq!(Some(true), terms.string_is_truthy("WAHR"));
+/// assert_eq!(Some(false), terms.string_is_truthy("FALSCH()"));
+/// assert_eq!(Some(true), terms.string_is_truthy("TRUE"));
+/// assert_eq!(None, terms.string_is_truthy("VRAI"));
+/// ```
+pub fn spreadsheet_terms_for_locale(tag : &str) -> Option<ChainedTerms<'static>> {
+    let language = tag.split(['-', '_']).next().unwrap_or(tag);
+    let terms = ChainedTerms::default();
+
+    if language.eq_ignore_ascii_case("en") {
+        return Some(terms.then(Preset::Spreadsheet.terms()));
+    }
+
+    SPREADSHEET_LOCALES
+        .iter()
+        .find(|(language_tag, _)| language.eq_ignore_ascii_case(language_tag))
+        .map(|(_, locale_terms)| terms.then(locale_terms.clone()).then(Preset::Spreadsheet.terms()))
+}
+
+/// Parses the given string as a value of the XML Schema `xs:boolean`
+/// type, exactly as would a schema processor: the string, once stripped of
+/// leading and trailing XML whitespace (space, tab, carriage return, and
+/// line feed), must be one of `"true"`, `"false"`, `"1"`, or `"0"`.
+///
+/// # Returns:
+/// - `None` - string is not in the lexical space of `xs:boolean`;
+/// - `Some(false)` - string is `"false"` or `"0"`;
+/// - `Some(true)` - string is `"true"` or `"1"`;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::preset::parse_xs_boolean;
+///
+/// assert_eq!(Some(true), parse_xs_boolean("\n  true\t"));
+/// assert_eq!(Some(false), parse_xs_boolean("0"));
+/// assert_eq!(None, parse_xs_boolean("True"));
+/// assert_eq!(None, parse_xs_boolean("\u{A0}1"));
+/// ```
+pub fn parse_xs_boolean(s : &str) -> Option<bool> {
+    match s.trim_matches([' ', '\t', '\r', '\n']) {
+        "0" | "false" => Some(false),
+        "1" | "true" => Some(true),
+        _ => None,
+    }
+}
+
+/// Parses the given string, when trimmed, as an SQL boolean value,
+/// according to three-valued logic, in which `UNKNOWN` - and `NULL`, which
+/// is the same value of the boolean type - is neither true nor false.
+///
+/// The keywords `TRUE`, `FALSE`, `UNKNOWN`, and `NULL` are matched
+/// case-insensitively, as are `t` and `f` (as rendered by some databases,
+/// e.g. PostgreSQL). `TRUE`, `FALSE`, `t`, and `f` may also be given as
+/// (single-quoted) string literals, e.g. `'t'`.
+///
+/// # Returns:
+/// - `None` - string is not an SQL boolean value;
+/// - `Some(Truthiness::False)` - string is false;
+/// - `Some(Truthiness::Unknown)` - string is unknown;
+/// - `Some(Truthiness::True)` - string is true;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     preset::parse_sql_boolean,
+///     Truthiness,
+/// };
+///
+/// assert_eq!(Some(Truthiness::True), parse_sql_boolean("TRUE"));
+/// assert_eq!(Some(Truthiness::False), parse_sql_boolean("'f'"));
+/// assert_eq!(Some(Truthiness::Unknown), parse_sql_boolean("Unknown"));
+/// assert_eq!(None, parse_sql_boolean("maybe"));
+///
+/// let value = parse_sql_boolean("null").unwrap();
+///
+/// assert_eq!("UNKNOWN", value.to_sql_literal());
+/// ```
+pub fn parse_sql_boolean(s : &str) -> Option<Truthiness> {
+    let is_any_of = |s : &str, words : &[&str]| words.iter().any(|word| s.eq_ignore_ascii_case(word));
+
+    let s = s.trim();
+
+    if is_any_of(s, &["unknown", "null"]) {
+        return Some(Truthiness::Unknown);
+    }
+
+    let s = s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')).unwrap_or(s);
+
+    if is_any_of(s, &["f", "false"]) {
+        Some(Truthiness::False)
+    } else if is_any_of(s, &["t", "true"]) {
+        Some(Truthiness::True)
+    } else {
+        None
+    }
+}
+
+/// Parses the given string, when trimmed, as a logical value of
+/// statistical data, in which a missing value is neither true nor false.
+///
+/// The logical values are those of [Preset::Statistical], and the
+/// missing-value markers are R's `NA` and those recognised by default by
+/// pandas (`""`, `"NA"`, `"N/A"`, `"n/a"`, `"NaN"`, `"nan"`, `"-NaN"`,
+/// `"NULL"`, `"null"`, `"None"`, `"<NA>"`, `"#N/A"`, and so on), all of
+/// which are matched case-sensitively.
+///
+/// # Returns:
+/// - `None` - string is neither a logical value nor a missing-value
+///   marker, i.e. it is malformed;
+/// - `Some(Truthiness::False)` - string is false;
+/// - `Some(Truthiness::Unknown)` - string is a missing value;
+/// - `Some(Truthiness::True)` - string is true;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     preset::parse_statistical_boolean,
+///     Truthiness,
+/// };
+///
+/// assert_eq!(Some(Truthiness::True), parse_statistical_boolean("T"));
+/// assert_eq!(Some(Truthiness::False), parse_statistical_boolean("FALSE"));
+/// assert_eq!(Some(Truthiness::Unknown), parse_statistical_boolean("NA"));
+/// assert_eq!(Some(Truthiness::Unknown), parse_statistical_boolean(""));
+/// assert_eq!(None, parse_statistical_boolean("yes"));
+/// ```
+pub fn parse_statistical_boolean(s : &str) -> Option<Truthiness> {
+    let s = s.trim();
+
+    if constants::STATISTICAL_FALSEY_PRECISE_STRINGS.binary_search(&s).is_ok() {
+        Some(Truthiness::False)
+    } else if constants::STATISTICAL_TRUEY_PRECISE_STRINGS.binary_search(&s).is_ok() {
+        Some(Truthiness::True)
+    } else if constants::STATISTICAL_MISSING_STRINGS.binary_search(&s).is_ok() {
+        Some(Truthiness::Unknown)
+    } else {
+        None
+    }
+}
+
+impl Truthiness {
+    /// The SQL boolean literal - `TRUE`, `FALSE`, or `UNKNOWN` - of the
+    /// value, such that [parse_sql_boolean()] obtains the value from it.
+    pub fn to_sql_literal(&self) -> &'static str {
+        match self {
+            Self::False => "FALSE",
+            Self::Unknown => "UNKNOWN",
+            Self::True => "TRUE",
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use crate::string_is_truthy_with;
+
+
+    #[test]
+    fn TEST_Preset_constants_ARE_SORTED() {
+        for strings in [
+            constants::STATISTICAL_FALSEY_PRECISE_STRINGS,
+            constants::STATISTICAL_MISSING_STRINGS,
+            constants::STATISTICAL_TRUEY_PRECISE_STRINGS,
+            constants::XS_BOOLEAN_FALSEY_PRECISE_STRINGS,
+            constants::XS_BOOLEAN_TRUEY_PRECISE_STRINGS,
+            constants::YAML11_FALSEY_PRECISE_STRINGS,
+            constants::YAML11_TRUEY_PRECISE_STRINGS,
+        ] {
+            assert!(strings.windows(2).all(|w| w[0] < w[1]));
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_Yaml11() {
+        let terms = Preset::Yaml11.terms();
+
+        for s in ["n", "N", "no", "No", "NO", "false", "False", "FALSE", "off", "Off", "OFF", " no "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["y", "Y", "yes", "Yes", "YES", "true", "True", "TRUE", "on", "On", "ON", " yes "] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "0", "1", "nO", "yEs", "tRUE", "oN", "enabled"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_ToBeOrNotToBe() {
+        let terms = Preset::ToBeOrNotToBe.terms();
+
+        for s in ["not to be", "Not to be", "NOT TO BE", " not to be "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["to be", "To be", "TO BE", "\tto be\n"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "to", "be", "tobe", "to  be", "to be, or not to be", "yes", "no"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_Fortran() {
+        let terms = Preset::Fortran.terms();
+
+        for s in [".FALSE.", ".false.", ".F.", ".f", "F", "False", ".False", " .FALSE. "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in [".TRUE.", ".true.", ".T.", ".t", "T", "TRUE", ".True", "\t.T.\n"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", ".", "..", "1", "0", ".yes.", "..TRUE..", "TRUE.", ".Tomato"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_parse_fortran_logical() {
+        for s in [".FALSE.", ".false.", ".F.", ".f", "F", "false", "FALSE.", " .Fxyz", "F.", ".f.."] {
+            assert_eq!(Some(false), parse_fortran_logical(s), "'{s}'");
+        }
+
+        for s in [".TRUE.", ".true.", ".T.", ".t", "T", "true", "\t.TRUE.\n", "Tomato", ".T..T"] {
+            assert_eq!(Some(true), parse_fortran_logical(s), "'{s}'");
+        }
+
+        for s in ["", " ", ".", "..T", "1", "0", "yes", ".T.,", "T/", "T=1", ".T. x", ". T"] {
+            assert_eq!(None, parse_fortran_logical(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_Hdl() {
+        let terms = Preset::Hdl.terms();
+
+        for s in ["0", "L", "l", " L "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["1", "H", "h", "\tH\n"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "X", "Z", "U", "W", "-", "'1'", "10", "high"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_parse_hdl_logic() {
+        for s in ["0", "L", "l", "'0'", " 'L' "] {
+            assert_eq!(Some(Truthiness::False), parse_hdl_logic(s), "'{s}'");
+        }
+
+        for s in ["X", "x", "Z", "z", "U", "u", "W", "w", "-", "'X'", " z "] {
+            assert_eq!(Some(Truthiness::Unknown), parse_hdl_logic(s), "'{s}'");
+        }
+
+        for s in ["1", "H", "h", "'1'", "\t1\n"] {
+            assert_eq!(Some(Truthiness::True), parse_hdl_logic(s), "'{s}'");
+        }
+
+        for s in ["", "''", "'", "2", "10", "'1", "\"1\"", "XX", "high", "1'b1", "\u{0}"] {
+            assert_eq!(None, parse_hdl_logic(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_Spreadsheet() {
+        let terms = Preset::Spreadsheet.terms();
+
+        for s in ["FALSE", "False", "false", "FALSE()", "false()", " FALSE "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["TRUE", "True", "true", "TRUE()", "True()"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "1", "0", "yes", "TRUE( )", "=TRUE()", "WAHR"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_spreadsheet_terms_for_locale() {
+        for (tag, locale_terms) in SPREADSHEET_LOCALES {
+            let terms = spreadsheet_terms_for_locale(tag).unwrap();
+
+            let Terms::Strings {
+                falsey_lowercase_strings,
+                truey_lowercase_strings,
+                ..
+            } = locale_terms
+            else {
+                unreachable!()
+            };
+
+            for (strings, expected) in [(falsey_lowercase_strings, false), (truey_lowercase_strings, true)] {
+                for s in strings.iter() {
+                    assert_eq!(Some(expected), terms.string_is_truthy(s), "{tag}: '{s}'");
+                    assert_eq!(Some(expected), terms.string_is_truthy(&s.to_uppercase()), "{tag}: '{s}'");
+                }
+            }
+
+            assert_eq!(Some(true), terms.string_is_truthy("TRUE"), "{tag}");
+            assert_eq!(Some(false), terms.string_is_truthy("false()"), "{tag}");
+        }
+
+        let terms = spreadsheet_terms_for_locale("EN_gb").unwrap();
+
+        assert_eq!(1, terms.term_sets().len());
+        assert_eq!(Some(true), terms.string_is_truthy("TRUE()"));
+
+        let terms = spreadsheet_terms_for_locale("fr-CA").unwrap();
+
+        assert_eq!(Some(false), terms.string_is_truthy("Faux"));
+        assert_eq!(None, terms.string_is_truthy("WAHR"));
+
+        assert!(spreadsheet_terms_for_locale("xx").is_none());
+        assert!(spreadsheet_terms_for_locale("").is_none());
+    }
+
+    #[test]
+    fn TEST_Preset_Sql() {
+        let terms = Preset::Sql.terms();
+
+        for s in ["f", "F", "false", "FALSE", " False "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["t", "T", "true", "TRUE", "\tTrue\n"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "unknown", "UNKNOWN", "null", "1", "0", "yes", "'t'"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_parse_sql_boolean() {
+        for s in ["f", "F", "false", "FALSE", "'f'", "'FALSE'", " 'false' "] {
+            assert_eq!(Some(Truthiness::False), parse_sql_boolean(s), "'{s}'");
+        }
+
+        for s in ["unknown", "UNKNOWN", " Unknown ", "null", "NULL"] {
+            assert_eq!(Some(Truthiness::Unknown), parse_sql_boolean(s), "'{s}'");
+        }
+
+        for s in ["t", "T", "true", "TRUE", "'t'", "'True'"] {
+            assert_eq!(Some(Truthiness::True), parse_sql_boolean(s), "'{s}'");
+        }
+
+        for s in ["", "''", "'unknown'", "'null'", "1", "0", "yes", "'t", "t'", "\"t\"", "unknow"] {
+            assert_eq!(None, parse_sql_boolean(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Truthiness_to_sql_literal() {
+        for truthiness in [Truthiness::False, Truthiness::Unknown, Truthiness::True] {
+            assert_eq!(Some(truthiness), parse_sql_boolean(truthiness.to_sql_literal()));
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_Statistical() {
+        let terms = Preset::Statistical.terms();
+
+        for s in ["F", "FALSE", "False", "false", " F "] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["T", "TRUE", "True", "true", "\tT\n"] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "NA", "NaN", "t", "f", "fALSE", "1", "0", "yes"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_parse_statistical_boolean() {
+        for s in ["F", "FALSE", "False", "false", " FALSE "] {
+            assert_eq!(Some(Truthiness::False), parse_statistical_boolean(s), "'{s}'");
+        }
+
+        for s in constants::STATISTICAL_MISSING_STRINGS.iter().copied().chain([" ", " NA\r\n"]) {
+            assert_eq!(Some(Truthiness::Unknown), parse_statistical_boolean(s), "'{s}'");
+        }
+
+        for s in ["T", "TRUE", "True", "true"] {
+            assert_eq!(Some(Truthiness::True), parse_statistical_boolean(s), "'{s}'");
+        }
+
+        for s in ["t", "f", "tRUE", "na", "NAN", "yes", "0", "1", "N A"] {
+            assert_eq!(None, parse_statistical_boolean(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_XsBoolean() {
+        let terms = Preset::XsBoolean.terms();
+
+        for s in ["0", "false", " false\n"] {
+            assert_eq!(Some(false), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["1", "true", "\ttrue "] {
+            assert_eq!(Some(true), string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+
+        for s in ["", "True", "FALSE", "yes", "no", "01", "+1", "1.0", "t"] {
+            assert_eq!(None, string_is_truthy_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_parse_xs_boolean() {
+        for s in ["0", "false", " \t\r\nfalse\r\n"] {
+            assert_eq!(Some(false), parse_xs_boolean(s), "'{s}'");
+        }
+
+        for s in ["1", "true", "\n1 "] {
+            assert_eq!(Some(true), parse_xs_boolean(s), "'{s}'");
+        }
+
+        for s in ["", " ", "True", "TRUE", "yes", "01", "+1", "1.0", "t r u e"] {
+            assert_eq!(None, parse_xs_boolean(s), "'{s}'");
+        }
+
+        // only XML whitespace is stripped
+
+        for s in ["\u{A0}true", "true\u{2003}", "\u{0B}0", "\u{0C}1"] {
+            assert_eq!(None, parse_xs_boolean(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_Preset_from_name() {
+        for &preset in Preset::ALL {
+            assert_eq!(Some(preset), Preset::from_name(preset.name()));
+            assert_eq!(Some(preset), Preset::from_name(&preset.name().to_uppercase()));
+        }
+
+        assert_eq!(Some(Preset::ToBeOrNotToBe), Preset::from_name("Hamlet"));
+        assert_eq!(Some(Preset::Fortran), Preset::from_name("F90"));
+        assert_eq!(Some(Preset::Hdl), Preset::from_name("VHDL"));
+        assert_eq!(Some(Preset::Hdl), Preset::from_name("verilog"));
+        assert_eq!(Some(Preset::Spreadsheet), Preset::from_name("Excel"));
+        assert_eq!(Some(Preset::Sql), Preset::from_name("SQL"));
+        assert_eq!(Some(Preset::Statistical), Preset::from_name("R"));
+        assert_eq!(Some(Preset::Statistical), Preset::from_name("pandas"));
+        assert_eq!(Some(Preset::XsBoolean), Preset::from_name("XSD"));
+        assert_eq!(Some(Preset::Yaml11), Preset::from_name("yaml-1.1"));
+        assert_eq!(None, Preset::from_name(""));
+        assert_eq!(None, Preset::from_name("yaml"));
+        assert_eq!(None, Preset::from_name(" sql"));
+
+        for &(alias, preset) in Preset::ALIASES {
+            assert_eq!(Some(preset), Preset::from_name(alias));
+        }
+
+        assert!(Preset::ALIASES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn TEST_Preset_names() {
+        let names : Vec<&str> = Preset::names().collect();
+
+        assert_eq!(Preset::ALL.len() + Preset::ALIASES.len(), names.len());
+
+        for name in names {
+            assert!(Preset::from_name(name).is_some(), "'{name}'");
+        }
+    }
+
+    #[test]
+    fn TEST_terms_for_identifier() {
+        let terms = terms_for_identifier("Hamlet").unwrap();
+
+        assert_eq!(Some(true), string_is_truthy_with("to be", terms));
+
+        let terms = terms_for_identifier("en_AU").unwrap();
+
+        assert_eq!(Some(true), string_is_truthy_with("yes", terms));
+
+        for identifier in available_identifiers() {
+            assert!(terms_for_identifier(identifier).is_some(), "'{identifier}'");
+        }
+
+        assert!(terms_for_identifier("").is_none());
+        assert!(terms_for_identifier("tlh").is_none());
+    }
+}
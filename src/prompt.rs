@@ -0,0 +1,254 @@
+// prompt.rs - interactive yes/no prompts
+
+//! Interactive confirmation prompts, which ask a yes/no question - with a
+//! `[y/N]`-style suffix derived from the default vocabulary - and classify
+//! the answer.
+//!
+//! # Example:
+//!
+//! ```no_run
+//! use to_be::prompt::{
+//!     confirm,
+//!     ConfirmDefault,
+//! };
+//!
+//! // prints "Proceed? [y/N] " (for the stock terms) and reads the answer
+//! if confirm("Proceed?", ConfirmDefault::No).unwrap() {
+//!     // ...
+//! }
+//! ```
+
+use super::{
+    defaults,
+    fmt::BoolStyle,
+    locale,
+    string_is_truthy_with_strictness,
+    Strictness,
+};
+
+use std::io::{
+    self,
+    BufRead,
+    Write,
+};
+
+
+/// The answer assumed when the answer to a prompt is empty.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum ConfirmDefault {
+    /// There is no default, so an empty answer is re-prompted.
+    #[default]
+    None,
+    /// An empty answer is taken as "yes".
+    Yes,
+    /// An empty answer is taken as "no".
+    No,
+}
+
+/// Obtains the style of the default vocabulary, as selected by the
+/// `"default-locale-*"` features, or [BoolStyle::YES_NO] otherwise.
+fn default_style_() -> BoolStyle<'static> {
+    defaults::DEFAULT_LOCALE_TAG
+        .and_then(locale::bool_style_for_locale)
+        .unwrap_or(BoolStyle::YES_NO)
+}
+
+/// Obtains the first character of the given term, in the given case.
+fn initial_(
+    term : &str,
+    uppercase : bool,
+) -> String {
+    let c = term.chars().next().unwrap_or('?');
+
+    if uppercase {
+        c.to_uppercase().collect()
+    } else {
+        c.to_lowercase().collect()
+    }
+}
+
+/// Obtains the suffix of the prompt - e.g. `"[y/N]"` - for the given style
+/// and default.
+fn suffix_(
+    style : &BoolStyle,
+    default : ConfirmDefault,
+) -> String {
+    format!(
+        "[{}/{}]",
+        initial_(style.truey, ConfirmDefault::Yes == default),
+        initial_(style.falsey, ConfirmDefault::No == default)
+    )
+}
+
+/// Classifies the given (trimmed, non-empty) answer, which may be the
+/// initial of either term of the given style (provided that they differ)
+/// or any string recognised leniently (see [Strictness::Lenient]).
+fn classify_answer_(
+    answer : &str,
+    style : &BoolStyle,
+) -> Option<bool> {
+    let answer_initial = answer.to_lowercase();
+    let truey_initial = initial_(style.truey, false);
+    let falsey_initial = initial_(style.falsey, false);
+
+    if truey_initial != falsey_initial {
+        if answer_initial == truey_initial {
+            return Some(true);
+        }
+        if answer_initial == falsey_initial {
+            return Some(false);
+        }
+    }
+
+    string_is_truthy_with_strictness(answer, Strictness::Lenient)
+}
+
+/// Asks the given question, on the standard output, and reads the answer,
+/// from the standard input, re-prompting until the answer is recognised.
+///
+/// The prompt comprises the question followed by a suffix - e.g. `"[y/N]"`,
+/// for the stock terms and [ConfirmDefault::No] - formed from the initials
+/// of the canonical terms of the default vocabulary, in which the initial
+/// of the default answer, if any, is uppercase. An answer is recognised if
+/// it is either of these initials, or is recognised leniently (see
+/// [Strictness::Lenient]), or is empty and there is a default.
+///
+/// # Returns:
+/// - `Err(e)` - reading or writing failed, or the input ended without a
+///   recognised answer and there is no default (if there is, it is taken
+///   as the answer);
+/// - `Ok(false)` - the answer is "falsey";
+/// - `Ok(true)` - the answer is "truey";
+pub fn confirm(
+    question : &str,
+    default : ConfirmDefault,
+) -> io::Result<bool> {
+    confirm_with(io::stdin().lock(), io::stdout().lock(), question, default)
+}
+
+/// Asks the given question, as by [confirm()], writing to the given writer
+/// and reading from the given reader.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::prompt::{
+///     confirm_with,
+///     ConfirmDefault,
+/// };
+///
+/// let mut output = Vec::new();
+///
+/// let answer = confirm_with("maybe\ny\n".as_bytes(), &mut output, "Proceed?", ConfirmDefault::No).unwrap();
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert!(answer);
+/// assert_eq!(
+///     "Proceed? [y/N] 'maybe' is not recognised; please answer yes or no\nProceed? [y/N] ",
+///     String::from_utf8(output).unwrap()
+/// );
+/// # }
+/// ```
+pub fn confirm_with<R, W>(
+    mut reader : R,
+    mut writer : W,
+    question : &str,
+    default : ConfirmDefault,
+) -> io::Result<bool>
+where
+    R : BufRead,
+    W : Write,
+{
+    let style = default_style_();
+    let suffix = suffix_(&style, default);
+    let mut line = String::new();
+
+    loop {
+        write!(writer, "{question} {suffix} ")?;
+        writer.flush()?;
+
+        line.clear();
+
+        let at_eof = 0 == reader.read_line(&mut line)?;
+        let answer = line.trim();
+
+        if answer.is_empty() {
+            match default {
+                ConfirmDefault::Yes => return Ok(true),
+                ConfirmDefault::No => return Ok(false),
+                ConfirmDefault::None if at_eof => {
+                    return Err(io::Error::new(io::ErrorKind::UnexpectedEof, "input ended without a recognised answer"));
+                },
+                ConfirmDefault::None => continue,
+            };
+        }
+
+        if let Some(b) = classify_answer_(answer, &style) {
+            return Ok(b);
+        }
+
+        writeln!(writer, "'{answer}' is not recognised; please answer {} or {}", style.truey, style.falsey)?;
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_suffix_() {
+        let style = BoolStyle::YES_NO;
+
+        assert_eq!("[y/n]", suffix_(&style, ConfirmDefault::None));
+        assert_eq!("[Y/n]", suffix_(&style, ConfirmDefault::Yes));
+        assert_eq!("[y/N]", suffix_(&style, ConfirmDefault::No));
+        assert_eq!("[o/N]", suffix_(&BoolStyle::new("oui", "non"), ConfirmDefault::No));
+    }
+
+    #[test]
+    fn TEST_classify_answer_() {
+        let style = BoolStyle::new("oui", "non");
+
+        assert_eq!(Some(true), classify_answer_("O", &style));
+        assert_eq!(Some(false), classify_answer_("n", &style));
+
+        let style = BoolStyle::new("nah", "nope");
+
+        assert_eq!(None, classify_answer_("x", &style));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_confirm_with() {
+        let confirm = |input : &str, default| {
+            let mut output = Vec::new();
+            let r = confirm_with(input.as_bytes(), &mut output, "Go?", default);
+
+            (r.map_err(|e| e.kind()), String::from_utf8(output).unwrap())
+        };
+
+        assert_eq!((Ok(true), "Go? [y/n] ".to_owned()), confirm("Y\n", ConfirmDefault::None));
+        assert_eq!((Ok(false), "Go? [y/n] ".to_owned()), confirm(" no \n", ConfirmDefault::None));
+        assert_eq!((Ok(true), "Go? [Y/n] ".to_owned()), confirm("\n", ConfirmDefault::Yes));
+        assert_eq!((Ok(false), "Go? [y/N] ".to_owned()), confirm("", ConfirmDefault::No));
+        assert_eq!((Ok(true), "Go? [y/n] Go? [y/n] ".to_owned()), confirm("\nyes", ConfirmDefault::None));
+        assert_eq!(
+            (Ok(false), "Go? [y/n] '?' is not recognised; please answer yes or no\nGo? [y/n] ".to_owned()),
+            confirm("?\nOFF\n", ConfirmDefault::None)
+        );
+        assert_eq!(Err(io::ErrorKind::UnexpectedEof), confirm("\n", ConfirmDefault::None).0);
+        assert_eq!(Err(io::ErrorKind::UnexpectedEof), confirm("maybe", ConfirmDefault::None).0);
+        assert_eq!(Ok(true), confirm("maybe", ConfirmDefault::Yes).0);
+    }
+}
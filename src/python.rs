@@ -0,0 +1,272 @@
+// python.rs - Python truth-value semantics
+
+
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+    },
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+
+
+/// Trait that coerces an instance to `bool` according to the truth-value
+/// testing rules of (C)Python, i.e.:
+///
+/// - `None` is false;
+/// - zero numbers (including `-0.0`) are false, and all others (including
+///   NaN) are true;
+/// - empty strings and collections (including the empty tuple) are false,
+///   and all others are true;
+/// - a zero `Duration` (as Python's `timedelta(0)`) is false;
+///
+/// # Note:
+/// These rules differ markedly from those of [Truthy](crate::Truthy): e.g.
+/// the string `"False"` is "falsey" but is true by `ToBool`.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::python::ToBool as _;
+///
+/// assert_eq!(false, 0.0.to_bool());
+/// assert_eq!(true, f64::NAN.to_bool());
+/// assert_eq!(true, "False".to_bool());
+/// assert_eq!(false, None::<i32>.to_bool());
+/// assert_eq!(false, Vec::<i32>::new().to_bool());
+/// assert_eq!(true, vec![0].to_bool());
+/// ```
+pub trait ToBool {
+    /// Coerces the instance to `bool`, as would Python's `bool()`.
+    fn to_bool(&self) -> bool;
+}
+
+impl<T> ToBool for &T
+where
+    T : ToBool + ?Sized,
+{
+    fn to_bool(&self) -> bool {
+        (**self).to_bool()
+    }
+}
+
+impl ToBool for bool {
+    fn to_bool(&self) -> bool {
+        *self
+    }
+}
+
+impl<T> ToBool for Option<T>
+where
+    T : ToBool,
+{
+    fn to_bool(&self) -> bool {
+        self.as_ref().is_some_and(ToBool::to_bool)
+    }
+}
+
+impl ToBool for () {
+    fn to_bool(&self) -> bool {
+        false
+    }
+}
+
+macro_rules! implement_ToBool_for_number {
+    ($zero:literal; $($type:ty),+ $(,)?) => {
+        $(
+            impl ToBool for $type {
+                fn to_bool(&self) -> bool {
+                    $zero != *self
+                }
+            }
+        )+
+    };
+}
+
+implement_ToBool_for_number!(0; i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+implement_ToBool_for_number!(0.0; f32, f64);
+
+impl ToBool for char {
+    fn to_bool(&self) -> bool {
+        // a (Python) string of one character is never empty
+        true
+    }
+}
+
+impl ToBool for Duration {
+    fn to_bool(&self) -> bool {
+        !self.is_zero()
+    }
+}
+
+macro_rules! implement_ToBool_for_collection {
+    ($(<$($param:ident),+> $type:ty),+ $(,)?) => {
+        $(
+            impl<$($param),+> ToBool for $type {
+                fn to_bool(&self) -> bool {
+                    !self.is_empty()
+                }
+            }
+        )+
+    };
+}
+
+impl ToBool for str {
+    fn to_bool(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl ToBool for String {
+    fn to_bool(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T, const N : usize> ToBool for [T; N] {
+    fn to_bool(&self) -> bool {
+        0 != N
+    }
+}
+
+implement_ToBool_for_collection!(
+    <T> [T],
+    <T> Vec<T>,
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+    <T> BinaryHeap<T>,
+    <K, V, S> HashMap<K, V, S>,
+    <K, V> BTreeMap<K, V>,
+    <T, S> HashSet<T, S>,
+    <T> BTreeSet<T>,
+);
+
+macro_rules! implement_ToBool_for_tuple {
+    ($(($($param:ident),+)),+ $(,)?) => {
+        $(
+            impl<$($param),+> ToBool for ($($param,)+) {
+                fn to_bool(&self) -> bool {
+                    // a non-empty tuple is true, irrespective of its elements
+                    true
+                }
+            }
+        )+
+    };
+}
+
+implement_ToBool_for_tuple!(
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+);
+
+macro_rules! implement_ToBool_for_pointer {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl<T> ToBool for $type<T>
+            where
+                T : ToBool + ?Sized,
+            {
+                fn to_bool(&self) -> bool {
+                    (**self).to_bool()
+                }
+            }
+        )+
+    };
+}
+
+implement_ToBool_for_pointer!(Box, Rc, Arc);
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::bool_assert_comparison)]
+    #![allow(clippy::needless_borrow)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_ToBool_NONE_AND_bool() {
+        assert_eq!(false, None::<bool>.to_bool());
+        assert_eq!(false, Some(false).to_bool());
+        assert_eq!(true, Some(true).to_bool());
+        assert_eq!(false, Some(0).to_bool());
+        assert_eq!(true, Some("x").to_bool());
+        assert_eq!(false, ().to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_NUMBERS() {
+        assert_eq!(false, 0i8.to_bool());
+        assert_eq!(false, 0u128.to_bool());
+        assert_eq!(true, (-1i64).to_bool());
+        assert_eq!(true, usize::MAX.to_bool());
+
+        assert_eq!(false, 0.0f32.to_bool());
+        assert_eq!(false, (-0.0f64).to_bool());
+        assert_eq!(true, f64::NAN.to_bool());
+        assert_eq!(true, f32::INFINITY.to_bool());
+        assert_eq!(true, 1e-300.to_bool());
+
+        assert_eq!(false, Duration::ZERO.to_bool());
+        assert_eq!(true, Duration::from_nanos(1).to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_STRINGS() {
+        assert_eq!(false, "".to_bool());
+        assert_eq!(false, String::new().to_bool());
+
+        for s in ["0", "false", "False", "no", " ", "None"] {
+            assert_eq!(true, s.to_bool(), "'{s}'");
+            assert_eq!(true, String::from(s).to_bool(), "'{s}'");
+        }
+
+        assert_eq!(true, '\0'.to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_COLLECTIONS() {
+        assert_eq!(false, Vec::<i32>::new().to_bool());
+        assert_eq!(true, vec![0].to_bool());
+        assert_eq!(false, [0u8; 0].to_bool());
+        assert_eq!(true, [false].to_bool());
+        assert_eq!(false, (&[] as &[i32]).to_bool());
+        assert_eq!(false, HashMap::<i32, i32>::new().to_bool());
+        assert_eq!(true, BTreeMap::from([(0, 0)]).to_bool());
+        assert_eq!(false, HashSet::<i32>::new().to_bool());
+        assert_eq!(true, BTreeSet::from([0]).to_bool());
+        assert_eq!(false, VecDeque::<i32>::new().to_bool());
+        assert_eq!(true, LinkedList::from([0]).to_bool());
+        assert_eq!(false, BinaryHeap::<i32>::new().to_bool());
+
+        assert_eq!(true, (0,).to_bool());
+        assert_eq!(true, (false, 0, "").to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_POINTERS_AND_REFERENCES() {
+        assert_eq!(false, Box::new(0).to_bool());
+        assert_eq!(true, Rc::new(String::from("x")).to_bool());
+        assert_eq!(false, Arc::<str>::from("").to_bool());
+        assert_eq!(true, (&&1).to_bool());
+
+        let values : Vec<Box<dyn ToBool>> = vec![Box::new(0), Box::new("a"), Box::new(None::<bool>)];
+
+        assert_eq!(vec![false, true, false], values.iter().map(|v| v.to_bool()).collect::<Vec<_>>());
+    }
+}
@@ -0,0 +1,249 @@
+// registry.rs - truthiness of Windows registry values
+
+//! Truthiness of Windows registry values, so that, say, services may read
+//! policy toggles - whether stored as numbers or as strings - through one
+//! consistent API.
+//!
+//! Values are classified from their raw type and data, as obtained from
+//! any registry API (such as `RegQueryValueExW()`, or the `vtype` and
+//! `bytes` of a **winreg** `RegValue`), so this module is available on all
+//! platforms:
+//! - `REG_DWORD`, `REG_DWORD_BIG_ENDIAN`, and `REG_QWORD` values are
+//!   "falsey" if zero and "truey" otherwise;
+//! - `REG_SZ` and `REG_EXPAND_SZ` values are classified by
+//!   [reg_string_is_truthy()];
+//!
+//! and all other types (and malformed data) are not "truthy".
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::registry::{
+//!     reg_value_is_truthy,
+//!     RegValueType,
+//! };
+//!
+//! assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Dword, &1u32.to_le_bytes()));
+//! assert_eq!(Some(false), reg_value_is_truthy(RegValueType::Qword, &0u64.to_le_bytes()));
+//!
+//! // "0x1", in UTF-16LE, with its terminating NUL
+//! let data : Vec<u8> = "0x1\0".encode_utf16().flat_map(u16::to_le_bytes).collect();
+//!
+//! assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Sz, &data));
+//! ```
+
+use super::string_is_truthy;
+
+
+/// The types of registry value that may be classified.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum RegValueType {
+    /// `REG_SZ` - a NUL-terminated UTF-16LE string.
+    Sz,
+    /// `REG_EXPAND_SZ` - a NUL-terminated UTF-16LE string, containing
+    /// unexpanded references to environment variables.
+    ExpandSz,
+    /// `REG_DWORD` - a 32-bit little-endian number.
+    Dword,
+    /// `REG_DWORD_BIG_ENDIAN` - a 32-bit big-endian number.
+    DwordBigEndian,
+    /// `REG_QWORD` - a 64-bit little-endian number.
+    Qword,
+}
+
+impl RegValueType {
+    /// Obtains the type corresponding to the given raw (`REG_*`) value.
+    ///
+    /// # Returns:
+    /// - `None` - raw value is not of a type that may be classified (e.g.
+    ///   `REG_BINARY`, `REG_MULTI_SZ`);
+    /// - `Some(vtype)` - the type;
+    pub fn from_raw(raw : u32) -> Option<Self> {
+        match raw {
+            1 => Some(Self::Sz),
+            2 => Some(Self::ExpandSz),
+            4 => Some(Self::Dword),
+            5 => Some(Self::DwordBigEndian),
+            11 => Some(Self::Qword),
+            _ => None,
+        }
+    }
+
+    /// The raw (`REG_*`) value of the type.
+    pub fn raw(&self) -> u32 {
+        match self {
+            Self::Sz => 1,
+            Self::ExpandSz => 2,
+            Self::Dword => 4,
+            Self::DwordBigEndian => 5,
+            Self::Qword => 11,
+        }
+    }
+}
+
+/// Decodes the given UTF-16LE data, up to the first NUL (if any).
+fn decode_utf16le_(data : &[u8]) -> Option<String> {
+    if 1 == data.len() % 2 {
+        return None;
+    }
+
+    let units : Vec<u16> = data
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .take_while(|&unit| 0 != unit)
+        .collect();
+
+    String::from_utf16(&units).ok()
+}
+
+/// Indicates whether the given string is a (non-negative) decimal or
+/// hexadecimal (with the prefix `"0x"` or `"0X"`) integer and, if so,
+/// whether it is non-zero.
+fn integer_is_truthy_(s : &str) -> Option<bool> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(digits) => (digits, 16),
+        None => (s, 10),
+    };
+
+    if digits.is_empty() || !digits.chars().all(|c| c.is_digit(radix)) {
+        return None;
+    }
+
+    Some(digits.bytes().any(|b| b'0' != b))
+}
+
+/// Indicates whether the given (`REG_SZ` or `REG_EXPAND_SZ`) string, when
+/// trimmed, is "truthy" and, if so, whether it is "truey" or "falsey".
+///
+/// The string is classified as by [string_is_truthy()] or, if it is not
+/// recognised thereby, as a decimal or hexadecimal (e.g. `"0x1"`) integer,
+/// which is "falsey" if zero and "truey" otherwise, as policy toggles are
+/// often stored thus.
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy";
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::registry::reg_string_is_truthy;
+///
+/// assert_eq!(Some(true), reg_string_is_truthy("0x1"));
+/// assert_eq!(Some(false), reg_string_is_truthy(" 0X00000000 "));
+/// assert_eq!(Some(true), reg_string_is_truthy("42"));
+/// # #[cfg(to_be_stock_default)]
+/// assert_eq!(Some(false), reg_string_is_truthy("Off"));
+/// assert_eq!(None, reg_string_is_truthy("0xG"));
+/// ```
+pub fn reg_string_is_truthy(s : &str) -> Option<bool> {
+    string_is_truthy(s).or_else(|| integer_is_truthy_(s.trim()))
+}
+
+/// Indicates whether the registry value of the given type and raw data is
+/// "truthy" and, if so, whether it is "truey" or "falsey".
+///
+/// Numeric values are "falsey" if zero and "truey" otherwise, and string
+/// values - whose terminating NUL is optional - are classified as by
+/// [reg_string_is_truthy()].
+///
+/// # Returns:
+/// - `None` - value is not classified as "truthy", including when its
+///   data is malformed (e.g. a `REG_DWORD` that is not 4 bytes long, or a
+///   string that is not valid UTF-16);
+/// - `Some(false)` - value (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - value (is classified as "truthy" and) is deemed
+///   "truey";
+pub fn reg_value_is_truthy(
+    vtype : RegValueType,
+    data : &[u8],
+) -> Option<bool> {
+    match vtype {
+        RegValueType::Sz | RegValueType::ExpandSz => reg_string_is_truthy(&decode_utf16le_(data)?),
+        RegValueType::Dword => <[u8; 4]>::try_from(data).ok().map(|bytes| 0 != u32::from_le_bytes(bytes)),
+        RegValueType::DwordBigEndian => <[u8; 4]>::try_from(data).ok().map(|bytes| 0 != u32::from_be_bytes(bytes)),
+        RegValueType::Qword => <[u8; 8]>::try_from(data).ok().map(|bytes| 0 != u64::from_le_bytes(bytes)),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn utf16le_(s : &str) -> Vec<u8> {
+        s.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[test]
+    fn TEST_RegValueType_RAW() {
+        for vtype in [
+            RegValueType::Sz,
+            RegValueType::ExpandSz,
+            RegValueType::Dword,
+            RegValueType::DwordBigEndian,
+            RegValueType::Qword,
+        ] {
+            assert_eq!(Some(vtype), RegValueType::from_raw(vtype.raw()));
+        }
+
+        assert_eq!(None, RegValueType::from_raw(0)); // REG_NONE
+        assert_eq!(None, RegValueType::from_raw(3)); // REG_BINARY
+        assert_eq!(None, RegValueType::from_raw(7)); // REG_MULTI_SZ
+    }
+
+    #[test]
+    fn TEST_reg_string_is_truthy() {
+        assert_eq!(Some(true), reg_string_is_truthy("0x1"));
+        assert_eq!(Some(true), reg_string_is_truthy("0xFF"));
+        assert_eq!(Some(false), reg_string_is_truthy("0x0"));
+        assert_eq!(Some(false), reg_string_is_truthy("000"));
+        assert_eq!(Some(true), reg_string_is_truthy("007"));
+        assert_eq!(Some(true), reg_string_is_truthy("123456789012345678901234567890"));
+        assert_eq!(None, reg_string_is_truthy("0x"));
+        assert_eq!(None, reg_string_is_truthy("-1"));
+        assert_eq!(None, reg_string_is_truthy("1.5"));
+        assert_eq!(None, reg_string_is_truthy(""));
+    }
+
+    #[test]
+    fn TEST_reg_value_is_truthy_NUMBERS() {
+        assert_eq!(Some(false), reg_value_is_truthy(RegValueType::Dword, &[0, 0, 0, 0]));
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Dword, &[0, 0, 0, 1]));
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::DwordBigEndian, &1u32.to_be_bytes()));
+        assert_eq!(Some(false), reg_value_is_truthy(RegValueType::Qword, &[0; 8]));
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Qword, &(1u64 << 40).to_le_bytes()));
+        assert_eq!(None, reg_value_is_truthy(RegValueType::Dword, &[1, 0]));
+        assert_eq!(None, reg_value_is_truthy(RegValueType::Qword, &[1, 0, 0, 0]));
+    }
+
+    #[test]
+    fn TEST_reg_value_is_truthy_STRINGS() {
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Sz, &utf16le_("0x1\0")));
+        assert_eq!(Some(false), reg_value_is_truthy(RegValueType::ExpandSz, &utf16le_("0\0\0")));
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Sz, &utf16le_("1")));
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Sz, &utf16le_("1\0garbage")));
+        assert_eq!(None, reg_value_is_truthy(RegValueType::Sz, &utf16le_("\0")));
+        assert_eq!(None, reg_value_is_truthy(RegValueType::Sz, &[b'1', 0, 0]));
+        assert_eq!(None, reg_value_is_truthy(RegValueType::Sz, &[0x00, 0xD8])); // lone surrogate
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_reg_value_is_truthy_TERMS() {
+        assert_eq!(Some(true), reg_value_is_truthy(RegValueType::Sz, &utf16le_("Yes\0")));
+        assert_eq!(Some(false), reg_value_is_truthy(RegValueType::Sz, &utf16le_(" FALSE \0")));
+    }
+}
@@ -0,0 +1,300 @@
+// rollout.rs - deterministic percentage rollouts
+
+//! Evaluation of flags whose values express gradual rollouts, such as
+//! `"25%"` or `"on:25%"`, so that such rollouts may be expressed in
+//! existing string-flag infrastructure.
+//!
+//! The decision for a given flag and key is deterministic - the same key
+//! always obtains the same decision for the same flag and percentage, on
+//! all platforms and in all versions of the crate - and is monotonic in
+//! the percentage, so that a key for which a flag is on remains so as the
+//! percentage is increased.
+//!
+//! The name of the flag is hashed together with the key, so that the
+//! rollouts of different flags are independent: the keys for which one
+//! flag is on at 10% are not those for which another is on at 10%.
+//!
+//! # Example:
+//!
+//! ```
+//! use to_be::rollout::rollout_decision;
+//!
+//! let value = "10%"; // as read from, say, configuration
+//!
+//! let new_checkout = rollout_decision(value, "new-checkout", "user-1234").unwrap_or(false);
+//!
+//! assert_eq!(Some(true), rollout_decision("100%", "new-checkout", "user-1234"));
+//! assert_eq!(Some(false), rollout_decision("0%", "new-checkout", "user-1234"));
+//! ```
+
+use super::{
+    string_is_truthy_with,
+    Terms,
+};
+
+
+/// The number of buckets into which keys are hashed, which is that of
+/// the hundredths of a percent.
+const NUM_BUCKETS : u64 = 10_000;
+
+/// The byte that separates the name of a flag from the key in the hashed
+/// bytes, which cannot occur in the (UTF-8) name, so that no two pairs of
+/// name and key hash the same bytes.
+const FLAG_NAME_TERMINATOR : u8 = 0xff;
+
+const FNV1A_OFFSET_BASIS : u64 = 0xcbf2_9ce4_8422_2325;
+const FNV1A_PRIME : u64 = 0x0000_0100_0000_01b3;
+
+/// Obtains the 64-bit FNV-1a hash of the given bytes, which - unlike the
+/// hashers of the standard library - is stable.
+fn fnv1a_(bytes : &[u8]) -> u64 {
+    fnv1a_continued_(FNV1A_OFFSET_BASIS, bytes)
+}
+
+/// Obtains the 64-bit FNV-1a hash of the bytes hashed as the given hash
+/// followed by the given bytes.
+fn fnv1a_continued_(
+    hash : u64,
+    bytes : &[u8],
+) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, &b| (hash ^ u64::from(b)).wrapping_mul(FNV1A_PRIME))
+}
+
+/// Obtains the bucket, in `[0, NUM_BUCKETS)`, of the given key for the
+/// flag of the given name.
+fn bucket_(
+    flag_name : &str,
+    key : &[u8],
+) -> u64 {
+    let hash = fnv1a_continued_(fnv1a_(flag_name.as_bytes()), &[FLAG_NAME_TERMINATOR]);
+
+    fnv1a_continued_(hash, key) % NUM_BUCKETS
+}
+
+/// Parses the given percentage - e.g. `"25%"`, `"12.5%"` - in the range
+/// `[0, 100]`, with at most two decimal places, as a number of hundredths
+/// of a percent.
+fn parse_percentage_(s : &str) -> Option<u64> {
+    let number = s.strip_suffix('%')?.trim_end();
+    let (whole, fraction) = number.split_once('.').unwrap_or((number, ""));
+
+    if whole.is_empty() || fraction.len() > 2 {
+        return None;
+    }
+    if !whole.bytes().chain(fraction.bytes()).all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+
+    let whole : u64 = whole.parse().ok()?;
+    let fraction : u64 = format!("{fraction:0<2}").parse().ok()?;
+    let hundredths = whole.checked_mul(100)?.checked_add(fraction)?;
+
+    (hundredths <= NUM_BUCKETS).then_some(hundredths)
+}
+
+/// Determines whether the flag of the given name and value is on for the
+/// given (stable) key - such as a user or tenant identifier - where the
+/// value is classified according to the default vocabulary.
+///
+/// See [rollout_decision_with()] for the forms of value.
+pub fn rollout_decision<K>(
+    value : &str,
+    flag_name : &str,
+    stable_key : K,
+) -> Option<bool>
+where
+    K : AsRef<[u8]>,
+{
+    rollout_decision_with(value, flag_name, stable_key, Terms::Default)
+}
+
+/// Determines whether the flag of the given name and value is on for the
+/// given (stable) key - such as a user or tenant identifier - where the
+/// value, when trimmed, is one of:
+///
+/// - a "truthy" value (e.g. `"on"`) - according to the given terms - that
+///   applies to all keys;
+/// - a percentage (e.g. `"25%"`, `"12.5%"`), in the range `[0, 100]` with
+///   at most two decimal places, of keys for which the flag is on;
+/// - a "truthy" value and a percentage, separated by `':'` (e.g.
+///   `"on:25%"`), where, if the value is "falsey" (e.g. `"off:25%"`), the
+///   flag is off for all keys, so that a rollout may be suspended without
+///   losing its percentage;
+///
+/// Whether the key is among the given percentage is determined by a
+/// stable hash of the name of the flag and the key, so that the keys for
+/// which one flag is on are independent of those for which another is.
+///
+/// # Returns:
+/// - `None` - value is not of any of the above forms;
+/// - `Some(false)` - flag is off for the key;
+/// - `Some(true)` - flag is on for the key;
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     rollout::rollout_decision_with,
+///     Terms,
+/// };
+///
+/// let terms = Terms::Strings {
+///     falsey_precise_strings :   &[],
+///     falsey_lowercase_strings : &["aus"],
+///     truey_precise_strings :    &[],
+///     truey_lowercase_strings :  &["an"],
+/// };
+///
+/// assert_eq!(Some(true), rollout_decision_with("an", "dark-mode", "tenant-7", terms.clone()));
+/// assert_eq!(Some(false), rollout_decision_with("aus:50%", "dark-mode", "tenant-7", terms.clone()));
+/// assert_eq!(None, rollout_decision_with("an:150%", "dark-mode", "tenant-7", terms.clone()));
+/// ```
+pub fn rollout_decision_with<K>(
+    value : &str,
+    flag_name : &str,
+    stable_key : K,
+    terms : Terms,
+) -> Option<bool>
+where
+    K : AsRef<[u8]>,
+{
+    let value = value.trim();
+
+    let (switch, percentage) = match value.split_once(':') {
+        Some((switch, percentage)) => (Some(switch), percentage.trim_start()),
+        None if value.ends_with('%') => (None, value),
+        None => return string_is_truthy_with(value, terms),
+    };
+
+    let hundredths = parse_percentage_(percentage)?;
+
+    if let Some(switch) = switch {
+        if !string_is_truthy_with(switch, terms)? {
+            return Some(false);
+        }
+    }
+
+    Some(bucket_(flag_name, stable_key.as_ref()) < hundredths)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn terms_() -> Terms<'static> {
+        Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["aus"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["an"],
+        }
+    }
+
+    #[test]
+    fn TEST_fnv1a_() {
+        // the published test vectors of FNV-1a (64-bit)
+
+        assert_eq!(0xcbf29ce484222325, fnv1a_(b""));
+        assert_eq!(0xaf63dc4c8601ec8c, fnv1a_(b"a"));
+        assert_eq!(0x85944171f73967e8, fnv1a_(b"foobar"));
+        assert_eq!(fnv1a_(b"foobar"), fnv1a_continued_(fnv1a_(b"foo"), b"bar"));
+    }
+
+    #[test]
+    fn TEST_bucket_() {
+        assert_ne!(bucket_("ab", b"c"), bucket_("a", b"bc"));
+        assert_eq!(bucket_("flag", b"key"), bucket_("flag", b"key"));
+    }
+
+    #[test]
+    fn TEST_parse_percentage_() {
+        assert_eq!(Some(0), parse_percentage_("0%"));
+        assert_eq!(Some(2500), parse_percentage_("25%"));
+        assert_eq!(Some(2500), parse_percentage_("25 %"));
+        assert_eq!(Some(1250), parse_percentage_("12.5%"));
+        assert_eq!(Some(1), parse_percentage_("0.01%"));
+        assert_eq!(Some(10_000), parse_percentage_("100%"));
+        assert_eq!(Some(10_000), parse_percentage_("100.00%"));
+
+        for s in ["", "%", "25", "-1%", "+1%", "100.01%", "101%", ".5%", "1.234%", "1e2%", "99999999999999999999%"] {
+            assert_eq!(None, parse_percentage_(s), "'{s}'");
+        }
+    }
+
+    #[test]
+    fn TEST_rollout_decision_with_EXTREMES() {
+        for key in ["", "a", "user-1", "user-2", "tenant-99"] {
+            assert_eq!(Some(true), rollout_decision_with("100%", "flag", key, terms_()));
+            assert_eq!(Some(false), rollout_decision_with("0%", "flag", key, terms_()));
+            assert_eq!(Some(true), rollout_decision_with(" An : 100% ", "flag", key, terms_()));
+            assert_eq!(Some(false), rollout_decision_with("aus:100%", "flag", key, terms_()));
+            assert_eq!(Some(true), rollout_decision_with("an", "flag", key, terms_()));
+            assert_eq!(Some(false), rollout_decision_with("AUS", "flag", key, terms_()));
+        }
+    }
+
+    #[test]
+    fn TEST_rollout_decision_with_INVALID() {
+        for value in ["", "25", "vielleicht", "vielleicht:25%", "an:", "an:25", "an:x%", "25%:an", "an:25%:aus"] {
+            assert_eq!(None, rollout_decision_with(value, "flag", "key", terms_()), "'{value}'");
+        }
+    }
+
+    #[test]
+    fn TEST_rollout_decision_with_PROPORTION() {
+        let keys : Vec<String> = (0..10_000).map(|i| format!("user-{i}")).collect();
+
+        for (value, expected) in [("10%", 1_000), ("25%", 2_500), ("50%", 5_000), ("an:75%", 7_500)] {
+            let count = keys
+                .iter()
+                .filter(|key| rollout_decision_with(value, "flag", key, terms_()).unwrap())
+                .count();
+
+            assert!(count.abs_diff(expected) < 250, "{value}: {count}");
+        }
+    }
+
+    #[test]
+    fn TEST_rollout_decision_with_MONOTONIC() {
+        for i in 0..1_000 {
+            let key = format!("user-{i}");
+            let decisions : Vec<bool> = (0..=100)
+                .map(|percent| rollout_decision_with(&format!("{percent}%"), "flag", &key, terms_()).unwrap())
+                .collect();
+
+            assert!(decisions.windows(2).all(|w| w[0] <= w[1]), "{key}");
+        }
+    }
+
+    #[test]
+    fn TEST_rollout_decision_with_INDEPENDENT_FLAGS() {
+        let keys : Vec<String> = (0..10_000).map(|i| format!("user-{i}")).collect();
+
+        // were the rollouts correlated, all 1,000 of the keys of each would
+        // be common to both, rather than about 100
+
+        let common = keys
+            .iter()
+            .filter(|key| {
+                rollout_decision_with("10%", "flag-a", key, terms_()).unwrap()
+                    && rollout_decision_with("10%", "flag-b", key, terms_()).unwrap()
+            })
+            .count();
+
+        assert!(common.abs_diff(100) < 50, "{common}");
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_rollout_decision() {
+        assert_eq!(Some(true), rollout_decision("on", "flag", "key"));
+        assert_eq!(Some(false), rollout_decision("off:100%", "flag", "key"));
+        assert_eq!(Some(true), rollout_decision("yes:100%", "flag", b"key"));
+    }
+}
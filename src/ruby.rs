@@ -0,0 +1,193 @@
+// ruby.rs - Ruby truthiness semantics
+
+
+use std::{
+    collections::{
+        BTreeMap,
+        BTreeSet,
+        BinaryHeap,
+        HashMap,
+        HashSet,
+        LinkedList,
+        VecDeque,
+    },
+    rc::Rc,
+    sync::Arc,
+    time::Duration,
+};
+
+
+/// Trait that coerces an instance to `bool` according to the rules of Ruby,
+/// in which only `nil` (`None`) and `false` are false, and everything else -
+/// including `0`, `0.0`, `""`, `"0"`, `"false"`, and empty collections - is
+/// true.
+///
+/// # Note:
+/// These rules differ markedly from those of both [Truthy](crate::Truthy)
+/// and [python::ToBool](crate::python::ToBool).
+///
+/// # Example:
+///
+/// ```
+/// use to_be::ruby::ToBool as _;
+///
+/// assert_eq!(false, None::<&str>.to_bool());
+/// assert_eq!(false, false.to_bool());
+/// assert_eq!(false, Some(false).to_bool());
+/// assert_eq!(true, 0.to_bool());
+/// assert_eq!(true, "".to_bool());
+/// assert_eq!(true, "false".to_bool());
+/// ```
+pub trait ToBool {
+    /// Coerces the instance to `bool`, as would Ruby's `!!`.
+    fn to_bool(&self) -> bool;
+}
+
+impl<T> ToBool for &T
+where
+    T : ToBool + ?Sized,
+{
+    fn to_bool(&self) -> bool {
+        (**self).to_bool()
+    }
+}
+
+impl ToBool for bool {
+    fn to_bool(&self) -> bool {
+        *self
+    }
+}
+
+impl<T> ToBool for Option<T>
+where
+    T : ToBool,
+{
+    fn to_bool(&self) -> bool {
+        self.as_ref().is_some_and(ToBool::to_bool)
+    }
+}
+
+/// Converts the given string to `bool`, as would Ruby, i.e. always `true`,
+/// including for `""`, `"0"`, and `"false"`.
+pub fn string_to_bool(_s : &str) -> bool {
+    true
+}
+
+macro_rules! implement_ToBool_as_true {
+    ($(<$($param:ident),*> $type:ty),+ $(,)?) => {
+        $(
+            impl<$($param),*> ToBool for $type {
+                fn to_bool(&self) -> bool {
+                    true
+                }
+            }
+        )+
+    };
+}
+
+implement_ToBool_as_true!(
+    <> i8,
+    <> i16,
+    <> i32,
+    <> i64,
+    <> i128,
+    <> isize,
+    <> u8,
+    <> u16,
+    <> u32,
+    <> u64,
+    <> u128,
+    <> usize,
+    <> f32,
+    <> f64,
+    <> char,
+    <> str,
+    <> String,
+    <> std::borrow::Cow<'_, str>,
+    <> Duration,
+    <T> [T],
+    <T> Vec<T>,
+    <T> VecDeque<T>,
+    <T> LinkedList<T>,
+    <T> BinaryHeap<T>,
+    <K, V, S> HashMap<K, V, S>,
+    <K, V> BTreeMap<K, V>,
+    <T, S> HashSet<T, S>,
+    <T> BTreeSet<T>,
+);
+
+impl<T, const N : usize> ToBool for [T; N] {
+    fn to_bool(&self) -> bool {
+        true
+    }
+}
+
+macro_rules! implement_ToBool_for_pointer {
+    ($($type:ident),+ $(,)?) => {
+        $(
+            impl<T> ToBool for $type<T>
+            where
+                T : ToBool + ?Sized,
+            {
+                fn to_bool(&self) -> bool {
+                    (**self).to_bool()
+                }
+            }
+        )+
+    };
+}
+
+implement_ToBool_for_pointer!(Box, Rc, Arc);
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+    #![allow(clippy::bool_assert_comparison)]
+
+    use super::*;
+
+    use std::borrow::Cow;
+
+
+    #[test]
+    fn TEST_ToBool_NIL_AND_false() {
+        assert_eq!(false, None::<i32>.to_bool());
+        assert_eq!(false, None::<bool>.to_bool());
+        assert_eq!(false, false.to_bool());
+        assert_eq!(false, Some(false).to_bool());
+        assert_eq!(false, Some(None::<i32>).to_bool());
+        assert_eq!(true, true.to_bool());
+        assert_eq!(true, Some(true).to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_EVERYTHING_ELSE_IS_TRUE() {
+        assert_eq!(true, 0.to_bool());
+        assert_eq!(true, 0u8.to_bool());
+        assert_eq!(true, 0.0.to_bool());
+        assert_eq!(true, f64::NAN.to_bool());
+        assert_eq!(true, '\0'.to_bool());
+        assert_eq!(true, Duration::ZERO.to_bool());
+
+        for s in ["", "0", "false", "no", "nil", " "] {
+            assert_eq!(true, s.to_bool(), "'{s}'");
+            assert_eq!(true, String::from(s).to_bool(), "'{s}'");
+            assert_eq!(true, Cow::from(s).to_bool(), "'{s}'");
+            assert_eq!(true, string_to_bool(s), "'{s}'");
+        }
+
+        assert_eq!(true, Vec::<i32>::new().to_bool());
+        assert_eq!(true, [0u8; 0].to_bool());
+        assert_eq!(true, HashMap::<i32, i32>::new().to_bool());
+        assert_eq!(true, BTreeSet::<i32>::new().to_bool());
+        assert_eq!(true, Some(0).to_bool());
+    }
+
+    #[test]
+    fn TEST_ToBool_POINTERS() {
+        assert_eq!(false, Box::new(false).to_bool());
+        assert_eq!(false, Rc::new(None::<i32>).to_bool());
+        assert_eq!(true, Arc::<str>::from("").to_bool());
+    }
+}
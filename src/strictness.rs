@@ -0,0 +1,411 @@
+// strictness.rs - graded strictness of classification
+
+use super::{
+    defaults,
+    string_is_truthy_with_,
+    Terms,
+};
+
+
+#[rustfmt::skip]
+mod constants {
+    #![allow(clippy::redundant_static_lifetimes)]
+
+    // NOTE: the extended vocabulary supplements the stock terms, and so is
+    // empty when another default vocabulary has been selected.
+
+    #[cfg(to_be_stock_default)]
+    pub(super) const EXTENDED_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "n",
+        "f",
+        "disabled",
+        "disable",
+        "inactive",
+        "none",
+    ];
+
+    #[cfg(to_be_stock_default)]
+    pub(super) const EXTENDED_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[
+        "y",
+        "t",
+        "enabled",
+        "enable",
+        "active",
+    ];
+
+    #[cfg(not(to_be_stock_default))]
+    pub(super) const EXTENDED_FALSEY_LOWERCASE_STRINGS : &'static [&'static str] = &[];
+
+    #[cfg(not(to_be_stock_default))]
+    pub(super) const EXTENDED_TRUEY_LOWERCASE_STRINGS : &'static [&'static str] = &[];
+}
+
+
+/// The strictness with which strings are classified, as a single dial in
+/// place of several separate options.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(Default)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum Strictness {
+    /// Only the terms themselves, exactly as given - i.e. neither trimmed
+    /// nor case-folded - are recognised.
+    Strict,
+    /// The terms are recognised as by [string_is_truthy()] and
+    /// [string_is_truthy_with()], i.e. the string is trimmed and the
+    /// lowercase terms are matched case-insensitively.
+    ///
+    /// [string_is_truthy()]: crate::string_is_truthy()
+    /// [string_is_truthy_with()]: crate::string_is_truthy_with()
+    #[default]
+    Standard,
+    /// As [Strictness::Standard], and additionally:
+    /// - any enclosing quotes (`'"'`, `'\''`, or `` '`' ``) and any trailing
+    ///   punctuation (`'.'`, `'!'`, `','`, or `';'`) are disregarded;
+    /// - for the default vocabulary, when it is the stock terms, an
+    ///   extended vocabulary - `"y"`/`"n"`, `"t"`/`"f"`,
+    ///   `"enabled"`/`"disabled"`, `"enable"`/`"disable"`,
+    ///   `"active"`/`"inactive"`, and `"none"` (falsey) - is recognised
+    ///   case-insensitively;
+    /// - any other decimal number is recognised, as "falsey" if it is zero
+    ///   and "truey" otherwise;
+    Lenient,
+}
+
+impl Strictness {
+    /// All strictness levels, from the strictest to the most lenient.
+    pub const ALL : &'static [Self] = &[Self::Strict, Self::Standard, Self::Lenient];
+
+    /// Obtains the strictness level of the given name, which is matched
+    /// case-insensitively.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::Strictness;
+    ///
+    /// assert_eq!(Some(Strictness::Lenient), Strictness::from_name("Lenient"));
+    /// assert_eq!(None, Strictness::from_name("relaxed"));
+    /// ```
+    pub fn from_name(name : &str) -> Option<Self> {
+        Self::ALL.iter().copied().find(|strictness| strictness.name().eq_ignore_ascii_case(name))
+    }
+
+    /// The name of the strictness level.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::Standard => "standard",
+            Self::Lenient => "lenient",
+        }
+    }
+}
+
+/// Indicates whether the given (untrimmed) string is exactly one of the
+/// given terms, whether precise or lowercase.
+fn string_is_truthy_strictly_(
+    s : &str,
+    terms : &Terms,
+) -> Option<bool> {
+    let (falsey_precise_strings, falsey_lowercase_strings, truey_precise_strings, truey_lowercase_strings) =
+        match terms {
+            Terms::Default => (
+                &defaults::FALSEY_PRECISE_STRINGS[..],
+                &defaults::FALSEY_LOWERCASE_STRINGS[..],
+                &defaults::TRUEY_PRECISE_STRINGS[..],
+                &defaults::TRUEY_LOWERCASE_STRINGS[..],
+            ),
+            Terms::Strings {
+                falsey_precise_strings,
+                falsey_lowercase_strings,
+                truey_precise_strings,
+                truey_lowercase_strings,
+            }
+            | Terms::SortedStrings {
+                falsey_precise_strings,
+                falsey_lowercase_strings,
+                truey_precise_strings,
+                truey_lowercase_strings,
+            } => (
+                *falsey_precise_strings,
+                *falsey_lowercase_strings,
+                *truey_precise_strings,
+                *truey_lowercase_strings,
+            ),
+        };
+
+    if falsey_precise_strings.contains(&s) || falsey_lowercase_strings.contains(&s) {
+        return Some(false);
+    }
+    if truey_precise_strings.contains(&s) || truey_lowercase_strings.contains(&s) {
+        return Some(true);
+    }
+
+    None
+}
+
+/// Obtains the given string trimmed and without any enclosing quotes or
+/// trailing punctuation.
+pub(crate) fn strip_quotes_and_punctuation_(s : &str) -> &str {
+    let s = s.trim().trim_end_matches(['.', '!', ',', ';']).trim_end();
+
+    for quote in ['"', '\'', '`'] {
+        if let Some(inner) = s.strip_prefix(quote).and_then(|s| s.strip_suffix(quote)) {
+            return inner.trim();
+        }
+    }
+
+    s
+}
+
+/// Indicates whether the given (trimmed) string is a decimal number and,
+/// if so, whether it is non-zero.
+fn number_is_truthy_(s : &str) -> Option<bool> {
+    let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+
+    if !digits.starts_with(|c : char| c.is_ascii_digit() || '.' == c) {
+        return None;
+    }
+    if !digits.bytes().all(|b| b.is_ascii_digit() || b"._eE+-".contains(&b)) {
+        return None;
+    }
+
+    digits.replace('_', "").parse::<f64>().ok().map(|n| 0.0 != n)
+}
+
+/// Indicates whether the given string, when trimmed, is one of the given
+/// terms, as by [string_is_truthy_with()](crate::string_is_truthy_with()),
+/// but without recording or reporting the classification, so that it may
+/// be used for the intermediate lookups of each strictness.
+fn string_is_truthy_with_terms_(
+    s : &str,
+    terms : Terms,
+) -> Option<bool> {
+    string_is_truthy_with_(
+        s,
+        terms,
+        defaults::FALSEY_PRECISE_STRINGS,
+        defaults::FALSEY_LOWERCASE_STRINGS,
+        defaults::TRUEY_PRECISE_STRINGS,
+        defaults::TRUEY_LOWERCASE_STRINGS,
+    )
+}
+
+/// Indicates whether the given (stripped) string, which is not one of the
+/// terms, is nonetheless "truthy" by [Strictness::Lenient], i.e. is one of
+/// the extended vocabulary - if the terms are the default vocabulary - or
+/// is a decimal number.
+pub(crate) fn string_is_truthy_leniently_otherwise_(
+    s : &str,
+    is_default : bool,
+) -> Option<bool> {
+    if is_default {
+        let extended = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : constants::EXTENDED_FALSEY_LOWERCASE_STRINGS,
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  constants::EXTENDED_TRUEY_LOWERCASE_STRINGS,
+        };
+
+        if let Some(b) = string_is_truthy_with_terms_(s, extended) {
+            return Some(b);
+        }
+    }
+
+    number_is_truthy_(s)
+}
+
+fn string_is_truthy_leniently_(
+    s : &str,
+    terms : Terms,
+) -> Option<bool> {
+    let s = strip_quotes_and_punctuation_(s);
+    let is_default = matches!(terms, Terms::Default);
+
+    if let Some(b) = string_is_truthy_with_terms_(s, terms) {
+        return Some(b);
+    }
+
+    string_is_truthy_leniently_otherwise_(s, is_default)
+}
+
+/// Indicates whether the given string is "truthy", according to the
+/// default vocabulary and the given strictness, and, if so, whether it is
+/// "truey" or "falsey".
+///
+/// # Returns:
+/// - `None` - string is not classified as "truthy";
+/// - `Some(false)` - string (is classified as "truthy" and) is deemed
+///   "falsey";
+/// - `Some(true)` - string (is classified as "truthy" and) is deemed
+///   "truey";
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     string_is_truthy_with_strictness,
+///     Strictness,
+/// };
+///
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!(None, string_is_truthy_with_strictness(" yes", Strictness::Strict));
+/// assert_eq!(Some(true), string_is_truthy_with_strictness(" yes", Strictness::Standard));
+/// assert_eq!(None, string_is_truthy_with_strictness("\"Enabled\".", Strictness::Standard));
+/// assert_eq!(Some(true), string_is_truthy_with_strictness("\"Enabled\".", Strictness::Lenient));
+/// assert_eq!(Some(false), string_is_truthy_with_strictness("0.0", Strictness::Lenient));
+/// # }
+/// ```
+pub fn string_is_truthy_with_strictness(
+    s : &str,
+    strictness : Strictness,
+) -> Option<bool> {
+    let r = string_is_truthy_with_terms_and_strictness_(s, Terms::Default, strictness);
+
+    record_classification_!(crate::metrics::Evaluator::Default, r);
+    report_unrecognised_!("string_is_truthy_with_strictness", s, r);
+
+    r
+}
+
+/// Indicates whether the given string is "truthy", according to the given
+/// terms and the given strictness, and, if so, whether it is "truey" or
+/// "falsey".
+///
+/// # Returns:
+/// As [string_is_truthy_with_strictness()];
+pub fn string_is_truthy_with_terms_and_strictness(
+    s : &str,
+    terms : Terms,
+    strictness : Strictness,
+) -> Option<bool> {
+    #[cfg(feature = "metrics")]
+    let evaluator = match terms {
+        Terms::Default => crate::metrics::Evaluator::Default,
+        _ => crate::metrics::Evaluator::Custom,
+    };
+
+    let r = string_is_truthy_with_terms_and_strictness_(s, terms, strictness);
+
+    record_classification_!(evaluator, r);
+    report_unrecognised_!("string_is_truthy_with_terms_and_strictness", s, r);
+
+    r
+}
+
+/// Classifies the given string according to the given terms and the given
+/// strictness, without recording or reporting the classification.
+fn string_is_truthy_with_terms_and_strictness_(
+    s : &str,
+    terms : Terms,
+    strictness : Strictness,
+) -> Option<bool> {
+    match strictness {
+        Strictness::Strict => string_is_truthy_strictly_(s, &terms),
+        Strictness::Standard => string_is_truthy_with_terms_(s, terms),
+        Strictness::Lenient => string_is_truthy_leniently_(s, terms),
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_Strictness_names() {
+        for &strictness in Strictness::ALL {
+            assert_eq!(Some(strictness), Strictness::from_name(strictness.name()));
+        }
+
+        assert_eq!(Some(Strictness::Strict), Strictness::from_name("STRICT"));
+        assert_eq!(Strictness::Standard, Strictness::default());
+    }
+
+    #[test]
+    fn TEST_strip_quotes_and_punctuation_() {
+        assert_eq!("yes", strip_quotes_and_punctuation_(" yes "));
+        assert_eq!("yes", strip_quotes_and_punctuation_("yes!"));
+        assert_eq!("yes", strip_quotes_and_punctuation_("' yes ' ."));
+        assert_eq!("yes", strip_quotes_and_punctuation_("`yes`"));
+        assert_eq!("\"yes", strip_quotes_and_punctuation_("\"yes"));
+        assert_eq!("", strip_quotes_and_punctuation_("..."));
+    }
+
+    #[test]
+    fn TEST_number_is_truthy_() {
+        assert_eq!(Some(false), number_is_truthy_("0"));
+        assert_eq!(Some(false), number_is_truthy_("-0.0"));
+        assert_eq!(Some(false), number_is_truthy_(".0e5"));
+        assert_eq!(Some(true), number_is_truthy_("2"));
+        assert_eq!(Some(true), number_is_truthy_("-1"));
+        assert_eq!(Some(true), number_is_truthy_("1_000"));
+        assert_eq!(Some(true), number_is_truthy_("1e-3"));
+        assert_eq!(None, number_is_truthy_("inf"));
+        assert_eq!(None, number_is_truthy_("NaN"));
+        assert_eq!(None, number_is_truthy_("0x1"));
+        assert_eq!(None, number_is_truthy_("--1"));
+        assert_eq!(None, number_is_truthy_(""));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_string_is_truthy_with_strictness_Strict() {
+        assert_eq!(Some(true), string_is_truthy_with_strictness("yes", Strictness::Strict));
+        assert_eq!(Some(true), string_is_truthy_with_strictness("YES", Strictness::Strict));
+        assert_eq!(Some(false), string_is_truthy_with_strictness("0", Strictness::Strict));
+        assert_eq!(None, string_is_truthy_with_strictness("yEs", Strictness::Strict));
+        assert_eq!(None, string_is_truthy_with_strictness("yes\n", Strictness::Strict));
+        assert_eq!(None, string_is_truthy_with_strictness("y", Strictness::Strict));
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_string_is_truthy_with_strictness_Standard() {
+        for s in ["yes", " yEs\n", "y", "'yes'", "2", "enabled"] {
+            assert_eq!(crate::string_is_truthy(s), string_is_truthy_with_strictness(s, Strictness::Standard));
+        }
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_string_is_truthy_with_strictness_Lenient() {
+        assert_eq!(Some(true), string_is_truthy_with_strictness(" yEs\n", Strictness::Lenient));
+        assert_eq!(Some(true), string_is_truthy_with_strictness("'yes'", Strictness::Lenient));
+        assert_eq!(Some(true), string_is_truthy_with_strictness("Y", Strictness::Lenient));
+        assert_eq!(Some(false), string_is_truthy_with_strictness("Disabled.", Strictness::Lenient));
+        assert_eq!(Some(false), string_is_truthy_with_strictness("none", Strictness::Lenient));
+        assert_eq!(Some(true), string_is_truthy_with_strictness("42", Strictness::Lenient));
+        assert_eq!(Some(false), string_is_truthy_with_strictness("\"0.00\"", Strictness::Lenient));
+        assert_eq!(None, string_is_truthy_with_strictness("maybe", Strictness::Lenient));
+        assert_eq!(None, string_is_truthy_with_strictness("", Strictness::Lenient));
+    }
+
+    #[test]
+    fn TEST_string_is_truthy_with_terms_and_strictness() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["NON"],
+            falsey_lowercase_strings : &["non"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["oui"],
+        };
+
+        let classify = |s, strictness| string_is_truthy_with_terms_and_strictness(s, terms.clone(), strictness);
+
+        assert_eq!(Some(true), classify("oui", Strictness::Strict));
+        assert_eq!(None, classify("Oui", Strictness::Strict));
+        assert_eq!(Some(false), classify("NON", Strictness::Strict));
+        assert_eq!(Some(true), classify("Oui", Strictness::Standard));
+        assert_eq!(None, classify("'NON'!", Strictness::Standard));
+        assert_eq!(Some(false), classify("'NON'!", Strictness::Lenient));
+        assert_eq!(None, classify("enabled", Strictness::Lenient));
+        assert_eq!(Some(true), classify("7", Strictness::Lenient));
+    }
+}
@@ -0,0 +1,319 @@
+// term_entry.rs - enumeration of the terms of a vocabulary
+
+use super::{
+    locale,
+    preset::Preset,
+    stock_term_strings,
+    terms_summary::origin_and_groups_,
+    ChainedTerms,
+    Terms,
+};
+
+
+/// How a term is matched.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum CaseMode {
+    /// The term is matched exactly (a "precise" string).
+    Sensitive,
+    /// The term is matched case-insensitively (a "lowercase" string).
+    Insensitive,
+}
+
+/// The origin of a term.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum TermOrigin {
+    /// The default vocabulary (i.e. [Terms::Default]).
+    Default,
+    /// The stock terms (as obtained from [stock_term_strings()]).
+    Stock,
+    /// The given preset.
+    Preset(Preset),
+    /// The terms of the locale of the given tag, as registered in the
+    /// locale registry.
+    Locale(&'static str),
+    /// Any other terms, such as those given by the user.
+    Custom,
+}
+
+/// A term of a vocabulary, as obtained from [Terms::entries()].
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub struct TermEntry<'a> {
+    /// The text of the term.
+    pub text :      &'a str,
+    /// Whether the term is "truey" (`true`) or "falsey" (`false`).
+    pub polarity :  bool,
+    /// How the term is matched.
+    pub case_mode : CaseMode,
+    /// The origin of the term.
+    pub origin :    TermOrigin,
+}
+
+/// The polarity and case mode of each of the groups of terms obtained
+/// from `origin_and_groups_()`, in order.
+const GROUP_KINDS : [(bool, CaseMode); 4] = [
+    (true, CaseMode::Sensitive),
+    (true, CaseMode::Insensitive),
+    (false, CaseMode::Sensitive),
+    (false, CaseMode::Insensitive),
+];
+
+/// Obtains the built-in (non-default) vocabularies, in order of
+/// precedence: the stock terms, the presets, and the registered locales.
+fn built_in_vocabularies_() -> impl Iterator<Item = (TermOrigin, Terms<'static>)> {
+    let presets = Preset::ALL.iter().map(|&preset| (TermOrigin::Preset(preset), preset.terms()));
+    let locales = locale::available_locales()
+        .filter(|&tag| "en" != tag)
+        .filter_map(|tag| Some((TermOrigin::Locale(tag), locale::terms_for_locale(tag)?)));
+
+    std::iter::once((TermOrigin::Stock, stock_term_strings()))
+        .chain(presets)
+        .chain(locales)
+}
+
+/// Obtains the origin of each of the groups of the given (non-default)
+/// terms: that of the built-in vocabulary all of whose groups equal those
+/// of the terms, if any; otherwise, for each group, that of the first
+/// built-in vocabulary whose corresponding group equals it, if any.
+fn origins_of_groups_(groups : &[&[&str]; 4]) -> [TermOrigin; 4] {
+    if let Some((origin, _)) = built_in_vocabularies_().find(|(_, terms)| origin_and_groups_(terms).1 == *groups) {
+        return [origin; 4];
+    }
+
+    let mut origins = [TermOrigin::Custom; 4];
+
+    for (origin, terms) in built_in_vocabularies_() {
+        let (_, built_in_groups) = origin_and_groups_(&terms);
+
+        for index in 0..4 {
+            if TermOrigin::Custom == origins[index] && built_in_groups[index] == groups[index] {
+                origins[index] = origin;
+            }
+        }
+    }
+
+    origins
+}
+
+impl<'a> Terms<'a> {
+    /// Obtains all terms of the vocabulary, along with their polarity, how
+    /// they are matched, and their origin, so that, say, an administrative
+    /// interface or a completion engine may present the effective
+    /// vocabulary.
+    ///
+    /// The terms are obtained in the order: "truey" case-sensitive, "truey"
+    /// case-insensitive, "falsey" case-sensitive, "falsey"
+    /// case-insensitive.
+    ///
+    /// The origin of the terms is determined by comparing them with the
+    /// stock terms, with the terms of each preset, and with those of each
+    /// registered locale, so that terms that are, say, those of a preset
+    /// are identified as such however obtained. Where the terms as a whole
+    /// are not built-in, each group of terms (of a given polarity and case
+    /// mode) is compared likewise, so that, say, stock "falsey" terms
+    /// combined with custom "truey" terms are identified as such.
+    ///
+    /// # Note:
+    /// Where built-in vocabularies are identical (as are those of some
+    /// locales), terms are identified as of the first, in the order: the
+    /// stock terms, the presets (in the order of [Preset::ALL]), and the
+    /// locales (in the order of
+    /// [available_locales()](crate::locale::available_locales())).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::{
+    ///     preset::Preset,
+    ///     CaseMode,
+    ///     TermEntry,
+    ///     TermOrigin,
+    ///     Terms,
+    /// };
+    ///
+    /// let entries : Vec<TermEntry> = Preset::XsBoolean.terms().entries().collect();
+    ///
+    /// assert_eq!(
+    ///     TermEntry {
+    ///         text :      "1",
+    ///         polarity :  true,
+    ///         case_mode : CaseMode::Sensitive,
+    ///         origin :    TermOrigin::Preset(Preset::XsBoolean),
+    ///     },
+    ///     entries[0]
+    /// );
+    ///
+    /// let terms = Terms::Strings {
+    ///     falsey_precise_strings :   &[],
+    ///     falsey_lowercase_strings : &["nope"],
+    ///     truey_precise_strings :    &[],
+    ///     truey_lowercase_strings :  &["yep"],
+    /// };
+    ///
+    /// assert!(terms.entries().all(|entry| TermOrigin::Custom == entry.origin));
+    /// ```
+    pub fn entries(&self) -> impl Iterator<Item = TermEntry<'a>> {
+        let (_, groups) = origin_and_groups_(self);
+        let origins = match self {
+            Terms::Default => [TermOrigin::Default; 4],
+            _ => origins_of_groups_(&groups),
+        };
+
+        groups
+            .into_iter()
+            .zip(GROUP_KINDS)
+            .zip(origins)
+            .flat_map(|((strings, (polarity, case_mode)), origin)| {
+                strings.iter().map(move |&text| TermEntry {
+                    text,
+                    polarity,
+                    case_mode,
+                    origin,
+                })
+            })
+    }
+}
+
+impl<'a> ChainedTerms<'a> {
+    /// Obtains all terms of each of the term sets, in the order in which
+    /// they are consulted, as by [Terms::entries()].
+    ///
+    /// # Note:
+    /// A term may be obtained more than once, if it is in more than one of
+    /// the term sets, in which case the first obtained is that which
+    /// prevails.
+    pub fn entries(&self) -> impl Iterator<Item = TermEntry<'a>> + '_ {
+        self.term_sets().iter().flat_map(Terms::entries)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_Terms_entries_CUSTOM() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["NO"],
+            falsey_lowercase_strings : &["nope", "nah"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["yep"],
+        };
+
+        let entries : Vec<(&str, bool, CaseMode)> =
+            terms.entries().map(|entry| (entry.text, entry.polarity, entry.case_mode)).collect();
+
+        assert_eq!(
+            vec![
+                ("yep", true, CaseMode::Insensitive),
+                ("NO", false, CaseMode::Sensitive),
+                ("nope", false, CaseMode::Insensitive),
+                ("nah", false, CaseMode::Insensitive),
+            ],
+            entries
+        );
+        assert!(terms.entries().all(|entry| TermOrigin::Custom == entry.origin));
+    }
+
+    #[test]
+    fn TEST_Terms_entries_DEFAULT() {
+        assert!(Terms::Default.entries().count() > 0);
+        assert!(Terms::Default.entries().all(|entry| TermOrigin::Default == entry.origin));
+        assert!(Terms::Default.entries().any(|entry| entry.polarity));
+        assert!(Terms::Default.entries().any(|entry| !entry.polarity));
+    }
+
+    #[test]
+    fn TEST_Terms_entries_BUILT_IN() {
+        assert!(stock_term_strings().entries().all(|entry| TermOrigin::Stock == entry.origin));
+
+        for &preset in Preset::ALL {
+            let entries : Vec<TermEntry> = preset.terms().entries().collect();
+
+            assert!(!entries.is_empty());
+            assert!(entries.iter().all(|entry| TermOrigin::Preset(preset) == entry.origin), "{preset:?}");
+        }
+
+        for tag in locale::available_locales().filter(|&tag| "en" != tag) {
+            let terms = locale::terms_for_locale(tag).unwrap();
+
+            // locales whose terms are identical are indistinguishable
+
+            for entry in terms.entries() {
+                let TermOrigin::Locale(origin) = entry.origin else {
+                    panic!("{tag}: {:?}", entry.origin);
+                };
+
+                assert_eq!(
+                    origin_and_groups_(&terms).1,
+                    origin_and_groups_(&locale::terms_for_locale(origin).unwrap()).1,
+                    "{tag}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn TEST_Terms_entries_MIXED() {
+        let Terms::Strings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            ..
+        } = stock_term_strings()
+        else {
+            unreachable!();
+        };
+
+        let terms = Terms::Strings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings :   &[],
+            truey_lowercase_strings : &["yep"],
+        };
+
+        for entry in terms.entries() {
+            let expected = match entry.polarity {
+                true => TermOrigin::Custom,
+                false => TermOrigin::Stock,
+            };
+
+            assert_eq!(expected, entry.origin, "{}", entry.text);
+        }
+    }
+
+    #[test]
+    fn TEST_ChainedTerms_entries() {
+        let user = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &[],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["yep"],
+        };
+
+        let chained = Terms::chain([user, Preset::Sql.terms()]);
+        let entries : Vec<TermEntry> = chained.entries().collect();
+
+        assert_eq!("yep", entries[0].text);
+        assert_eq!(TermOrigin::Custom, entries[0].origin);
+        assert!(entries[1..].iter().all(|entry| TermOrigin::Preset(Preset::Sql) == entry.origin));
+        assert_eq!(1 + Preset::Sql.terms().entries().count(), entries.len());
+    }
+}
@@ -0,0 +1,264 @@
+// terms_summary.rs - human-readable summaries of the accepted vocabulary
+
+use super::{
+    defaults,
+    preset::Preset,
+    Terms,
+};
+
+use std::{
+    borrow::Cow,
+    fmt::Write as _,
+};
+
+
+/// The labels of the groups of terms, in order, by which the terms are
+/// summarised.
+const GROUP_LABELS : [(&str, &str); 4] = [
+    ("truey", "case-sensitive"),
+    ("truey", "case-insensitive"),
+    ("falsey", "case-sensitive"),
+    ("falsey", "case-insensitive"),
+];
+
+/// Obtains the name of the origin of the default vocabulary, as selected by
+/// the `"default-locale-*"` and `"default-preset-*"` features.
+fn default_origin_() -> Cow<'static, str> {
+    match (defaults::DEFAULT_LOCALE_TAG, defaults::DEFAULT_PRESET) {
+        (Some(tag), _) => format!("default (locale {tag})").into(),
+        (None, Some(preset)) => format!("default (preset {})", preset.name()).into(),
+        (None, None) => "default (stock)".into(),
+    }
+}
+
+/// Obtains the origin and the groups (in the order of [GROUP_LABELS]) of
+/// the given terms.
+pub(crate) fn origin_and_groups_<'a>(terms : &Terms<'a>) -> (Cow<'static, str>, [&'a [&'a str]; 4]) {
+    match terms {
+        Terms::Default => (
+            default_origin_(),
+            [
+                &defaults::TRUEY_PRECISE_STRINGS[..],
+                &defaults::TRUEY_LOWERCASE_STRINGS[..],
+                &defaults::FALSEY_PRECISE_STRINGS[..],
+                &defaults::FALSEY_LOWERCASE_STRINGS[..],
+            ],
+        ),
+        Terms::Strings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings,
+            truey_lowercase_strings,
+        }
+        | Terms::SortedStrings {
+            falsey_precise_strings,
+            falsey_lowercase_strings,
+            truey_precise_strings,
+            truey_lowercase_strings,
+        } => (
+            "custom".into(),
+            [
+                truey_precise_strings,
+                truey_lowercase_strings,
+                falsey_precise_strings,
+                falsey_lowercase_strings,
+            ],
+        ),
+    }
+}
+
+/// Formats the given terms, of the given origin, as a Markdown table.
+fn markdown_table_(
+    origin : &str,
+    groups : [&[&str]; 4],
+) -> String {
+    let mut table = String::from("| Origin | Value | Matching | Accepted terms |\n| --- | --- | --- | --- |\n");
+
+    for ((value, matching), strings) in GROUP_LABELS.iter().zip(groups) {
+        if strings.is_empty() {
+            continue;
+        }
+
+        let strings : Vec<String> = strings.iter().map(|s| format!("`{}`", s.replace('|', "\\|"))).collect();
+
+        let _ = writeln!(table, "| {origin} | {value} | {matching} | {} |", strings.join(", "));
+    }
+
+    table
+}
+
+/// Formats the given terms, of the given origin, as a plain-text summary.
+fn plain_summary_(
+    origin : &str,
+    groups : [&[&str]; 4],
+) -> String {
+    let mut summary = format!("Accepted values ({origin}):\n");
+
+    for ((value, matching), strings) in GROUP_LABELS.iter().zip(groups) {
+        if strings.is_empty() {
+            continue;
+        }
+
+        let label = format!("{value} ({matching})");
+
+        let _ = writeln!(summary, "  {label:<25} : {}", strings.join(", "));
+    }
+
+    summary
+}
+
+impl Terms<'_> {
+    /// Formats the accepted terms as a Markdown table, with one row for
+    /// each non-empty group of terms, grouped by classification and by
+    /// case sensitivity, and labelled by origin (which is `"custom"` for
+    /// all but [Terms::Default]).
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::Terms;
+    ///
+    /// let terms = Terms::Strings {
+    ///     falsey_precise_strings :   &[],
+    ///     falsey_lowercase_strings : &["non"],
+    ///     truey_precise_strings :    &["OUI"],
+    ///     truey_lowercase_strings :  &["oui", "vrai"],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "\
+    /// | Origin | Value | Matching | Accepted terms |
+    /// | --- | --- | --- | --- |
+    /// | custom | truey | case-sensitive | `OUI` |
+    /// | custom | truey | case-insensitive | `oui`, `vrai` |
+    /// | custom | falsey | case-insensitive | `non` |
+    /// ",
+    ///     terms.to_markdown_table()
+    /// );
+    /// ```
+    pub fn to_markdown_table(&self) -> String {
+        let (origin, groups) = origin_and_groups_(self);
+
+        markdown_table_(&origin, groups)
+    }
+
+    /// Formats the accepted terms as a plain-text summary, with one line
+    /// for each non-empty group of terms, grouped as by
+    /// [Terms::to_markdown_table()].
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::Terms;
+    ///
+    /// let terms = Terms::Strings {
+    ///     falsey_precise_strings :   &[],
+    ///     falsey_lowercase_strings : &["non"],
+    ///     truey_precise_strings :    &[],
+    ///     truey_lowercase_strings :  &["oui", "vrai"],
+    /// };
+    ///
+    /// assert_eq!(
+    ///     "\
+    /// Accepted values (custom):
+    ///   truey (case-insensitive)  : oui, vrai
+    ///   falsey (case-insensitive) : non
+    /// ",
+    ///     terms.to_plain_summary()
+    /// );
+    /// ```
+    pub fn to_plain_summary(&self) -> String {
+        let (origin, groups) = origin_and_groups_(self);
+
+        plain_summary_(&origin, groups)
+    }
+}
+
+impl Preset {
+    /// Formats the accepted terms of the preset as by
+    /// [Terms::to_markdown_table()], labelled by the preset's name.
+    pub fn to_markdown_table(&self) -> String {
+        let (_, groups) = origin_and_groups_(&self.terms());
+
+        markdown_table_(&format!("preset {}", self.name()), groups)
+    }
+
+    /// Formats the accepted terms of the preset as by
+    /// [Terms::to_plain_summary()], labelled by the preset's name.
+    pub fn to_plain_summary(&self) -> String {
+        let (_, groups) = origin_and_groups_(&self.terms());
+
+        plain_summary_(&format!("preset {}", self.name()), groups)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_Terms_to_markdown_table_Default() {
+        let table = Terms::Default.to_markdown_table();
+
+        assert!(table.starts_with("| Origin | Value | Matching | Accepted terms |\n| --- | --- | --- | --- |\n"));
+        assert!(table.contains("| default (stock) | truey | case-insensitive | `true`, `yes`, `on`, `1` |\n"));
+        assert!(table.contains("| default (stock) | falsey | case-insensitive | `false`, `no`, `off`, `0` |\n"));
+        assert_eq!(6, table.lines().count());
+    }
+
+    #[test]
+    fn TEST_default_origin_() {
+        #[cfg(to_be_stock_default)]
+        assert_eq!("default (stock)", default_origin_());
+        #[cfg(feature = "default-locale-fr")]
+        assert_eq!("default (locale fr)", default_origin_());
+        #[cfg(feature = "default-locale-zh")]
+        assert_eq!("default (locale zh)", default_origin_());
+        #[cfg(feature = "default-preset-yaml11")]
+        assert_eq!("default (preset yaml11)", default_origin_());
+    }
+
+    #[test]
+    fn TEST_Terms_to_markdown_table_ESCAPING() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &["a|b"],
+            falsey_lowercase_strings : &[],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &[],
+        };
+
+        assert!(terms.to_markdown_table().ends_with("| custom | falsey | case-sensitive | `a\\|b` |\n"));
+    }
+
+    #[test]
+    fn TEST_Terms_to_plain_summary_EMPTY() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &[],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &[],
+        };
+
+        assert_eq!("Accepted values (custom):\n", terms.to_plain_summary());
+    }
+
+    #[test]
+    fn TEST_Preset_to_plain_summary() {
+        let summary = Preset::ToBeOrNotToBe.to_plain_summary();
+
+        assert_eq!(
+            "\
+Accepted values (preset to-be-or-not-to-be):
+  truey (case-insensitive)  : to be
+  falsey (case-insensitive) : not to be
+",
+            summary
+        );
+        assert!(Preset::Yaml11.to_markdown_table().contains("| preset yaml11 | truey | case-sensitive | "));
+    }
+}
@@ -0,0 +1,143 @@
+// toggle_command.rs - relative and absolute toggle commands
+
+
+use super::{
+    string_is_truthy_with,
+    Terms,
+};
+
+
+/// The words - matched case-insensitively - that denote a toggle command.
+const TOGGLE_WORDS : &[&str] = &["flip", "invert", "toggle"];
+
+/// A command to change a boolean state, as given in, say, a chat-ops or
+/// REPL-style control interface, which is either absolute (e.g. `"on"`)
+/// or relative (e.g. `"toggle"`).
+///
+/// # Example:
+///
+/// ```
+/// use to_be::ToggleCommand;
+///
+/// let mut maintenance = false;
+///
+/// for command in ["toggle", "flip", "sideways"] {
+///     match ToggleCommand::parse(command).apply(maintenance) {
+///         Some(state) => maintenance = state,
+///         None => eprintln!("'{command}' is not recognised"),
+///     };
+/// }
+///
+/// assert!(!maintenance);
+/// # #[cfg(to_be_stock_default)]
+/// # {
+/// assert_eq!(Some(true), ToggleCommand::parse("ON").apply(maintenance));
+/// # }
+/// ```
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(Hash)]
+pub enum ToggleCommand {
+    /// Sets the state to the given value.
+    Set(bool),
+    /// Inverts the state.
+    Toggle,
+    /// The command is not recognised.
+    Unrecognised,
+}
+
+impl ToggleCommand {
+    /// Parses the given command, when trimmed, which is a toggle command if
+    /// it is one of `"toggle"`, `"flip"`, or `"invert"` (in any case), and
+    /// otherwise is classified according to the default vocabulary.
+    pub fn parse(s : &str) -> Self {
+        Self::parse_with(s, Terms::Default)
+    }
+
+    /// Parses the given command, when trimmed, which is a toggle command if
+    /// it is one of `"toggle"`, `"flip"`, or `"invert"` (in any case), and
+    /// otherwise is classified according to the given terms.
+    pub fn parse_with(
+        s : &str,
+        terms : Terms,
+    ) -> Self {
+        let trimmed = s.trim();
+
+        if TOGGLE_WORDS.iter().any(|word| trimmed.eq_ignore_ascii_case(word)) {
+            return Self::Toggle;
+        }
+
+        match string_is_truthy_with(trimmed, terms) {
+            Some(b) => Self::Set(b),
+            None => Self::Unrecognised,
+        }
+    }
+
+    /// Applies the command to the given current state.
+    ///
+    /// # Returns:
+    /// - `None` - the command is not recognised;
+    /// - `Some(state)` - the new state;
+    pub fn apply(
+        &self,
+        current : bool,
+    ) -> Option<bool> {
+        match self {
+            Self::Set(b) => Some(*b),
+            Self::Toggle => Some(!current),
+            Self::Unrecognised => None,
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[test]
+    fn TEST_ToggleCommand_parse_with() {
+        let terms = Terms::Strings {
+            falsey_precise_strings :   &[],
+            falsey_lowercase_strings : &["aus"],
+            truey_precise_strings :    &[],
+            truey_lowercase_strings :  &["an"],
+        };
+
+        for s in ["toggle", "Toggle", " FLIP ", "invert"] {
+            assert_eq!(ToggleCommand::Toggle, ToggleCommand::parse_with(s, terms.clone()), "'{s}'");
+        }
+
+        assert_eq!(ToggleCommand::Set(true), ToggleCommand::parse_with("An", terms.clone()));
+        assert_eq!(ToggleCommand::Set(false), ToggleCommand::parse_with(" aus ", terms.clone()));
+
+        for s in ["", "toggles", "flip it", "on"] {
+            assert_eq!(ToggleCommand::Unrecognised, ToggleCommand::parse_with(s, terms.clone()), "'{s}'");
+        }
+    }
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_ToggleCommand_parse() {
+        assert_eq!(ToggleCommand::Set(true), ToggleCommand::parse("yes"));
+        assert_eq!(ToggleCommand::Set(false), ToggleCommand::parse("OFF"));
+        assert_eq!(ToggleCommand::Toggle, ToggleCommand::parse("flip"));
+        assert_eq!(ToggleCommand::Unrecognised, ToggleCommand::parse("maybe"));
+    }
+
+    #[test]
+    fn TEST_ToggleCommand_apply() {
+        for current in [false, true] {
+            assert_eq!(Some(false), ToggleCommand::Set(false).apply(current));
+            assert_eq!(Some(true), ToggleCommand::Set(true).apply(current));
+            assert_eq!(Some(!current), ToggleCommand::Toggle.apply(current));
+            assert_eq!(None, ToggleCommand::Unrecognised.apply(current));
+        }
+    }
+}
@@ -0,0 +1,709 @@
+// truthiness.rs - the tri-state outcome of classification
+
+
+use super::Truthy;
+
+use std::{
+    error,
+    fmt,
+    ops,
+};
+
+
+/// The tri-state outcome of classification, i.e. "falsey", not "truthy"
+/// (unknown), or "truey".
+///
+/// Values are ordered `False < Unknown < True`, so that, say, collections
+/// of classifications may be sorted, or used as the keys of a `BTreeMap`.
+/// In this order, [Truthiness::and()] is the minimum, and
+/// [Truthiness::or()] the maximum, of its operands.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::Truthiness;
+///
+/// let mut values = vec![Truthiness::True, Truthiness::False, Truthiness::Unknown];
+///
+/// values.sort();
+///
+/// assert_eq!(vec![Truthiness::False, Truthiness::Unknown, Truthiness::True], values);
+/// assert_eq!(Some(Truthiness::True), values.into_iter().max());
+/// ```
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+#[derive(PartialOrd)]
+#[derive(Ord)]
+#[derive(Hash)]
+pub enum Truthiness {
+    /// The value is "falsey".
+    False,
+    /// The value is not "truthy", i.e. is neither "falsey" nor "truey".
+    Unknown,
+    /// The value is "truey".
+    True,
+}
+
+impl Truthiness {
+    /// Indicates whether the value is [Truthiness::True].
+    pub const fn is_true(&self) -> bool {
+        matches!(self, Self::True)
+    }
+
+    /// Indicates whether the value is [Truthiness::False].
+    pub const fn is_false(&self) -> bool {
+        matches!(self, Self::False)
+    }
+
+    /// Indicates whether the value is [Truthiness::Unknown].
+    pub const fn is_unknown(&self) -> bool {
+        matches!(self, Self::Unknown)
+    }
+
+    /// The conjunction of the two values, according to Kleene's
+    /// three-valued logic, in which "falsey" prevails over unknown, which
+    /// prevails over "truey".
+    pub fn and(
+        self,
+        other : Self,
+    ) -> Self {
+        match (self, other) {
+            (Self::False, _) | (_, Self::False) => Self::False,
+            (Self::True, Self::True) => Self::True,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// The disjunction of the two values, according to Kleene's
+    /// three-valued logic, in which "truey" prevails over unknown, which
+    /// prevails over "falsey".
+    pub fn or(
+        self,
+        other : Self,
+    ) -> Self {
+        match (self, other) {
+            (Self::True, _) | (_, Self::True) => Self::True,
+            (Self::False, Self::False) => Self::False,
+            _ => Self::Unknown,
+        }
+    }
+
+    /// The exclusive disjunction of the two values, according to Kleene's
+    /// three-valued logic, which is unknown if either is unknown.
+    pub fn xor(
+        self,
+        other : Self,
+    ) -> Self {
+        match (self, other) {
+            (Self::Unknown, _) | (_, Self::Unknown) => Self::Unknown,
+            (lhs, rhs) => Self::from(lhs != rhs),
+        }
+    }
+
+    /// The (material) implication of the other value by this value,
+    /// according to Kleene's three-valued logic, i.e. `!self | other`, which
+    /// is "truey" if this value is "falsey" or the other is "truey", even if
+    /// the remaining one is unknown.
+    pub fn implies(
+        self,
+        other : Self,
+    ) -> Self {
+        (!self).or(other)
+    }
+
+    /// The equivalence of the two values, according to Kleene's
+    /// three-valued logic, which is unknown if either is unknown.
+    pub fn iff(
+        self,
+        other : Self,
+    ) -> Self {
+        !self.xor(other)
+    }
+
+    /// Invokes whichever of the given functions corresponds to the value,
+    /// obtaining its result.
+    ///
+    /// # Example:
+    ///
+    /// ```
+    /// use to_be::Truthiness;
+    ///
+    /// let truthiness = Truthiness::Unknown;
+    ///
+    /// let colour = truthiness.if_truthy(
+    ///     || "always",
+    ///     || "never",
+    ///     || {
+    ///         eprintln!("colour setting not recognised; using 'auto'");
+    ///
+    ///         "auto"
+    ///     },
+    /// );
+    ///
+    /// assert_eq!("auto", colour);
+    /// ```
+    pub fn if_truthy<R, T, F, U>(
+        self,
+        on_true : T,
+        on_false : F,
+        on_unknown : U,
+    ) -> R
+    where
+        T : FnOnce() -> R,
+        F : FnOnce() -> R,
+        U : FnOnce() -> R,
+    {
+        match self {
+            Self::False => on_false(),
+            Self::Unknown => on_unknown(),
+            Self::True => on_true(),
+        }
+    }
+}
+
+/// The negation of the value, according to Kleene's three-valued logic, in
+/// which the negation of unknown is unknown.
+impl ops::Not for Truthiness {
+    type Output = Self;
+
+    fn not(self) -> Self {
+        match self {
+            Self::False => Self::True,
+            Self::Unknown => Self::Unknown,
+            Self::True => Self::False,
+        }
+    }
+}
+
+/// Compares with a `bool`, to which [Truthiness::Unknown] is never equal,
+/// so that, say, `classification == true` may be used in place of a
+/// comparison with `Some(true)`.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::Truthiness;
+///
+/// assert!(Truthiness::True == true);
+/// assert!(Truthiness::False != true);
+/// assert!(Truthiness::Unknown != true);
+/// assert!(Truthiness::Unknown != false);
+/// ```
+impl PartialEq<bool> for Truthiness {
+    fn eq(
+        &self,
+        other : &bool,
+    ) -> bool {
+        Self::from(*other) == *self
+    }
+}
+
+/// Compares with a [Truthiness], as by `PartialEq<bool> for Truthiness`.
+impl PartialEq<Truthiness> for bool {
+    fn eq(
+        &self,
+        other : &Truthiness,
+    ) -> bool {
+        other == self
+    }
+}
+
+/// The error returned by the conversion of [Truthiness::Unknown] to
+/// `bool`.
+#[derive(Clone)]
+#[derive(Copy)]
+#[derive(Debug)]
+#[derive(PartialEq)]
+#[derive(Eq)]
+pub struct UnknownTruthiness;
+
+impl fmt::Display for UnknownTruthiness {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.write_str("truthiness is unknown")
+    }
+}
+
+impl error::Error for UnknownTruthiness {}
+
+/// The conjunction of the two values, as by [Truthiness::and()].
+impl ops::BitAnd for Truthiness {
+    type Output = Self;
+
+    fn bitand(
+        self,
+        rhs : Self,
+    ) -> Self {
+        self.and(rhs)
+    }
+}
+
+/// The disjunction of the two values, as by [Truthiness::or()].
+impl ops::BitOr for Truthiness {
+    type Output = Self;
+
+    fn bitor(
+        self,
+        rhs : Self,
+    ) -> Self {
+        self.or(rhs)
+    }
+}
+
+/// The exclusive disjunction of the two values, as by [Truthiness::xor()].
+impl ops::BitXor for Truthiness {
+    type Output = Self;
+
+    fn bitxor(
+        self,
+        rhs : Self,
+    ) -> Self {
+        self.xor(rhs)
+    }
+}
+
+impl From<bool> for Truthiness {
+    fn from(b : bool) -> Self {
+        if b {
+            Self::True
+        } else {
+            Self::False
+        }
+    }
+}
+
+/// Converts the result of a classification (e.g. of
+/// [string_is_truthy()](crate::string_is_truthy())), in which `None`
+/// denotes unknown.
+impl From<Option<bool>> for Truthiness {
+    fn from(r : Option<bool>) -> Self {
+        r.map_or(Self::Unknown, Self::from)
+    }
+}
+
+/// Converts to the form of the result of a classification, in which
+/// unknown is denoted by `None`.
+impl From<Truthiness> for Option<bool> {
+    fn from(truthiness : Truthiness) -> Self {
+        match truthiness {
+            Truthiness::False => Some(false),
+            Truthiness::Unknown => None,
+            Truthiness::True => Some(true),
+        }
+    }
+}
+
+/// Converts to `bool`, failing for [Truthiness::Unknown], so that, say, a
+/// determinate classification may be required with `?`.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     Truthiness,
+///     UnknownTruthiness,
+/// };
+///
+/// assert_eq!(Ok(true), bool::try_from(Truthiness::True));
+/// assert_eq!(Err(UnknownTruthiness), bool::try_from(Truthiness::Unknown));
+/// ```
+impl TryFrom<Truthiness> for bool {
+    type Error = UnknownTruthiness;
+
+    fn try_from(truthiness : Truthiness) -> Result<Self, Self::Error> {
+        Option::<bool>::from(truthiness).ok_or(UnknownTruthiness)
+    }
+}
+
+/// The connectives of Kleene's three-valued logic, for classifications.
+///
+/// The methods are prefixed with `kleene_` so that they are not hidden by
+/// the inherent methods of `Option` (e.g. `Option::and()`), whose meanings
+/// differ.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::KleeneLogic as _;
+///
+/// let maintenance : Option<bool> = None; // not recognised
+/// let read_only = Some(true);
+///
+/// assert_eq!(Some(true), maintenance.kleene_or(read_only));
+/// assert_eq!(None, maintenance.kleene_and(read_only));
+/// assert_eq!(Some(true), Some(false).kleene_implies(maintenance));
+/// ```
+pub trait KleeneLogic : Sized {
+    /// The conjunction of the two values, in which "falsey" prevails over
+    /// unknown, which prevails over "truey".
+    fn kleene_and(
+        self,
+        other : Self,
+    ) -> Self;
+
+    /// The disjunction of the two values, in which "truey" prevails over
+    /// unknown, which prevails over "falsey".
+    fn kleene_or(
+        self,
+        other : Self,
+    ) -> Self;
+
+    /// The negation of the value, in which the negation of unknown is
+    /// unknown.
+    fn kleene_not(self) -> Self;
+
+    /// The exclusive disjunction of the two values, which is unknown if
+    /// either is unknown.
+    fn kleene_xor(
+        self,
+        other : Self,
+    ) -> Self;
+
+    /// The (material) implication of the other value by this value, i.e.
+    /// the disjunction of the negation of this value and the other value.
+    fn kleene_implies(
+        self,
+        other : Self,
+    ) -> Self {
+        self.kleene_not().kleene_or(other)
+    }
+
+    /// The equivalence of the two values, which is unknown if either is
+    /// unknown.
+    fn kleene_iff(
+        self,
+        other : Self,
+    ) -> Self {
+        self.kleene_xor(other).kleene_not()
+    }
+}
+
+impl KleeneLogic for Truthiness {
+    fn kleene_and(
+        self,
+        other : Self,
+    ) -> Self {
+        self.and(other)
+    }
+
+    fn kleene_or(
+        self,
+        other : Self,
+    ) -> Self {
+        self.or(other)
+    }
+
+    fn kleene_not(self) -> Self {
+        !self
+    }
+
+    fn kleene_xor(
+        self,
+        other : Self,
+    ) -> Self {
+        self.xor(other)
+    }
+}
+
+/// Kleene logic on the results of classifications (e.g. of
+/// [string_is_truthy()](crate::string_is_truthy())), in which `None`
+/// denotes unknown.
+impl KleeneLogic for Option<bool> {
+    fn kleene_and(
+        self,
+        other : Self,
+    ) -> Self {
+        Truthiness::from(self).and(other.into()).into()
+    }
+
+    fn kleene_or(
+        self,
+        other : Self,
+    ) -> Self {
+        Truthiness::from(self).or(other.into()).into()
+    }
+
+    fn kleene_not(self) -> Self {
+        self.map(|b| !b)
+    }
+
+    fn kleene_xor(
+        self,
+        other : Self,
+    ) -> Self {
+        Some(self? != other?)
+    }
+}
+
+/// Three-way branching on a classification, as an alternative to matching
+/// on its variants.
+pub trait IfTruthy {
+    /// Invokes whichever of the given functions corresponds to the
+    /// classification - "truey", "falsey", or unknown - obtaining its
+    /// result.
+    fn if_truthy<R, T, F, U>(
+        self,
+        on_true : T,
+        on_false : F,
+        on_unknown : U,
+    ) -> R
+    where
+        T : FnOnce() -> R,
+        F : FnOnce() -> R,
+        U : FnOnce() -> R;
+}
+
+impl IfTruthy for Truthiness {
+    fn if_truthy<R, T, F, U>(
+        self,
+        on_true : T,
+        on_false : F,
+        on_unknown : U,
+    ) -> R
+    where
+        T : FnOnce() -> R,
+        F : FnOnce() -> R,
+        U : FnOnce() -> R,
+    {
+        Truthiness::if_truthy(self, on_true, on_false, on_unknown)
+    }
+}
+
+/// Branches on the result of a classification (e.g. of
+/// [string_is_truthy()](crate::string_is_truthy())), in which `None`
+/// denotes unknown.
+impl IfTruthy for Option<bool> {
+    fn if_truthy<R, T, F, U>(
+        self,
+        on_true : T,
+        on_false : F,
+        on_unknown : U,
+    ) -> R
+    where
+        T : FnOnce() -> R,
+        F : FnOnce() -> R,
+        U : FnOnce() -> R,
+    {
+        Truthiness::from(self).if_truthy(on_true, on_false, on_unknown)
+    }
+}
+
+/// Classifies the given value, as by [Truthy::is_truthy()], and invokes
+/// whichever of the given functions corresponds to the classification -
+/// "truey", "falsey", or not "truthy" (unknown) - obtaining its result.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::if_truthy;
+///
+/// let value = std::env::var("MY_SERVICE_VERBOSE").unwrap_or_default();
+///
+/// # #[cfg(all(feature = "implement-Truthy-for-String", feature = "implement-Truthy-for-str"))]
+/// let verbosity = if_truthy(
+///     value.as_str(),
+///     || 2,
+///     || 0,
+///     || {
+///         eprintln!("MY_SERVICE_VERBOSE='{value}' not recognised; using default");
+///
+///         1
+///     },
+/// );
+/// ```
+pub fn if_truthy<V, R, T, F, U>(
+    value : V,
+    on_true : T,
+    on_false : F,
+    on_unknown : U,
+) -> R
+where
+    V : Truthy,
+    T : FnOnce() -> R,
+    F : FnOnce() -> R,
+    U : FnOnce() -> R,
+{
+    Truthiness::from(value.is_truthy()).if_truthy(on_true, on_false, on_unknown)
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    const ALL : [Truthiness; 3] = [Truthiness::False, Truthiness::Unknown, Truthiness::True];
+
+    #[test]
+    fn TEST_Truthiness_KLEENE() {
+        use Truthiness::{
+            False as F,
+            True as T,
+            Unknown as U,
+        };
+
+        let and = [[F, F, F], [F, U, U], [F, U, T]];
+        let or = [[F, U, T], [U, U, T], [T, T, T]];
+
+        for (i, &lhs) in ALL.iter().enumerate() {
+            for (j, &rhs) in ALL.iter().enumerate() {
+                assert_eq!(and[i][j], lhs.and(rhs), "{lhs:?} && {rhs:?}");
+                assert_eq!(or[i][j], lhs.or(rhs), "{lhs:?} || {rhs:?}");
+            }
+        }
+
+        assert_eq!([T, U, F], ALL.map(|t| !t));
+
+        let xor = [[F, U, T], [U, U, U], [T, U, F]];
+        let implies = [[T, T, T], [U, U, T], [F, U, T]];
+        let iff = [[T, U, F], [U, U, U], [F, U, T]];
+
+        for (i, &lhs) in ALL.iter().enumerate() {
+            for (j, &rhs) in ALL.iter().enumerate() {
+                assert_eq!(xor[i][j], lhs.xor(rhs), "{lhs:?} ^ {rhs:?}");
+                assert_eq!(implies[i][j], lhs.implies(rhs), "{lhs:?} -> {rhs:?}");
+                assert_eq!(iff[i][j], lhs.iff(rhs), "{lhs:?} <-> {rhs:?}");
+
+                assert_eq!(lhs.and(rhs), lhs & rhs);
+                assert_eq!(lhs.or(rhs), lhs | rhs);
+                assert_eq!(lhs.xor(rhs), lhs ^ rhs);
+            }
+        }
+    }
+
+    #[test]
+    fn TEST_Truthiness_PREDICATES() {
+        assert_eq!([false, false, true], ALL.map(|t| t.is_true()));
+        assert_eq!([true, false, false], ALL.map(|t| t.is_false()));
+        assert_eq!([false, true, false], ALL.map(|t| t.is_unknown()));
+    }
+
+    #[test]
+    fn TEST_Truthiness_PartialEq_bool() {
+        for truthiness in ALL {
+            for b in [false, true] {
+                let expected = Some(b) == Option::<bool>::from(truthiness);
+
+                assert_eq!(expected, truthiness == b, "{truthiness:?} == {b}");
+                assert_eq!(expected, b == truthiness, "{b} == {truthiness:?}");
+                assert_eq!(!expected, truthiness != b, "{truthiness:?} != {b}");
+            }
+        }
+    }
+
+    #[test]
+    fn TEST_KleeneLogic() {
+        for lhs in ALL {
+            for rhs in ALL {
+                let (l, r) = (Option::<bool>::from(lhs), Option::<bool>::from(rhs));
+
+                assert_eq!(lhs.and(rhs), lhs.kleene_and(rhs));
+                assert_eq!(lhs.or(rhs), lhs.kleene_or(rhs));
+                assert_eq!(lhs.xor(rhs), lhs.kleene_xor(rhs));
+                assert_eq!(lhs.implies(rhs), lhs.kleene_implies(rhs));
+                assert_eq!(lhs.iff(rhs), lhs.kleene_iff(rhs));
+
+                assert_eq!(Option::from(lhs.and(rhs)), l.kleene_and(r), "{l:?} && {r:?}");
+                assert_eq!(Option::from(lhs.or(rhs)), l.kleene_or(r), "{l:?} || {r:?}");
+                assert_eq!(Option::from(lhs.xor(rhs)), l.kleene_xor(r), "{l:?} ^ {r:?}");
+                assert_eq!(Option::from(lhs.implies(rhs)), l.kleene_implies(r), "{l:?} -> {r:?}");
+                assert_eq!(Option::from(lhs.iff(rhs)), l.kleene_iff(r), "{l:?} <-> {r:?}");
+            }
+
+            assert_eq!(!lhs, lhs.kleene_not());
+            assert_eq!(Option::from(!lhs), Option::<bool>::from(lhs).kleene_not());
+        }
+    }
+
+    #[test]
+    fn TEST_Truthiness_Ord() {
+        use std::collections::BTreeMap;
+
+        assert!(Truthiness::False < Truthiness::Unknown);
+        assert!(Truthiness::Unknown < Truthiness::True);
+        assert!(Truthiness::False < Truthiness::True);
+
+        for lhs in ALL {
+            for rhs in ALL {
+                assert_eq!(lhs.min(rhs), lhs.and(rhs), "{lhs:?} and {rhs:?}");
+                assert_eq!(lhs.max(rhs), lhs.or(rhs), "{lhs:?} or {rhs:?}");
+            }
+        }
+
+        let mut counts = BTreeMap::new();
+
+        for value in [Truthiness::True, Truthiness::Unknown, Truthiness::True, Truthiness::False] {
+            *counts.entry(value).or_insert(0) += 1;
+        }
+
+        assert_eq!(
+            vec![(Truthiness::False, 1), (Truthiness::Unknown, 1), (Truthiness::True, 2)],
+            counts.into_iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn TEST_Truthiness_From() {
+        assert_eq!(Truthiness::False, Truthiness::from(false));
+        assert_eq!(Truthiness::True, Truthiness::from(true));
+        assert_eq!(Truthiness::False, Truthiness::from(Some(false)));
+        assert_eq!(Truthiness::Unknown, Truthiness::from(None));
+        let truthiness : Truthiness = Some(true).into();
+
+        assert_eq!(Truthiness::True, truthiness);
+
+        for truthiness in ALL {
+            assert_eq!(truthiness, Truthiness::from(Option::<bool>::from(truthiness)));
+        }
+
+        assert_eq!(Ok(false), bool::try_from(Truthiness::False));
+        assert_eq!(Err(UnknownTruthiness), bool::try_from(Truthiness::Unknown));
+        assert_eq!(Ok(true), Truthiness::True.try_into());
+
+        for b in [false, true] {
+            assert_eq!(Ok(b), bool::try_from(Truthiness::from(b)));
+        }
+
+        assert_eq!("truthiness is unknown", UnknownTruthiness.to_string());
+    }
+
+    #[test]
+    fn TEST_Truthiness_if_truthy() {
+        let truthinesses = [Truthiness::True, Truthiness::False, Truthiness::Unknown];
+
+        assert_eq!(["T", "F", "U"], truthinesses.map(|t| t.if_truthy(|| "T", || "F", || "U")));
+        assert_eq!(["T", "F", "U"], [Some(true), Some(false), None].map(|r| r.if_truthy(|| "T", || "F", || "U")));
+    }
+
+    #[test]
+    fn TEST_if_truthy() {
+        struct Answer(Option<bool>);
+
+        impl Truthy for Answer {
+            fn is_truthy(&self) -> Option<bool> {
+                self.0
+            }
+        }
+
+        let mut unknowns = 0;
+        let mut branch = |answer| {
+            if_truthy(answer, || 'T', || 'F', || {
+                unknowns += 1;
+
+                'U'
+            })
+        };
+
+        assert_eq!('T', branch(Answer(Some(true))));
+        assert_eq!('F', branch(Answer(Some(false))));
+        assert_eq!('U', branch(Answer(None)));
+        assert_eq!(1, unknowns);
+    }
+}
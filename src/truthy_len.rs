@@ -0,0 +1,193 @@
+// truthy_len.rs - truthiness of collections by emptiness
+
+
+use super::Truthiness;
+
+use std::collections::{
+    BTreeMap,
+    BTreeSet,
+    BinaryHeap,
+    HashMap,
+    HashSet,
+    LinkedList,
+    VecDeque,
+};
+
+
+/// Trait that provides truthy attributes for collections (and strings) by
+/// length, i.e. an empty instance is "falsey" and a non-empty instance is
+/// "truey", as in Python and similar languages.
+///
+/// This is distinct from [Truthy](crate::Truthy) because it has different
+/// semantics: e.g. `"no"` is "falsey" by [Truthy](crate::Truthy) but
+/// "truey" by `TruthyLen`.
+///
+/// # Example:
+///
+/// ```
+/// use to_be::{
+///     Truthiness,
+///     TruthyLen as _,
+/// };
+///
+/// assert_eq!(true, Vec::<i32>::new().is_len_falsey());
+/// assert_eq!(true, "no".is_len_truey());
+/// assert_eq!(Truthiness::True, [1, 2, 3][..].len_truthiness());
+/// ```
+pub trait TruthyLen {
+    /// Indicates whether the instance is "truey" by length, i.e. is not
+    /// empty.
+    fn is_len_truey(&self) -> bool;
+
+    /// Indicates whether the instance is "falsey" by length, i.e. is empty.
+    fn is_len_falsey(&self) -> bool {
+        !self.is_len_truey()
+    }
+
+    /// Obtains the truthiness of the instance by length, which is never
+    /// `Truthiness::Unknown`.
+    fn len_truthiness(&self) -> Truthiness {
+        if self.is_len_truey() {
+            Truthiness::True
+        } else {
+            Truthiness::False
+        }
+    }
+}
+
+impl<T> TruthyLen for &T
+where
+    T : TruthyLen + ?Sized,
+{
+    fn is_len_truey(&self) -> bool {
+        (**self).is_len_truey()
+    }
+}
+
+impl TruthyLen for str {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl TruthyLen for String {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> TruthyLen for [T] {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T, const N : usize> TruthyLen for [T; N] {
+    fn is_len_truey(&self) -> bool {
+        0 != N
+    }
+}
+
+impl<T> TruthyLen for Vec<T> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> TruthyLen for VecDeque<T> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> TruthyLen for LinkedList<T> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> TruthyLen for BinaryHeap<T> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<K, V, S> TruthyLen for HashMap<K, V, S> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<K, V> TruthyLen for BTreeMap<K, V> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T, S> TruthyLen for HashSet<T, S> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+impl<T> TruthyLen for BTreeSet<T> {
+    fn is_len_truey(&self) -> bool {
+        !self.is_empty()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    fn check_<T : TruthyLen + ?Sized>(
+        empty : &T,
+        non_empty : &T,
+    ) {
+        assert!(empty.is_len_falsey());
+        assert!(!empty.is_len_truey());
+        assert_eq!(Truthiness::False, empty.len_truthiness());
+
+        assert!(!non_empty.is_len_falsey());
+        assert!(non_empty.is_len_truey());
+        assert_eq!(Truthiness::True, non_empty.len_truthiness());
+    }
+
+    #[test]
+    fn TEST_TruthyLen_STRINGS() {
+        check_("", "false");
+        check_(&String::new(), &String::from("0"));
+    }
+
+    #[test]
+    fn TEST_TruthyLen_SEQUENCES() {
+        check_::<[i32]>(&[], &[0]);
+        assert!([0u8; 0].is_len_falsey());
+        assert!([0u8; 1].is_len_truey());
+        check_(&Vec::<bool>::new(), &vec![false]);
+        check_(&VecDeque::<bool>::new(), &VecDeque::from([false]));
+        check_(&LinkedList::<bool>::new(), &LinkedList::from([false]));
+        check_(&BinaryHeap::<i32>::new(), &BinaryHeap::from([0]));
+    }
+
+    #[test]
+    fn TEST_TruthyLen_MAPS_AND_SETS() {
+        check_(&HashMap::<i32, i32>::new(), &HashMap::from([(0, 0)]));
+        check_(&BTreeMap::<i32, i32>::new(), &BTreeMap::from([(0, 0)]));
+        check_(&HashSet::<i32>::new(), &HashSet::from([0]));
+        check_(&BTreeSet::<i32>::new(), &BTreeSet::from([0]));
+    }
+
+    #[test]
+    fn TEST_TruthyLen_REFERENCES() {
+        let v = vec![1];
+        let r = &v;
+
+        assert!(TruthyLen::is_len_truey(&r));
+        assert!(TruthyLen::is_len_falsey(&&""));
+    }
+}
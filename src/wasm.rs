@@ -0,0 +1,235 @@
+// wasm.rs - WebAssembly (JavaScript) bindings
+
+//! WebAssembly bindings, exported to JavaScript via **wasm-bindgen**, so
+//! that front-end and Node.js code classifies strings identically to Rust
+//! code.
+//!
+//! ```js
+//! import { stringIsTruthy, Terms } from "to-be";
+//!
+//! stringIsTruthy("Yes");     // true
+//! stringIsTruthy("off");     // false
+//! stringIsTruthy("maybe");   // undefined
+//!
+//! const terms = new Terms({ falseyLowercaseStrings : [ "non" ], trueyLowercaseStrings : [ "oui" ] });
+//!
+//! terms.stringIsTruthy("OUI");   // true
+//! ```
+
+use super::{
+    locale,
+    preset::Preset,
+    CompiledTerms,
+};
+
+use wasm_bindgen::prelude::*;
+
+
+/// Indicates whether the given string, when trimmed, is "truthy" according
+/// to the default vocabulary and, if so, whether it is "truey" or "falsey".
+///
+/// # Returns:
+/// - `undefined` - string is not classified as "truthy";
+/// - `false` - string (is classified as "truthy" and) is deemed "falsey";
+/// - `true` - string (is classified as "truthy" and) is deemed "truey";
+#[wasm_bindgen(js_name = stringIsTruthy)]
+pub fn string_is_truthy(s : &str) -> Option<bool> {
+    super::string_is_truthy(s)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "falsey"
+/// according to the default vocabulary.
+#[wasm_bindgen(js_name = stringIsFalsey)]
+pub fn string_is_falsey(s : &str) -> bool {
+    super::string_is_falsey(s)
+}
+
+/// Indicates that the given string, when trimmed, is deemed as "truey"
+/// according to the default vocabulary.
+#[wasm_bindgen(js_name = stringIsTruey)]
+pub fn string_is_truey(s : &str) -> bool {
+    super::string_is_truey(s)
+}
+
+
+/// The options object from which a [Terms] instance is created, each of
+/// whose properties is optional (and empty if absent).
+#[wasm_bindgen(typescript_custom_section)]
+const TERMS_OPTIONS_TS : &str = r#"
+export interface TermsOptions {
+    falseyPreciseStrings? : string[];
+    falseyLowercaseStrings? : string[];
+    trueyPreciseStrings? : string[];
+    trueyLowercaseStrings? : string[];
+}
+"#;
+
+#[wasm_bindgen]
+extern "C" {
+    /// The options object from which a [Terms] instance is created.
+    #[wasm_bindgen(typescript_type = "TermsOptions")]
+    pub type TermsOptions;
+
+    #[wasm_bindgen(method, getter, js_name = falseyPreciseStrings)]
+    fn falsey_precise_strings(this : &TermsOptions) -> Option<Vec<String>>;
+
+    #[wasm_bindgen(method, getter, js_name = falseyLowercaseStrings)]
+    fn falsey_lowercase_strings(this : &TermsOptions) -> Option<Vec<String>>;
+
+    #[wasm_bindgen(method, getter, js_name = trueyPreciseStrings)]
+    fn truey_precise_strings(this : &TermsOptions) -> Option<Vec<String>>;
+
+    #[wasm_bindgen(method, getter, js_name = trueyLowercaseStrings)]
+    fn truey_lowercase_strings(this : &TermsOptions) -> Option<Vec<String>>;
+}
+
+fn strs_(strings : &[String]) -> Vec<&str> {
+    strings.iter().map(String::as_str).collect()
+}
+
+/// Custom terms, exported to JavaScript as the class `Terms`, against which
+/// strings may be classified.
+#[wasm_bindgen]
+pub struct Terms {
+    terms : CompiledTerms,
+}
+
+impl Terms {
+    /// Creates an instance from the given term strings.
+    pub fn from_strings(
+        falsey_precise_strings : &[String],
+        falsey_lowercase_strings : &[String],
+        truey_precise_strings : &[String],
+        truey_lowercase_strings : &[String],
+    ) -> Self {
+        let falsey_precise_strings = strs_(falsey_precise_strings);
+        let falsey_lowercase_strings = strs_(falsey_lowercase_strings);
+        let truey_precise_strings = strs_(truey_precise_strings);
+        let truey_lowercase_strings = strs_(truey_lowercase_strings);
+
+        Self {
+            terms : CompiledTerms::new(super::Terms::Strings {
+                falsey_precise_strings :   &falsey_precise_strings,
+                falsey_lowercase_strings : &falsey_lowercase_strings,
+                truey_precise_strings :    &truey_precise_strings,
+                truey_lowercase_strings :  &truey_lowercase_strings,
+            }),
+        }
+    }
+}
+
+#[wasm_bindgen]
+impl Terms {
+    /// Creates an instance from the given options object.
+    #[wasm_bindgen(constructor)]
+    pub fn new(options : &TermsOptions) -> Self {
+        Self::from_strings(
+            &options.falsey_precise_strings().unwrap_or_default(),
+            &options.falsey_lowercase_strings().unwrap_or_default(),
+            &options.truey_precise_strings().unwrap_or_default(),
+            &options.truey_lowercase_strings().unwrap_or_default(),
+        )
+    }
+
+    /// Obtains the terms registered for the given locale, or `undefined`
+    /// if there are none.
+    #[wasm_bindgen(js_name = forLocale)]
+    pub fn for_locale(tag : &str) -> Option<Terms> {
+        locale::terms_for_locale(tag).map(|terms| Self {
+            terms : CompiledTerms::new(terms),
+        })
+    }
+
+    /// Obtains the terms of the named preset (see
+    /// [Preset::from_name()]), or `undefined` if there is no such preset.
+    #[wasm_bindgen(js_name = forPreset)]
+    pub fn for_preset(name : &str) -> Option<Terms> {
+        Preset::from_name(name).map(|preset| Self {
+            terms : CompiledTerms::new(preset.terms()),
+        })
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy"
+    /// according to the terms and, if so, whether it is "truey" or
+    /// "falsey".
+    ///
+    /// # Returns:
+    /// - `undefined` - string is not classified as "truthy";
+    /// - `false` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `true` - string (is classified as "truthy" and) is deemed "truey";
+    #[wasm_bindgen(js_name = stringIsTruthy)]
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        self.terms.string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as
+    /// "falsey" according to the terms.
+    #[wasm_bindgen(js_name = stringIsFalsey)]
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.terms.string_is_falsey(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey"
+    /// according to the terms.
+    #[wasm_bindgen(js_name = stringIsTruey)]
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.terms.string_is_truey(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+
+    #[cfg(to_be_stock_default)]
+    #[test]
+    fn TEST_string_is_truthy() {
+        assert_eq!(Some(true), string_is_truthy(" Yes "));
+        assert_eq!(Some(false), string_is_truthy("off"));
+        assert_eq!(None, string_is_truthy("maybe"));
+
+        assert!(string_is_truey("TRUE"));
+        assert!(!string_is_truey("false"));
+        assert!(string_is_falsey("false"));
+        assert!(!string_is_falsey("maybe"));
+    }
+
+    #[test]
+    fn TEST_Terms() {
+        let terms = Terms::from_strings(&["NYET".into()], &["non".into()], &[], &["Oui".into()]);
+
+        assert_eq!(Some(false), terms.string_is_truthy("NYET"));
+        assert_eq!(None, terms.string_is_truthy("nyet"));
+        assert_eq!(Some(false), terms.string_is_truthy(" NON "));
+        assert_eq!(Some(true), terms.string_is_truthy("oui"));
+        assert!(terms.string_is_truey("OUI"));
+        assert!(terms.string_is_falsey("non"));
+        assert_eq!(None, terms.string_is_truthy("yes"));
+    }
+
+    #[test]
+    fn TEST_Terms_for_locale_AND_for_preset() {
+        assert_eq!(Some(true), Terms::for_locale("en").unwrap().string_is_truthy("yes"));
+        assert!(Terms::for_locale("xx-unknown").is_none());
+
+        let yaml11 = Terms::for_preset("yaml11").unwrap();
+
+        assert_eq!(Some(true), yaml11.string_is_truthy("Yes"));
+        assert_eq!(None, yaml11.string_is_truthy("yEs"));
+        assert!(Terms::for_preset("unknown").is_none());
+    }
+}
@@ -0,0 +1,523 @@
+// watch.rs - terms loaded from, and reloaded on changes to, a file
+
+//! Terms loaded from a file, and reloaded whenever it changes, so that the
+//! accepted vocabulary may be tuned without restarting the program.
+//!
+//! A terms file comprises lines of the form `key = term, term, ...`, where
+//! `key` is one of `falsey_precise_strings`, `falsey_lowercase_strings`,
+//! `truey_precise_strings`, and `truey_lowercase_strings` (as in
+//! [Terms::Strings]). Each term is trimmed, and so may not contain `','`.
+//! A key may appear on more than one line, and those that are absent are
+//! empty. Blank lines and lines beginning with `'#'` are ignored. For
+//! example:
+//!
+//! ```text
+//! # accepted vocabulary of the flag service
+//! falsey_lowercase_strings = 0, false, no, off, disabled
+//! truey_lowercase_strings  = 1, true, yes, on, enabled
+//! ```
+
+use super::{
+    CompiledTerms,
+    Terms,
+};
+
+use arc_swap::ArcSwap;
+use notify::{
+    RecommendedWatcher,
+    RecursiveMode,
+    Watcher as _,
+};
+
+use std::{
+    error,
+    fmt,
+    fs,
+    io,
+    path::{
+        Path,
+        PathBuf,
+    },
+    sync::{
+        mpsc,
+        Arc,
+    },
+    thread,
+    time::Duration,
+};
+
+
+/// The period for which the watched file must be unchanged before it is
+/// reloaded, so that the several events of a single save - such as the
+/// truncation of the file and the writing of its new content - are
+/// coalesced into one reload, of the complete file.
+const RELOAD_DELAY : Duration = Duration::from_millis(100);
+
+
+/// An error in loading, or watching, a terms file.
+#[derive(Debug)]
+pub enum WatchError {
+    /// The file could not be read.
+    Io(io::Error),
+    /// The file could not be parsed.
+    Parse {
+        /// The (1-based) number of the offending line.
+        line :    usize,
+        message : String,
+    },
+    /// The file could not be watched.
+    Notify(notify::Error),
+}
+
+impl fmt::Display for WatchError {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "could not read terms file: {e}"),
+            Self::Parse {
+                line,
+                message,
+            } => write!(f, "could not parse terms file: line {line}: {message}"),
+            Self::Notify(e) => write!(f, "could not watch terms file: {e}"),
+        }
+    }
+}
+
+impl error::Error for WatchError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse {
+                ..
+            } => None,
+            Self::Notify(e) => Some(e),
+        }
+    }
+}
+
+impl From<io::Error> for WatchError {
+    fn from(e : io::Error) -> Self {
+        Self::Io(e)
+    }
+}
+
+impl From<notify::Error> for WatchError {
+    fn from(e : notify::Error) -> Self {
+        Self::Notify(e)
+    }
+}
+
+
+/// Parses the given content of a terms file (see the module documentation
+/// for the format).
+pub fn parse_terms(text : &str) -> Result<CompiledTerms, WatchError> {
+    let mut falsey_precise_strings = Vec::new();
+    let mut falsey_lowercase_strings = Vec::new();
+    let mut truey_precise_strings = Vec::new();
+    let mut truey_lowercase_strings = Vec::new();
+
+    for (index, line) in text.lines().enumerate() {
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let Some((key, value)) = line.split_once('=') else {
+            return Err(WatchError::Parse {
+                line :    index + 1,
+                message : "expected `key = term, ...`".into(),
+            });
+        };
+
+        let strings = match key.trim() {
+            "falsey_precise_strings" => &mut falsey_precise_strings,
+            "falsey_lowercase_strings" => &mut falsey_lowercase_strings,
+            "truey_precise_strings" => &mut truey_precise_strings,
+            "truey_lowercase_strings" => &mut truey_lowercase_strings,
+            key => {
+                return Err(WatchError::Parse {
+                    line :    index + 1,
+                    message : format!("unrecognised key '{key}'"),
+                });
+            },
+        };
+
+        strings.extend(value.split(',').map(str::trim).filter(|term| !term.is_empty()));
+    }
+
+    Ok(CompiledTerms::new(Terms::Strings {
+        falsey_precise_strings :   &falsey_precise_strings,
+        falsey_lowercase_strings : &falsey_lowercase_strings,
+        truey_precise_strings :    &truey_precise_strings,
+        truey_lowercase_strings :  &truey_lowercase_strings,
+    }))
+}
+
+fn load_(path : &Path) -> Result<CompiledTerms, WatchError> {
+    parse_terms(&fs::read_to_string(path)?)
+}
+
+/// Reloads the terms from the given file into the given handle, unless the
+/// file cannot be read or parsed or - since it is then most likely being
+/// written - it has no terms where the current terms have some.
+fn reload_if_complete_(
+    path : &Path,
+    current : &ArcSwap<CompiledTerms>,
+) {
+    match load_(path) {
+        Ok(terms) if terms.is_empty_() && !current.load().is_empty_() => {
+            debug_event_!(path = %path.display(), "terms file has no terms; not reloaded");
+        },
+        Ok(terms) => {
+            debug_event_!(path = %path.display(), "terms file reloaded");
+
+            current.store(Arc::new(terms));
+        },
+        Err(_e) => {
+            debug_event_!(path = %path.display(), error = %_e, "terms file not reloaded");
+        },
+    };
+}
+
+/// Reloads the terms from the given file into the given handle once each
+/// burst of the changes notified on the given channel has ended, until
+/// the channel is closed.
+fn reload_on_changes_(
+    path : &Path,
+    current : &ArcSwap<CompiledTerms>,
+    changes : &mpsc::Receiver<()>,
+) {
+    while changes.recv().is_ok() {
+        loop {
+            match changes.recv_timeout(RELOAD_DELAY) {
+                Ok(()) => continue,
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+        }
+
+        reload_if_complete_(path, current);
+    }
+}
+
+
+/// Terms loaded from a file, and reloaded whenever it changes.
+///
+/// The current terms are held in an atomically swappable handle, so
+/// classification never blocks on - nor observes a partial - reload. If a
+/// reload fails (e.g. because the file has been saved with a syntax error)
+/// the previous terms remain in effect.
+///
+/// Changes are reloaded once the file has been unchanged for a short
+/// period, so that a file is not reloaded while it is being written, and a
+/// file that has no terms does not replace terms that have some (as it
+/// would be, say, when read just after being truncated). A file is best
+/// replaced by renaming a complete file into its place.
+///
+/// # Example:
+///
+/// ```no_run
+/// use to_be::watch::WatchedTerms;
+///
+/// let terms = WatchedTerms::open("/etc/my-service/terms.conf").unwrap();
+///
+/// // ... later, and repeatedly, with the latest terms
+///
+/// if terms.string_is_truey("enabled") {
+///     // ...
+/// }
+/// ```
+pub struct WatchedTerms {
+    path :     PathBuf,
+    current :  Arc<ArcSwap<CompiledTerms>>,
+    _watcher : RecommendedWatcher,
+}
+
+impl fmt::Debug for WatchedTerms {
+    fn fmt(
+        &self,
+        f : &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        f.debug_struct("WatchedTerms")
+            .field("path", &self.path)
+            .field("current", &self.current.load())
+            .finish_non_exhaustive()
+    }
+}
+
+impl WatchedTerms {
+    /// Loads the terms from the given file, and watches it for changes.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - the file could not be read, parsed, or watched;
+    /// - `Ok(terms)` - the (watched) terms;
+    pub fn open<P : AsRef<Path>>(path : P) -> Result<Self, WatchError> {
+        let path = path.as_ref().to_path_buf();
+        let current = Arc::new(ArcSwap::from_pointee(load_(&path)?));
+
+        // the parent directory, rather than the file, is watched, so that
+        // a file that is replaced (as by many editors) continues to be
+        // watched
+
+        let directory = match path.parent() {
+            Some(directory) if !directory.as_os_str().is_empty() => directory.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+        let file_name = path.file_name().map(ToOwned::to_owned);
+
+        // the events are coalesced, and the file reloaded, on a thread of
+        // its own, which ends when the watcher (and so the sender) is
+        // dropped
+
+        let (sender, receiver) = mpsc::channel();
+
+        {
+            let path = path.clone();
+            let current = Arc::clone(&current);
+
+            thread::Builder::new()
+                .name("to-be-watch".into())
+                .spawn(move || reload_on_changes_(&path, &current, &receiver))?;
+        }
+
+        let mut watcher = notify::recommended_watcher(move |event : notify::Result<notify::Event>| {
+            let Ok(event) = event else {
+                return;
+            };
+
+            if event.kind.is_access() || !event.paths.iter().any(|p| p.file_name() == file_name.as_deref()) {
+                return;
+            }
+
+            let _ = sender.send(());
+        })?;
+
+        watcher.watch(&directory, RecursiveMode::NonRecursive)?;
+
+        Ok(Self {
+            path,
+            current,
+            _watcher : watcher,
+        })
+    }
+
+    /// The path of the watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Obtains the current terms.
+    pub fn load(&self) -> Arc<CompiledTerms> {
+        self.current.load_full()
+    }
+
+    /// Reloads the terms from the file immediately, regardless of whether
+    /// it has changed.
+    ///
+    /// # Returns:
+    /// - `Err(e)` - the file could not be read or parsed, and the previous
+    ///   terms remain in effect;
+    /// - `Ok(())` - the terms were reloaded;
+    pub fn reload(&self) -> Result<(), WatchError> {
+        self.current.store(Arc::new(load_(&self.path)?));
+
+        Ok(())
+    }
+
+    /// Indicates whether the given string, when trimmed, is "truthy"
+    /// according to the current terms and, if so, whether it is "truey"
+    /// or "falsey".
+    ///
+    /// # Returns:
+    /// - `None` - string is not classified as "truthy";
+    /// - `Some(false)` - string (is classified as "truthy" and) is deemed
+    ///   "falsey";
+    /// - `Some(true)` - string (is classified as "truthy" and) is deemed
+    ///   "truey";
+    pub fn string_is_truthy(
+        &self,
+        s : &str,
+    ) -> Option<bool> {
+        self.current.load().string_is_truthy(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as
+    /// "falsey" according to the current terms.
+    pub fn string_is_falsey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.current.load().string_is_falsey(s)
+    }
+
+    /// Indicates that the given string, when trimmed, is deemed as "truey"
+    /// according to the current terms.
+    pub fn string_is_truey(
+        &self,
+        s : &str,
+    ) -> bool {
+        self.current.load().string_is_truey(s)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    #![allow(non_snake_case)]
+
+    use super::*;
+
+    use std::time::Instant;
+
+
+    /// Obtains a path, unique to the given test, in the temporary
+    /// directory.
+    fn temp_path_(name : &str) -> PathBuf {
+        std::env::temp_dir().join(format!("to-be-watch-{}-{name}.conf", std::process::id()))
+    }
+
+    /// Replaces the given file with the given content, by renaming a
+    /// complete file into its place.
+    fn replace_file_(
+        path : &Path,
+        content : &str,
+    ) {
+        let temp_path = path.with_extension("tmp");
+
+        fs::write(&temp_path, content).unwrap();
+        fs::rename(&temp_path, path).unwrap();
+    }
+
+    #[test]
+    fn TEST_parse_terms() {
+        let terms = parse_terms(
+            "
+# comment
+falsey_precise_strings = NYET
+falsey_lowercase_strings = non, nein
+truey_lowercase_strings = oui,  , ja
+truey_lowercase_strings = da
+",
+        )
+        .unwrap();
+
+        assert_eq!(Some(false), terms.string_is_truthy("NYET"));
+        assert_eq!(None, terms.string_is_truthy("nyet"));
+        assert_eq!(Some(false), terms.string_is_truthy("NEIN"));
+        assert_eq!(Some(true), terms.string_is_truthy("Oui"));
+        assert_eq!(Some(true), terms.string_is_truthy("da"));
+        assert_eq!(None, terms.string_is_truthy(""));
+        assert_eq!(None, terms.string_is_truthy("yes"));
+    }
+
+    #[test]
+    fn TEST_parse_terms_ERRORS() {
+        match parse_terms("truey_lowercase_strings = yes\nfalsey\n") {
+            Err(WatchError::Parse {
+                line,
+                ..
+            }) => assert_eq!(2, line),
+            r => panic!("unexpected result: {r:?}"),
+        };
+
+        match parse_terms("maybe_strings = perhaps") {
+            Err(WatchError::Parse {
+                line,
+                message,
+            }) => {
+                assert_eq!(1, line);
+                assert_eq!("unrecognised key 'maybe_strings'", message);
+            },
+            r => panic!("unexpected result: {r:?}"),
+        };
+    }
+
+    #[test]
+    fn TEST_WatchedTerms_open_MISSING_FILE() {
+        assert!(matches!(WatchedTerms::open(temp_path_("missing")), Err(WatchError::Io(_))));
+    }
+
+    #[test]
+    fn TEST_reload_if_complete_() {
+        let path = temp_path_("reload-if-complete");
+
+        fs::write(&path, "truey_lowercase_strings = oui\n").unwrap();
+
+        let current = ArcSwap::from_pointee(load_(&path).unwrap());
+
+        // an invalid file leaves the previous terms in effect
+
+        fs::write(&path, "truey_lowercase_strings = ja\nnonsense\n").unwrap();
+        reload_if_complete_(&path, &current);
+
+        assert!(current.load().string_is_truey("oui"));
+
+        // as does a file without terms (e.g. one just truncated) ...
+
+        fs::write(&path, "").unwrap();
+        reload_if_complete_(&path, &current);
+
+        assert!(current.load().string_is_truey("oui"));
+
+        // ... whereas a complete file replaces them
+
+        fs::write(&path, "truey_lowercase_strings = ja\n").unwrap();
+        reload_if_complete_(&path, &current);
+
+        assert!(current.load().string_is_truey("ja"));
+        assert!(!current.load().string_is_truey("oui"));
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn TEST_WatchedTerms_reload() {
+        let path = temp_path_("explicit-reload");
+
+        fs::write(&path, "truey_lowercase_strings = oui\n").unwrap();
+
+        let terms = WatchedTerms::open(&path).unwrap();
+
+        // (the file is not changed, so the watcher does not reload it)
+
+        assert!(terms.reload().is_ok());
+        assert!(terms.string_is_truey("OUI"));
+
+        let _ = fs::remove_file(&path);
+
+        assert!(matches!(terms.reload(), Err(WatchError::Io(_))));
+        assert!(terms.string_is_truey("oui"));
+    }
+
+    #[test]
+    fn TEST_WatchedTerms_RELOAD() {
+        let path = temp_path_("reload");
+
+        fs::write(&path, "truey_lowercase_strings = oui\n").unwrap();
+
+        let terms = WatchedTerms::open(&path).unwrap();
+
+        assert_eq!(path, terms.path());
+        assert!(terms.string_is_truey("OUI"));
+        assert!(!terms.string_is_truey("ja"));
+
+        // a change is picked up by the watcher
+
+        replace_file_(&path, "truey_lowercase_strings = ja\n");
+
+        let deadline = Instant::now() + Duration::from_secs(10);
+
+        while !terms.string_is_truey("ja") && Instant::now() < deadline {
+            thread::sleep(Duration::from_millis(10));
+        }
+
+        assert!(terms.string_is_truey("ja"));
+        assert!(!terms.string_is_truey("oui"));
+        assert!(terms.load().string_is_truey("JA"));
+
+        let _ = fs::remove_file(&path);
+    }
+}
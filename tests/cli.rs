@@ -0,0 +1,172 @@
+// cli.rs - tests of the `to-be` program
+
+#![cfg(feature = "cli")]
+#![allow(non_snake_case)]
+
+use std::{
+    io::Write as _,
+    process::{
+        Command,
+        Output,
+        Stdio,
+    },
+};
+
+
+fn to_be_(
+    args : &[&str],
+    stdin : Option<&str>,
+) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_to-be"))
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    {
+        let mut child_stdin = child.stdin.take().unwrap();
+
+        if let Some(stdin) = stdin {
+            child_stdin.write_all(stdin.as_bytes()).unwrap();
+        }
+    }
+
+    child.wait_with_output().unwrap()
+}
+
+fn status_and_stdout_(output : Output) -> (Option<i32>, String) {
+    (output.status.code(), String::from_utf8(output.stdout).unwrap())
+}
+
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_ARGUMENTS() {
+    assert_eq!((Some(0), "true\n".into()), status_and_stdout_(to_be_(&["1"], None)));
+    assert_eq!((Some(1), "true\nfalse\n".into()), status_and_stdout_(to_be_(&["1", "0"], None)));
+    assert_eq!((Some(2), "unknown\nfalse\n".into()), status_and_stdout_(to_be_(&["abc", "0"], None)));
+    assert_eq!((Some(2), "unknown\n".into()), status_and_stdout_(to_be_(&["--", "-1"], None)));
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_STDIN() {
+    assert_eq!((Some(1), "true\nfalse\n".into()), status_and_stdout_(to_be_(&[], Some("1\n0\n"))));
+    assert_eq!((Some(2), "".into()), status_and_stdout_(to_be_(&[], Some(""))));
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_OPTIONS() {
+    assert_eq!((Some(0), "".into()), status_and_stdout_(to_be_(&["-q", "1"], None)));
+    assert_eq!(
+        (Some(2), "true\nfalse\nunknown\n".into()),
+        status_and_stdout_(to_be_(&["-t", "Oui", "--falsey", "non", "OUI", "non", "1"], None))
+    );
+    assert_eq!(Some(64), to_be_(&["-t", "oui", "-p", "yaml11", "1"], None).status.code());
+    assert_eq!(Some(64), to_be_(&["--no-such-option"], None).status.code());
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_FAIL_FAST() {
+    let output = to_be_(&["--fail-fast", "1", "0", "abc", "1"], None);
+
+    assert_eq!(Some(2), output.status.code());
+    assert_eq!("true\nfalse\nunknown\n", String::from_utf8(output.stdout).unwrap());
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_STRICTNESS() {
+    let values = ["yes", " yes", "'Enabled'.", "42"];
+    let stdout_of = |args : &[&str]| status_and_stdout_(to_be_(&[args, &values[..]].concat(), None)).1;
+
+    assert_eq!("true\ntrue\nunknown\nunknown\n", stdout_of(&[]));
+    assert_eq!("true\nunknown\nunknown\nunknown\n", stdout_of(&["-s", "strict"]));
+    assert_eq!("true\ntrue\nunknown\nunknown\n", stdout_of(&["--strictness", "Standard"]));
+    assert_eq!("true\ntrue\ntrue\ntrue\n", stdout_of(&["--strictness", "lenient"]));
+    assert_eq!(
+        (Some(2), "unknown\n".into()),
+        status_and_stdout_(to_be_(&["--strictness", "strict", "--fail-fast", "YES!", "1"], None))
+    );
+    assert_eq!(Some(64), to_be_(&["--strictness", "relaxed", "1"], None).status.code());
+}
+
+#[test]
+fn TEST_FAIL_FAST_SUGGESTIONS() {
+    let stderr_of = |args : &[&str]| String::from_utf8(to_be_(args, None).stderr).unwrap();
+
+    assert_eq!(
+        "to-be: unrecognised value \"ou\" (did you mean: \"oui\"?)\n",
+        stderr_of(&["--fail-fast", "-t", "oui", "-f", "non", "ou"])
+    );
+    assert_eq!("to-be: unrecognised value \"treu\"\n", stderr_of(&["--fail-fast", "-t", "oui", "-f", "non", "treu"]));
+    assert_eq!(
+        "to-be: unrecognised value \"tru\" (did you mean: \"true\"?)\n",
+        stderr_of(&["--fail-fast", "-p", "sql", "tru"])
+    );
+}
+
+#[test]
+fn TEST_BROKEN_PIPE() {
+    // the output far exceeds the capacity of a pipe, so the program writes
+    // to it after it is closed
+
+    let args = vec!["-t", "oui", "-f", "non"].into_iter().chain(std::iter::repeat_n("oui", 100_000));
+
+    let mut child = Command::new(env!("CARGO_BIN_EXE_to-be"))
+        .args(args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+
+    drop(child.stdout.take());
+
+    let output = child.wait_with_output().unwrap();
+
+    assert_eq!(Some(0), output.status.code());
+    assert_eq!("", String::from_utf8(output.stderr).unwrap());
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_filter() {
+    let input = "yes\nno\nmaybe\nTRUE\n";
+
+    assert_eq!((Some(0), "yes\nTRUE\n".into()), status_and_stdout_(to_be_(&["filter"], Some(input))));
+    assert_eq!((Some(0), "no\n".into()), status_and_stdout_(to_be_(&["filter", "--only", "falsey"], Some(input))));
+    assert_eq!(
+        (Some(0), "unknown\tmaybe\n".into()),
+        status_and_stdout_(to_be_(&["filter", "-o", "unknown", "-a"], Some(input)))
+    );
+    assert_eq!((Some(1), "".into()), status_and_stdout_(to_be_(&["filter"], Some("maybe\n"))));
+}
+
+#[cfg(to_be_stock_default)]
+#[test]
+fn TEST_filter_FIELD() {
+    let input = "a,on,1\nb,off,2\nc,,3\nd\n";
+
+    assert_eq!(
+        (Some(0), "a,on,1\n".into()),
+        status_and_stdout_(to_be_(&["filter", "-d", ",", "-k", "2"], Some(input)))
+    );
+    assert_eq!(
+        (Some(0), "false\tb,off,2\n".into()),
+        status_and_stdout_(to_be_(
+            &["filter", "--delimiter", ",", "--field", "2", "--only", "falsey", "--annotate"],
+            Some(input)
+        ))
+    );
+    assert_eq!(
+        (Some(0), "c,,3\nd\n".into()),
+        status_and_stdout_(to_be_(&["filter", "-d", ",", "-k", "2", "-o", "unknown"], Some(input)))
+    );
+    assert_eq!(Some(64), to_be_(&["filter", "-k", "0"], Some(input)).status.code());
+    assert_eq!(Some(64), to_be_(&["-k", "2", "1"], None).status.code());
+}
@@ -0,0 +1,127 @@
+// strictness.rs - tests of the instrumentation of classification by strictness
+
+#![allow(non_snake_case)]
+
+#[cfg(any(feature = "hooks", feature = "metrics"))]
+use to_be::{
+    string_is_truthy_with_strictness,
+    string_is_truthy_with_terms_and_strictness,
+    CompiledTerms,
+    Strictness,
+    Terms,
+};
+
+#[cfg(any(feature = "hooks", feature = "metrics"))]
+use std::sync::Mutex;
+
+
+// NOTE: as the callback and the counters are process-wide, the tests are
+// serialised, and this file contains no others, so that the reports and
+// counts are exactly those of each test
+
+#[cfg(any(feature = "hooks", feature = "metrics"))]
+static SERIAL : Mutex<()> = Mutex::new(());
+
+#[cfg(any(feature = "hooks", feature = "metrics"))]
+fn terms_() -> Terms<'static> {
+    Terms::Strings {
+        falsey_precise_strings :   &[],
+        falsey_lowercase_strings : &["non"],
+        truey_precise_strings :    &[],
+        truey_lowercase_strings :  &["oui"],
+    }
+}
+
+
+#[cfg(feature = "hooks")]
+#[test]
+fn TEST_on_unrecognised_FOR_Lenient() {
+    use to_be::hooks::{
+        clear_on_unrecognised,
+        set_on_unrecognised,
+    };
+
+    static REPORTS : Mutex<Vec<(String, String)>> = Mutex::new(Vec::new());
+
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+
+    set_on_unrecognised(|u| REPORTS.lock().unwrap().push((u.input.to_owned(), u.context.to_owned())));
+
+    // numbers are recognised leniently, and so are not reported
+
+    assert_eq!(Some(true), string_is_truthy_with_strictness("42", Strictness::Lenient));
+    assert_eq!(Some(true), string_is_truthy_with_terms_and_strictness("42", terms_(), Strictness::Lenient));
+    assert_eq!(Some(true), CompiledTerms::new(terms_()).with_strictness(Strictness::Lenient).string_is_truthy("42"));
+
+    // inputs that are not recognised are reported once
+
+    assert_eq!(None, string_is_truthy_with_strictness("maybe", Strictness::Lenient));
+    assert_eq!(None, string_is_truthy_with_terms_and_strictness("peut-être", terms_(), Strictness::Strict));
+
+    clear_on_unrecognised();
+
+    assert_eq!(
+        vec![
+            ("maybe".to_owned(), "string_is_truthy_with_strictness".to_owned()),
+            ("peut-être".to_owned(), "string_is_truthy_with_terms_and_strictness".to_owned()),
+        ],
+        *REPORTS.lock().unwrap()
+    );
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn TEST_metrics_FOR_Lenient() {
+    use to_be::metrics::{
+        snapshot,
+        Counts,
+        Evaluator,
+    };
+
+    let _serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+
+    let counts_of = |f : &dyn Fn()| -> [Counts; 3] {
+        let before = snapshot();
+
+        f();
+
+        let after = snapshot();
+
+        [Evaluator::Default, Evaluator::Custom, Evaluator::Compiled].map(|evaluator| {
+            let (before, after) = (before.get(evaluator), after.get(evaluator));
+
+            Counts {
+                truey :   after.truey - before.truey,
+                falsey :  after.falsey - before.falsey,
+                unknown : after.unknown - before.unknown,
+            }
+        })
+    };
+    let truey = Counts {
+        truey : 1,
+        ..Counts::default()
+    };
+    let none = Counts::default();
+
+    assert_eq!(
+        [truey, none, none],
+        counts_of(&|| {
+            string_is_truthy_with_strictness("42", Strictness::Lenient);
+        })
+    );
+    assert_eq!(
+        [none, truey, none],
+        counts_of(&|| {
+            string_is_truthy_with_terms_and_strictness("42", terms_(), Strictness::Lenient);
+        })
+    );
+
+    let compiled = CompiledTerms::new(terms_()).with_strictness(Strictness::Lenient);
+
+    assert_eq!(
+        [none, none, truey],
+        counts_of(&|| {
+            compiled.string_is_truthy("42");
+        })
+    );
+}